@@ -1,21 +1,144 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
-use log::LevelFilter;
+use log::{Level, LevelFilter};
 use serde::Deserialize;
 
+use crate::Error;
+
 /// Default value for the target width.
 const DEFAULT_TARGET_WIDTH: usize = 42;
 /// Default value for the level width.
 const DEFAULT_LEVEL_WIDTH: usize = 5;
+/// Minimum value for the level width, which is the length of the longest level, `ERROR`.
+const MIN_LEVEL_WIDTH: usize = 5;
+/// Default value for the automatic target width flag.
+const DEFAULT_AUTO_TARGET_WIDTH: bool = false;
 /// Default name for an output.
 const DEFAULT_OUTPUT_NAME: &str = crate::LOGGER_STDOUT_NAME;
 /// Default log level for an output.
 const DEFAULT_OUTPUT_LEVEL_FILTER: LevelFilter = LevelFilter::Info;
 /// Default value for the color flag.
 const DEFAULT_COLOR_ENABLED: bool = false;
+/// Default value for the message escaping flag.
+const DEFAULT_ESCAPE_MESSAGE: bool = false;
+/// Default value for the target display flag.
+const DEFAULT_SHOW_TARGET: bool = true;
+/// Default value for the append flag.
+const DEFAULT_APPEND: bool = true;
+/// Default number of records retained by a ring buffer output.
+const DEFAULT_RING_BUFFER_CAPACITY: usize = 1000;
+/// Default name of the event field marking the records to highlight.
+const DEFAULT_HIGHLIGHT_FIELD: &str = "highlight";
+/// Prefix of the target filters and exclusions referencing a target group of the logger.
+const TARGET_GROUP_PREFIX: char = '@';
+
+/// Separator written after each record of a logger output.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    /// Line feed, `\n`.
+    #[default]
+    Lf,
+    /// Carriage return followed by a line feed, `\r\n`.
+    CrLf,
+    /// Null character, `\0`, for tools splitting records on it. Records are not terminated by a new line.
+    Nul,
+}
+
+impl LineEnding {
+    /// Returns the separator as a string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+            Self::Nul => "\0",
+        }
+    }
+}
+
+/// Behavior of a logger output when a record cannot be written, e.g. to a file on a full disk.
+///
+/// This policy is applied by the log layer of `trace-tools`. The outputs of the `fern` logger report their write
+/// errors on the standard error instead.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WriteErrorPolicy {
+    /// The record is dropped.
+    #[default]
+    Ignore,
+    /// Writing the record is retried once before it is dropped.
+    Retry,
+    /// The record is written to the standard error instead.
+    Stderr,
+    /// The record is dropped, and the first write error of the output is reported on the standard error.
+    Report,
+}
+
+/// Palette used to color the levels of a logger output with colors enabled.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorScheme {
+    /// Bright colors, readable on most terminal themes.
+    #[default]
+    Default,
+    /// Normal colors, for terminals that only support 8 colors.
+    Basic,
+    /// Colors of the Solarized dark theme, which renders most bright colors as grey tones.
+    SolarizedDark,
+    /// Colors of the Solarized light theme, with warnings in orange to remain readable on a light background.
+    SolarizedLight,
+}
+
+impl ColorScheme {
+    /// Returns the name of the color of a level, as understood by the `colored` crate.
+    pub fn level_color(&self, level: Level) -> &'static str {
+        match (self, level) {
+            (Self::Default, Level::Trace) => "bright magenta",
+            (Self::Default, Level::Debug) => "bright blue",
+            (Self::Default, Level::Info) => "bright green",
+            (Self::Default, Level::Warn) => "bright yellow",
+            (Self::Default, Level::Error) => "bright red",
+            (Self::Basic, Level::Trace) => "magenta",
+            (Self::Basic, Level::Debug) => "blue",
+            (Self::Basic, Level::Info) => "green",
+            (Self::Basic, Level::Warn) => "yellow",
+            (Self::Basic, Level::Error) => "red",
+            // Solarized renders bright magenta as violet and bright red as orange.
+            (Self::SolarizedDark | Self::SolarizedLight, Level::Trace) => "bright magenta",
+            (Self::SolarizedDark | Self::SolarizedLight, Level::Debug) => "blue",
+            (Self::SolarizedDark, Level::Info) => "green",
+            (Self::SolarizedLight, Level::Info) => "cyan",
+            (Self::SolarizedDark, Level::Warn) => "yellow",
+            (Self::SolarizedLight, Level::Warn) => "bright red",
+            (Self::SolarizedDark | Self::SolarizedLight, Level::Error) => "red",
+        }
+    }
+}
+
+/// Emphasis of the highlighted records of a logger output with colors enabled.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightStyle {
+    /// The message is written in bold.
+    Bold,
+    /// The foreground and background colors of the message are swapped.
+    #[default]
+    Reverse,
+}
+
+/// Rate limit of the records of each target of a logger output.
+///
+/// Records are dropped once a target has used up its budget, which is refilled over time.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of records per second a target can write in the long run.
+    pub rate: u32,
+    /// Number of records a target can write at once, after having been quiet.
+    pub burst: u32,
+}
 
 /// Builder for a logger output configuration.
 #[derive(Default, Deserialize, PartialEq, Eq)]
@@ -35,6 +158,44 @@ pub struct LoggerOutputConfigBuilder {
     /// Color flag of an output.
     #[serde(alias = "colorEnabled")]
     color_enabled: Option<bool>,
+    /// Color scheme of an output.
+    #[serde(alias = "colorScheme")]
+    color_scheme: Option<ColorScheme>,
+    /// Name of the event field marking the records to highlight in an output.
+    #[serde(alias = "highlightField")]
+    highlight_field: Option<String>,
+    /// Emphasis of the highlighted records of an output.
+    #[serde(alias = "highlightStyle")]
+    highlight_style: Option<HighlightStyle>,
+    /// Line ending of an output.
+    #[serde(alias = "lineEnding")]
+    line_ending: Option<LineEnding>,
+    /// Width of the target section of the logs of an output.
+    #[serde(alias = "targetWidth")]
+    target_width: Option<usize>,
+    /// Width of the level section of the logs of an output.
+    #[serde(alias = "levelWidth")]
+    level_width: Option<usize>,
+    /// Rate limit of the records of each target of an output.
+    #[serde(alias = "rateLimit")]
+    rate_limit: Option<RateLimit>,
+    /// Append flag of an output file.
+    append: Option<bool>,
+    /// Message escaping flag of an output.
+    #[serde(alias = "escapeMessage")]
+    escape_message: Option<bool>,
+    /// Target display flag of an output.
+    #[serde(alias = "showTarget")]
+    show_target: Option<bool>,
+    /// Number of records retained by a ring buffer output.
+    #[serde(alias = "ringBufferCapacity")]
+    ring_buffer_capacity: Option<usize>,
+    /// Name of the event source of a Windows Event Log output.
+    #[serde(alias = "eventLogSource")]
+    event_log_source: Option<String>,
+    /// Behavior of an output when a record cannot be written.
+    #[serde(alias = "writeErrorPolicy")]
+    write_error_policy: Option<WriteErrorPolicy>,
 }
 
 impl LoggerOutputConfigBuilder {
@@ -44,6 +205,9 @@ impl LoggerOutputConfigBuilder {
     }
 
     /// Sets the name of a logger output.
+    ///
+    /// Unless it is `stdout`, the name is the path of the output file, which can contain placeholders expanded by
+    /// [`expand_log_file_path`](crate::expand_log_file_path).
     pub fn name<'a>(mut self, name: impl Into<Cow<'a, str>>) -> Self {
         self.name.replace(name.into().into_owned());
         self
@@ -57,6 +221,9 @@ impl LoggerOutputConfigBuilder {
 
     /// Sets a collection of filters of a logger output.
     /// A message is logged only if one of the filters is part of the log's metadata target.
+    ///
+    /// A filter of the form `@name` stands for all the targets of the group `name` of the logger, see
+    /// [`LoggerConfigBuilder::with_target_group`].
     pub fn target_filters(mut self, target_filters: &[&str]) -> Self {
         self.target_filters = Some(target_filters.iter().map(ToString::to_string).collect::<Vec<_>>());
         self
@@ -64,6 +231,9 @@ impl LoggerOutputConfigBuilder {
 
     /// Sets a collection of exclusions of a logger output.
     /// A message is logged only if one of the exclusions is *not* part of the log's metadata target.
+    ///
+    /// An exclusion of the form `@name` stands for all the targets of the group `name` of the logger, see
+    /// [`LoggerConfigBuilder::with_target_group`].
     pub fn target_exclusions(mut self, target_exclusions: &[&str]) -> Self {
         self.target_exclusions = Some(target_exclusions.iter().map(ToString::to_string).collect::<Vec<_>>());
         self
@@ -75,6 +245,96 @@ impl LoggerOutputConfigBuilder {
         self
     }
 
+    /// Sets the color scheme of the levels of a logger output, used if colors are enabled.
+    pub fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme.replace(color_scheme);
+        self
+    }
+
+    /// Sets the name of the event field marking the records to highlight in a logger output, `highlight` by default.
+    ///
+    /// Records of events with this field set to `true` are emphasized if colors are enabled, e.g.
+    /// `info!(highlight = true, "...")`. Highlighting is applied by the log layer of `trace-tools` to the standard
+    /// output, and the field is not written with the other fields of the record.
+    pub fn highlight_field(mut self, field: &str) -> Self {
+        self.highlight_field.replace(field.to_owned());
+        self
+    }
+
+    /// Sets the emphasis of the highlighted records of a logger output.
+    pub fn highlight_style(mut self, style: HighlightStyle) -> Self {
+        self.highlight_style.replace(style);
+        self
+    }
+
+    /// Sets the line ending of a logger output.
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending.replace(line_ending);
+        self
+    }
+
+    /// Sets the target width of a logger output, overriding the one of the logger.
+    pub fn target_width(mut self, width: usize) -> Self {
+        self.target_width.replace(width);
+        self
+    }
+
+    /// Sets the level width of a logger output, overriding the one of the logger.
+    pub fn level_width(mut self, width: usize) -> Self {
+        self.level_width.replace(width);
+        self
+    }
+
+    /// Sets the rate limit of the records of each target of a logger output.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit.replace(rate_limit);
+        self
+    }
+
+    /// Sets whether records are appended to the output file, or whether the file is truncated when it is opened.
+    pub fn append(mut self, append: bool) -> Self {
+        self.append.replace(append);
+        self
+    }
+
+    /// Sets whether the messages of a logger output are quoted, with their quotes, backslashes and control characters
+    /// escaped, so that every record fits on a single line.
+    pub fn escape_message(mut self, escape_message: bool) -> Self {
+        self.escape_message.replace(escape_message);
+        self
+    }
+
+    /// Sets whether the records of a logger output have a target section, `true` by default. Without it, records are
+    /// made of their time, level and message, which is enough for applications logging from a single module.
+    pub fn show_target(mut self, show_target: bool) -> Self {
+        self.show_target.replace(show_target);
+        self
+    }
+
+    /// Sets the number of most recent records retained in memory by a logger output named `ring_buffer`.
+    ///
+    /// Ring buffer outputs are provided by the log layer of `trace-tools`, and this option is ignored by other outputs.
+    pub fn ring_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.ring_buffer_capacity.replace(capacity);
+        self
+    }
+
+    /// Sets the name of the event source under which a logger output named `winlog` writes records to the Windows
+    /// Event Log, the file stem of the current executable by default.
+    ///
+    /// Windows Event Log outputs are provided by the log layer of `trace-tools`, and this option is ignored by other
+    /// outputs.
+    pub fn event_log_source(mut self, source: &str) -> Self {
+        self.event_log_source.replace(source.to_owned());
+        self
+    }
+
+    /// Sets the behavior of a logger output when a record cannot be written.
+    pub fn write_error_policy(mut self, policy: WriteErrorPolicy) -> Self {
+        self.write_error_policy.replace(policy);
+        self
+    }
+
     /// Builds a logger output configuration.
     #[must_use]
     pub fn finish(self) -> LoggerOutputConfig {
@@ -94,6 +354,21 @@ impl LoggerOutputConfigBuilder {
                 .map(|f| f.to_lowercase())
                 .collect(),
             color_enabled: self.color_enabled.unwrap_or(DEFAULT_COLOR_ENABLED),
+            color_scheme: self.color_scheme.unwrap_or_default(),
+            highlight_field: self
+                .highlight_field
+                .unwrap_or_else(|| DEFAULT_HIGHLIGHT_FIELD.to_owned()),
+            highlight_style: self.highlight_style.unwrap_or_default(),
+            line_ending: self.line_ending.unwrap_or_default(),
+            target_width: self.target_width,
+            level_width: self.level_width.map(|width| width.max(MIN_LEVEL_WIDTH)),
+            rate_limit: self.rate_limit,
+            append: self.append.unwrap_or(DEFAULT_APPEND),
+            escape_message: self.escape_message.unwrap_or(DEFAULT_ESCAPE_MESSAGE),
+            show_target: self.show_target.unwrap_or(DEFAULT_SHOW_TARGET),
+            ring_buffer_capacity: self.ring_buffer_capacity.unwrap_or(DEFAULT_RING_BUFFER_CAPACITY),
+            event_log_source: self.event_log_source,
+            write_error_policy: self.write_error_policy.unwrap_or_default(),
         }
     }
 }
@@ -111,6 +386,32 @@ pub struct LoggerOutputConfig {
     pub(crate) target_exclusions: Vec<String>,
     /// Color flag of the output.
     pub(crate) color_enabled: bool,
+    /// Color scheme of the output.
+    pub(crate) color_scheme: ColorScheme,
+    /// Name of the event field marking the records to highlight in the output.
+    pub(crate) highlight_field: String,
+    /// Emphasis of the highlighted records of the output.
+    pub(crate) highlight_style: HighlightStyle,
+    /// Line ending of the output.
+    pub(crate) line_ending: LineEnding,
+    /// Width of the target section of the logs of the output, if it overrides the one of the logger.
+    pub(crate) target_width: Option<usize>,
+    /// Width of the level section of the logs of the output, if it overrides the one of the logger.
+    pub(crate) level_width: Option<usize>,
+    /// Rate limit of the records of each target of the output.
+    pub(crate) rate_limit: Option<RateLimit>,
+    /// Append flag of the output file.
+    pub(crate) append: bool,
+    /// Message escaping flag of the output.
+    pub(crate) escape_message: bool,
+    /// Target display flag of the output.
+    pub(crate) show_target: bool,
+    /// Number of records retained by the output, if it is a ring buffer.
+    pub(crate) ring_buffer_capacity: usize,
+    /// Name of the event source of the output, if it is a Windows Event Log and the source is not the default one.
+    pub(crate) event_log_source: Option<String>,
+    /// Behavior of the output when a record cannot be written.
+    pub(crate) write_error_policy: WriteErrorPolicy,
 }
 
 impl LoggerOutputConfig {
@@ -138,6 +439,101 @@ impl LoggerOutputConfig {
     pub fn color_enabled(&self) -> bool {
         self.color_enabled
     }
+
+    /// Returns the color scheme of the output.
+    pub fn color_scheme(&self) -> ColorScheme {
+        self.color_scheme
+    }
+
+    /// Returns the name of the event field marking the records to highlight in the output.
+    pub fn highlight_field(&self) -> &str {
+        &self.highlight_field
+    }
+
+    /// Returns the emphasis of the highlighted records of the output.
+    pub fn highlight_style(&self) -> HighlightStyle {
+        self.highlight_style
+    }
+
+    /// Returns the line ending of the output.
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Returns the width of the target section of the logs of the output, if it overrides the one of the logger.
+    pub fn target_width(&self) -> Option<usize> {
+        self.target_width
+    }
+
+    /// Returns the width of the level section of the logs of the output, if it overrides the one of the logger.
+    pub fn level_width(&self) -> Option<usize> {
+        self.level_width
+    }
+
+    /// Returns the rate limit of the records of each target of the output, if any.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+    }
+
+    /// Returns whether records are appended to the output file, or whether the file is truncated when it is opened.
+    pub fn append(&self) -> bool {
+        self.append
+    }
+
+    /// Returns whether the messages of the output are quoted and escaped.
+    pub fn escape_message(&self) -> bool {
+        self.escape_message
+    }
+
+    /// Returns whether the records of the output have a target section.
+    pub fn show_target(&self) -> bool {
+        self.show_target
+    }
+
+    /// Returns the number of most recent records retained by the output, if it is a ring buffer.
+    pub fn ring_buffer_capacity(&self) -> usize {
+        self.ring_buffer_capacity
+    }
+
+    /// Returns the name of the event source of the output, if it is a Windows Event Log and the source is not the
+    /// default one.
+    pub fn event_log_source(&self) -> Option<&str> {
+        self.event_log_source.as_deref()
+    }
+
+    /// Returns the behavior of the output when a record cannot be written.
+    pub fn write_error_policy(&self) -> WriteErrorPolicy {
+        self.write_error_policy
+    }
+
+    /// Returns a copy of the output configuration with the references to target groups in its filters and exclusions
+    /// replaced by the targets of these groups, e.g. those of [`LoggerConfig::target_groups`].
+    ///
+    /// # Errors
+    /// This method fails with [`Error::UnknownTargetGroup`] if a filter or exclusion references a group that is not
+    /// part of `target_groups`, as it would otherwise silently match nothing. Outputs used on their own can be checked
+    /// with an empty map, which rejects every reference.
+    pub fn resolve_target_groups(&self, target_groups: &HashMap<String, Vec<String>>) -> Result<Self, Error> {
+        let resolve = |targets: &[String]| {
+            targets
+                .iter()
+                .map(|target| match target.strip_prefix(TARGET_GROUP_PREFIX) {
+                    Some(name) => target_groups
+                        .get(name)
+                        .map(Vec::as_slice)
+                        .ok_or_else(|| Error::UnknownTargetGroup(name.to_owned())),
+                    None => Ok(std::slice::from_ref(target)),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|targets| targets.concat())
+        };
+
+        Ok(Self {
+            target_filters: resolve(&self.target_filters)?,
+            target_exclusions: resolve(&self.target_exclusions)?,
+            ..self.clone()
+        })
+    }
 }
 
 /// Builder for a logger configuration.
@@ -150,6 +546,12 @@ pub struct LoggerConfigBuilder {
     /// Width of the level section of a log.
     #[serde(alias = "levelWidth")]
     level_width: Option<usize>,
+    /// Automatic target width flag of the logger.
+    #[serde(alias = "autoTargetWidth")]
+    auto_target_width: Option<bool>,
+    /// Named groups of targets, referenced by the target filters and exclusions of the outputs.
+    #[serde(alias = "targetGroups")]
+    target_groups: Option<HashMap<String, Vec<String>>>,
     /// Outputs of the logger.
     outputs: Option<Vec<LoggerOutputConfigBuilder>>,
 }
@@ -161,12 +563,34 @@ impl LoggerConfigBuilder {
         self
     }
 
-    /// Sets the target width.
+    /// Sets the level width. It cannot be narrower than the longest level, `ERROR`.
     pub fn with_level_width(mut self, width: usize) -> Self {
         self.level_width.replace(width);
         self
     }
 
+    /// Sets the automatic target width flag.
+    ///
+    /// When enabled, the target section of the logs of each output grows to fit the longest target logged so far, so
+    /// that columns stay aligned. The target width is then the minimum width of the section.
+    pub fn with_auto_target_width(mut self, auto: bool) -> Self {
+        self.auto_target_width.replace(auto);
+        self
+    }
+
+    /// Adds a named group of targets, which the target filters and exclusions of the outputs can reference as
+    /// `@name`, e.g. to exclude the same noisy dependencies from several outputs.
+    ///
+    /// Group names and targets are lowercased, like the filters and exclusions. References are kept as they are in
+    /// the output configurations, and replaced by the targets of their group by
+    /// [`LoggerOutputConfig::resolve_target_groups`] when the logger is initialized.
+    pub fn with_target_group(mut self, name: &str, targets: &[&str]) -> Self {
+        self.target_groups
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_owned(), targets.iter().map(ToString::to_string).collect());
+        self
+    }
+
     /// Adds an output builder to the logger builder.
     pub fn with_output(mut self, output: LoggerOutputConfigBuilder) -> Self {
         self.outputs.get_or_insert_with(Vec::new).push(output);
@@ -197,7 +621,14 @@ impl LoggerConfigBuilder {
 
         LoggerConfig {
             target_width: self.target_width.unwrap_or(DEFAULT_TARGET_WIDTH),
-            level_width: self.level_width.unwrap_or(DEFAULT_LEVEL_WIDTH),
+            level_width: self.level_width.unwrap_or(DEFAULT_LEVEL_WIDTH).max(MIN_LEVEL_WIDTH),
+            auto_target_width: self.auto_target_width.unwrap_or(DEFAULT_AUTO_TARGET_WIDTH),
+            target_groups: self
+                .target_groups
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, targets)| (name.to_lowercase(), targets.iter().map(|t| t.to_lowercase()).collect()))
+                .collect(),
             outputs,
         }
     }
@@ -210,6 +641,10 @@ pub struct LoggerConfig {
     pub(crate) target_width: usize,
     /// Width of the level section of a log.
     pub(crate) level_width: usize,
+    /// Automatic target width flag of the logger.
+    pub(crate) auto_target_width: bool,
+    /// Named groups of targets, referenced by the target filters and exclusions of the outputs.
+    pub(crate) target_groups: HashMap<String, Vec<String>>,
     /// Outputs of the logger.
     pub(crate) outputs: Vec<LoggerOutputConfig>,
 }
@@ -236,6 +671,16 @@ impl LoggerConfig {
         self.level_width
     }
 
+    /// Returns the automatic target width flag of the `LoggerConfig`.
+    pub fn auto_target_width(&self) -> bool {
+        self.auto_target_width
+    }
+
+    /// Returns the named groups of targets of the `LoggerConfig`.
+    pub fn target_groups(&self) -> &HashMap<String, Vec<String>> {
+        &self.target_groups
+    }
+
     /// Returns the outputs of the `LoggerConfig`.
     pub fn outputs(&self) -> &[LoggerOutputConfig] {
         &self.outputs
@@ -4,6 +4,10 @@
 //! Convenient multi-output backend for the `log` crate.
 
 mod config;
+mod path;
+mod rate_limit;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use fern::{
     colors::{Color, ColoredLevelConfig},
@@ -11,7 +15,14 @@ use fern::{
 };
 use thiserror::Error;
 
-pub use self::config::{LoggerConfig, LoggerConfigBuilder, LoggerOutputConfig, LoggerOutputConfigBuilder};
+pub use self::{
+    config::{
+        ColorScheme, HighlightStyle, LineEnding, LoggerConfig, LoggerConfigBuilder, LoggerOutputConfig,
+        LoggerOutputConfigBuilder, RateLimit, WriteErrorPolicy,
+    },
+    path::{expand_log_file_path, open_log_file},
+    rate_limit::TargetRateLimiter,
+};
 
 /// Name of the standard output.
 pub const LOGGER_STDOUT_NAME: &str = "stdout";
@@ -23,12 +34,27 @@ pub enum Error {
     /// Creating output file failed.
     #[error("Creating output file failed.")]
     CreatingFileFailed,
+    /// The output file path contains an unknown or unclosed placeholder.
+    #[error("Invalid output file path.")]
+    InvalidFilePath,
     /// Initializing the logger backend failed.
     #[error("Initializing the logger backend failed.")]
     InitializationFailed,
+    /// A target filter or exclusion references a target group that does not exist.
+    #[error("Unknown target group `{0}`.")]
+    UnknownTargetGroup(String),
 }
 
 macro_rules! log_format {
+    ($level:expr, $message:expr, $level_width:expr) => {
+        format_args!(
+            "{} {:level_width$} {}",
+            time_helper::format(&time_helper::now_utc()),
+            $level,
+            $message,
+            level_width = $level_width
+        )
+    };
     ($target:expr, $level:expr, $message:expr, $target_width:expr, $level_width:expr) => {
         format_args!(
             "{} {:target_width$} {:level_width$} {}",
@@ -42,47 +68,111 @@ macro_rules! log_format {
     };
 }
 
+/// Quotes a log message and escapes its quotes, backslashes and control characters, e.g. new lines, so that line-based
+/// parsers can read records spanning a single line.
+pub fn escape_message(message: &str) -> String {
+    format!("{:?}", message)
+}
+
+/// Width of the target section of the logs of an output.
+struct TargetWidth {
+    /// The configured width of the section.
+    width: usize,
+    /// The width of the longest target logged so far, if the width is automatic.
+    auto: Option<AtomicUsize>,
+}
+
+impl TargetWidth {
+    fn new(width: usize, auto: bool) -> Self {
+        Self {
+            width,
+            auto: auto.then(|| AtomicUsize::new(width)),
+        }
+    }
+
+    /// Returns the width of the section for a log with the given target.
+    fn for_target(&self, target: &str) -> usize {
+        match &self.auto {
+            Some(max) => max.fetch_max(target.len(), Ordering::Relaxed).max(target.len()),
+            None => self.width,
+        }
+    }
+}
+
 /// Initializes a `fern` logger backend for the `log` crate.
 ///
 /// # Arguments
 ///
 /// * `config`  -   Logger configuration
 pub fn logger_init(config: LoggerConfig) -> Result<(), Error> {
-    let target_width = config.target_width;
-    let level_width = config.level_width;
+    let auto_target_width = config.auto_target_width;
 
     let mut logger = Dispatch::new();
 
-    for output in config.outputs {
+    for output in &config.outputs {
+        let output = output.resolve_target_groups(&config.target_groups)?;
+        let target_width = TargetWidth::new(
+            output.target_width.unwrap_or(config.target_width),
+            auto_target_width,
+        );
+        let level_width = output.level_width.unwrap_or(config.level_width);
+        let escape = output.escape_message;
+        let show_target = output.show_target;
+
         // Creates a logger dispatch for each output of the configuration.
         let mut dispatch = if output.color_enabled {
+            let scheme = output.color_scheme;
             let colors = ColoredLevelConfig::new()
-                .trace(Color::BrightMagenta)
-                .debug(Color::BrightBlue)
-                .info(Color::BrightGreen)
-                .warn(Color::BrightYellow)
-                .error(Color::BrightRed);
+                .trace(Color::from(scheme.level_color(log::Level::Trace)))
+                .debug(Color::from(scheme.level_color(log::Level::Debug)))
+                .info(Color::from(scheme.level_color(log::Level::Info)))
+                .warn(Color::from(scheme.level_color(log::Level::Warn)))
+                .error(Color::from(scheme.level_color(log::Level::Error)));
 
             // Creates a logger dispatch with color support.
             Dispatch::new().format(move |out, message, record| {
-                out.finish(log_format!(
-                    record.target(),
-                    colors.color(record.level()),
-                    message,
-                    target_width,
-                    level_width
-                ))
+                let escaped;
+                let message: &dyn std::fmt::Display = if escape {
+                    escaped = escape_message(&message.to_string());
+                    &escaped
+                } else {
+                    message
+                };
+
+                if show_target {
+                    out.finish(log_format!(
+                        record.target(),
+                        colors.color(record.level()),
+                        message,
+                        target_width.for_target(record.target()),
+                        level_width
+                    ))
+                } else {
+                    out.finish(log_format!(colors.color(record.level()), message, level_width))
+                }
             })
         } else {
             // Creates a logger dispatch without color support.
             Dispatch::new().format(move |out, message, record| {
-                out.finish(log_format!(
-                    record.target(),
-                    record.level(),
-                    message,
-                    target_width,
-                    level_width
-                ))
+                let escaped;
+                let message: &dyn std::fmt::Display = if escape {
+                    escaped = escape_message(&message.to_string());
+                    &escaped
+                } else {
+                    message
+                };
+
+                if show_target {
+                    out.finish(log_format!(
+                        record.target(),
+                        record.level(),
+                        message,
+                        target_width.for_target(record.target()),
+                        level_width
+                    ))
+                } else {
+                    out.finish(log_format!(record.level(), message, level_width))
+                }
             })
         }
         .level(output.level_filter);
@@ -103,11 +193,23 @@ pub fn logger_init(config: LoggerConfig) -> Result<(), Error> {
             });
         }
 
+        // The rate limit is checked last so that only the records that are written use the budget of their target.
+        if let Some(rate_limit) = output.rate_limit {
+            let rate_limiter = TargetRateLimiter::new(rate_limit);
+            dispatch = dispatch.filter(move |metadata| rate_limiter.check(metadata.target()));
+        }
+
+        let line_ending = output.line_ending.as_str();
+
         // Special case for the standard output.
         dispatch = if output.name == LOGGER_STDOUT_NAME {
-            dispatch.chain(std::io::stdout())
+            dispatch.chain(fern::Output::stdout(line_ending))
         } else {
-            dispatch.chain(fern::log_file(output.name).map_err(|_| Error::CreatingFileFailed)?)
+            let file = open_log_file(&output.name, output.append).map_err(|err| match err.kind() {
+                std::io::ErrorKind::InvalidInput => Error::InvalidFilePath,
+                _ => Error::CreatingFileFailed,
+            })?;
+            dispatch.chain(fern::Output::file(file, line_ending))
         };
 
         logger = logger.chain(dispatch);
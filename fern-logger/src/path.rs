@@ -0,0 +1,95 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io,
+    path::PathBuf,
+};
+
+/// Placeholder expanded to the current date, at UTC, formatted as `YYYY-MM-DD`.
+const DATE_PLACEHOLDER: &str = "date";
+/// Placeholder expanded to the identifier of the current process.
+const PID_PLACEHOLDER: &str = "pid";
+
+/// Expands the placeholders of a log file path.
+///
+/// The supported placeholders are `{date}`, expanded to the current date at UTC, and `{pid}`, expanded to the
+/// identifier of the current process. Literal braces are written `{{` and `}}`.
+///
+/// # Errors
+/// Returns an [`io::Error`] of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if the path contains an unknown
+/// or unclosed placeholder.
+pub fn expand_log_file_path(template: &str) -> Result<PathBuf, io::Error> {
+    let invalid = |reason: String| io::Error::new(io::ErrorKind::InvalidInput, reason);
+
+    let mut path = String::with_capacity(template.len());
+    let mut chars = template.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                let rest = chars.as_str();
+
+                if let Some(rest) = rest.strip_prefix('{') {
+                    path.push('{');
+                    chars = rest.chars();
+                    continue;
+                }
+
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| invalid(format!("unclosed placeholder in log file path `{}`", template)))?;
+
+                match &rest[..end] {
+                    DATE_PLACEHOLDER => path.push_str(&time_helper::now_utc().date().to_string()),
+                    PID_PLACEHOLDER => path.push_str(&std::process::id().to_string()),
+                    placeholder => {
+                        return Err(invalid(format!(
+                            "unknown placeholder `{{{}}}` in log file path `{}`",
+                            placeholder, template
+                        )));
+                    }
+                }
+
+                chars = rest[end + 1..].chars();
+            }
+            '}' => {
+                let rest = chars.as_str();
+
+                match rest.strip_prefix('}') {
+                    Some(rest) => {
+                        path.push('}');
+                        chars = rest.chars();
+                    }
+                    None => return Err(invalid(format!("unmatched `}}` in log file path `{}`", template))),
+                }
+            }
+            c => path.push(c),
+        }
+    }
+
+    Ok(path.into())
+}
+
+/// Opens a log file, after expanding the placeholders of its path with [`expand_log_file_path`].
+///
+/// The file is opened in append mode if `append` is `true`, and truncated otherwise. The file and its parent
+/// directories are created if they do not exist yet.
+///
+/// # Errors
+/// Returns an [`io::Error`] if the path is invalid, or if the file or its parent directories cannot be created.
+pub fn open_log_file(template: &str, append: bool) -> Result<File, io::Error> {
+    let path = expand_log_file_path(template)?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+}
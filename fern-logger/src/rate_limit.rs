@@ -0,0 +1,75 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use crate::RateLimit;
+
+/// Token bucket of a single target.
+struct Bucket {
+    /// Number of records the target can write right now.
+    tokens: f64,
+    /// Time at which the tokens were last refilled.
+    refilled: Instant,
+}
+
+/// Limits the rate of the records written to a logger output, with a separate token bucket for every target.
+///
+/// The bucket of a target holds up to `burst` tokens and is refilled with `rate` tokens per second. Writing a record
+/// takes a token from the bucket of its target, so a noisy target cannot use up the budget of the other targets.
+pub struct TargetRateLimiter {
+    limit: RateLimit,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl TargetRateLimiter {
+    /// Creates a rate limiter whose targets all have the given rate limit.
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            buckets: Mutex::default(),
+        }
+    }
+
+    /// Returns the rate limit of every target.
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Takes a token from the bucket of the given target, returning `false` if the record is over budget and must
+    /// be dropped.
+    pub fn check(&self, target: &str) -> bool {
+        let now = Instant::now();
+        let burst = f64::from(self.limit.burst);
+        // A panic while holding the lock cannot leave the buckets in an invalid state.
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if !buckets.contains_key(target) {
+            buckets.insert(
+                target.to_owned(),
+                Bucket {
+                    tokens: burst,
+                    refilled: now,
+                },
+            );
+        }
+
+        // This unwrap is fine because the bucket has just been inserted if it did not exist.
+        let bucket = buckets.get_mut(target).unwrap();
+        let elapsed = now.duration_since(bucket.refilled).as_secs_f64();
+
+        bucket.tokens = (bucket.tokens + elapsed * f64::from(self.limit.rate)).min(burst);
+        bucket.refilled = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
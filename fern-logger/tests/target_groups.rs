@@ -0,0 +1,51 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use fern_logger::{Error, LoggerConfig, LoggerOutputConfigBuilder};
+
+#[test]
+fn target_groups_resolved() {
+    let output = LoggerOutputConfigBuilder::new()
+        .target_filters(&["@app"])
+        .target_exclusions(&["@noisy", "hyper"]);
+
+    let config = LoggerConfig::build()
+        .with_target_group("app", &["app::net", "app::db"])
+        .with_target_group("noisy", &["h2", "rustls"])
+        .with_output(output)
+        .finish();
+
+    assert_eq!(config.outputs()[0].target_filters(), ["@app"]);
+
+    let output = config.outputs()[0]
+        .resolve_target_groups(config.target_groups())
+        .unwrap();
+
+    assert_eq!(output.target_filters(), ["app::net", "app::db"]);
+    assert_eq!(output.target_exclusions(), ["h2", "rustls", "hyper"]);
+}
+
+#[test]
+fn unknown_target_group() {
+    let output = LoggerOutputConfigBuilder::new().target_exclusions(&["@noisy_dep"]);
+
+    let config = LoggerConfig::build()
+        .with_target_group("noisy_deps", &["h2"])
+        .with_output(output)
+        .finish();
+
+    let output = config.outputs()[0].resolve_target_groups(config.target_groups());
+
+    assert!(matches!(output, Err(Error::UnknownTargetGroup(name)) if name == "noisy_dep"));
+}
+
+#[test]
+fn standalone_output_target_group() {
+    let output = LoggerOutputConfigBuilder::new().target_exclusions(&["@noisy"]).finish();
+
+    let output = output.resolve_target_groups(&HashMap::new());
+
+    assert!(matches!(output, Err(Error::UnknownTargetGroup(name)) if name == "noisy"));
+}
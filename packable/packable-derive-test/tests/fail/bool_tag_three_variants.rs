@@ -0,0 +1,19 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = bool)]
+pub enum Mode {
+    #[packable(tag = false)]
+    Read,
+    #[packable(tag = true)]
+    Write,
+    #[packable(tag = true)]
+    Append,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Frame<'a> {
+    #[packable(tag = 0)]
+    Body(&'a [u8]),
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Frame<'a> {
+    #[packable(length_prefix = u8)]
+    body: &'a [u8],
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{Packable, PackableExt, error::ArrayUnpackError};
+
+#[derive(Debug)]
+pub enum KeyError {
+    UnknownTag(u8),
+    Array(ArrayUnpackError<Infallible>),
+}
+
+impl From<Infallible> for KeyError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<ArrayUnpackError<Infallible>> for KeyError {
+    fn from(err: ArrayUnpackError<Infallible>) -> Self {
+        Self::Array(err)
+    }
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8, with_error = KeyError::UnknownTag)]
+#[packable(unpack_error = KeyError)]
+#[packable(tag_after)]
+pub enum Key<const N: usize> {
+    #[packable(tag = 0)]
+    Short([u8; N]),
+    #[packable(tag = 1)]
+    Long([u8; 4]),
+}
+
+fn main() {
+    // The sizes of the payloads only differ once `N` is known.
+    let _ = Key::<3>::unpack_unverified([0, 0, 0, 0]);
+}
@@ -0,0 +1,15 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Records {
+    #[packable(count = count)]
+    values: Vec<u32>,
+    count: u16,
+}
+
+fn main() {}
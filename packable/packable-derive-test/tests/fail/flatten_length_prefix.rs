@@ -0,0 +1,15 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Message {
+    #[packable(flatten)]
+    #[packable(length_prefix = u8)]
+    items: Vec<u8>,
+}
+
+fn main() {}
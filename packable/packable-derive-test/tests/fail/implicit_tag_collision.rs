@@ -0,0 +1,18 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Message {
+    #[packable(tag = 1)]
+    Ping,
+    Pong,
+}
+
+fn main() {}
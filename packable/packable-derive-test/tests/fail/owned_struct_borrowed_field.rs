@@ -0,0 +1,13 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Frame {
+    body: &'static [u8],
+}
+
+fn main() {}
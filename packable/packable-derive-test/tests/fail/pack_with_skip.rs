@@ -0,0 +1,19 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{packer::Packer, Packable};
+
+fn pack_flag<P: Packer>(flag: &bool, packer: &mut P) -> Result<(), P::Error> {
+    flag.pack(packer)
+}
+
+#[derive(Packable)]
+pub struct Message {
+    #[packable(skip)]
+    #[packable(pack_with = pack_flag)]
+    flag: bool,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(packed_size)]
+pub struct Message {
+    id: u32,
+    #[packable(length_prefix = u8)]
+    payload: Vec<u8>,
+}
+
+fn main() {}
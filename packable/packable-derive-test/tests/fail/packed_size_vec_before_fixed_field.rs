@@ -0,0 +1,15 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(packed_size)]
+pub struct Message {
+    payload: Vec<u8>,
+    id: u32,
+}
+
+fn main() {}
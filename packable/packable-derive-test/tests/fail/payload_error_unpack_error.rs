@@ -0,0 +1,21 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+#[packable(payload_error = Infallible)]
+#[packable(unpack_error = Infallible)]
+pub enum OptI32 {
+    #[packable(tag = 0)]
+    None,
+    #[packable(tag = 1)]
+    Some(i32),
+}
+
+fn main() {}
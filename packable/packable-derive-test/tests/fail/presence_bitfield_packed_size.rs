@@ -0,0 +1,16 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(presence_bitfield)]
+#[packable(packed_size)]
+pub struct Message {
+    id: u32,
+    flags: Option<u8>,
+}
+
+fn main() {}
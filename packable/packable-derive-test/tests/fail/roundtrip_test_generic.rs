@@ -0,0 +1,12 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Default, Packable)]
+#[packable(roundtrip_test)]
+pub struct Wrapper<T: Packable>(T);
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Frame {
+    #[packable(tag = 0)]
+    #[packable(strict_len)]
+    Ping(u32),
+}
+
+fn main() {}
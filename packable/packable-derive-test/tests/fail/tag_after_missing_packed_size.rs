@@ -0,0 +1,23 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Payload {
+    id: u8,
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+#[packable(tag_after)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping(u8),
+    #[packable(tag = 1)]
+    Data(Payload),
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+#[packable(tag_after)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    Data(u32),
+}
+
+fn main() {}
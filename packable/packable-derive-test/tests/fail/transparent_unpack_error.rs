@@ -8,11 +8,9 @@ use core::convert::Infallible;
 use packable::Packable;
 
 #[derive(Packable)]
-#[packable(tag_type = u32)]
+#[repr(transparent)]
+#[packable(transparent)]
 #[packable(unpack_error = Infallible)]
-pub enum OptI32 {
-    None,
-    Some(i32),
-}
+pub struct Id(u64);
 
 fn main() {}
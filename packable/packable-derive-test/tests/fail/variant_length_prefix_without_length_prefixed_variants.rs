@@ -0,0 +1,16 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Message {
+    #[packable(tag = 0)]
+    #[packable(length_prefix = u8)]
+    Ping(u16),
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+#![deny(unreachable_patterns)]
+
+use core::convert::Infallible;
+
+use packable::{Packable, PackableExt};
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = bool)]
+#[packable(unpack_error = Infallible)]
+pub enum Amount {
+    #[packable(tag = false)]
+    Small(u8),
+    #[packable(tag = true)]
+    Large(u64),
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = bool)]
+pub enum Switch {
+    #[packable(tag = true)]
+    On,
+    #[packable(tag = false)]
+    Off,
+}
+
+fn main() {
+    let small = Amount::Small(7);
+    assert_eq!(small.pack_to_vec(), [0, 7]);
+    assert_eq!(Amount::unpack_verified([0, 7], &()).unwrap(), small);
+
+    let large = Amount::Large(7);
+    assert_eq!(large.pack_to_vec(), [1, 7, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Amount::unpack_verified(large.pack_to_vec(), &()).unwrap(), large);
+
+    assert_eq!(Switch::On.pack_to_vec(), [1]);
+    assert_eq!(Switch::unpack_verified([0], &()).unwrap(), Switch::Off);
+}
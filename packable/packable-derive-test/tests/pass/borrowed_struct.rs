@@ -0,0 +1,62 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{error::UnpackError, Packable, PackableRef};
+
+#[derive(Debug, PartialEq)]
+pub struct EmptyBodyError;
+
+impl From<Infallible> for EmptyBodyError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+fn verify_body<const VERIFY: bool>(body: &&[u8]) -> Result<(), EmptyBodyError> {
+    if !VERIFY || !body.is_empty() {
+        Ok(())
+    } else {
+        Err(EmptyBodyError)
+    }
+}
+
+#[derive(Packable, Debug, PartialEq)]
+pub struct Body<'a>(&'a [u8]);
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(unpack_error = EmptyBodyError, with = |_| EmptyBodyError)]
+pub struct Frame<'a> {
+    kind: u8,
+    #[packable(verify_with = verify_body)]
+    body: &'a [u8],
+    #[packable(skip)]
+    cached: Option<u32>,
+    trailer: Body<'a>,
+}
+
+fn main() {
+    let frame = Frame {
+        kind: 1,
+        body: &[2, 3],
+        cached: Some(4),
+        trailer: Body(&[5]),
+    };
+
+    let mut bytes = Vec::new();
+    frame.pack_ref(&mut bytes).unwrap();
+    assert_eq!(bytes, [1, 2, 0, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 5]);
+
+    let unpacked = Frame::unpack_ref_verified(&bytes).unwrap();
+    assert_eq!(unpacked, Frame { cached: None, ..frame });
+    // The bytes of the fields are borrowed from the unpacked bytes.
+    assert_eq!(unpacked.body.as_ptr(), bytes[9..].as_ptr());
+
+    assert!(matches!(
+        Frame::unpack_ref_verified(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+        Err(UnpackError::Packable(EmptyBodyError))
+    ));
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{Packable, PackableExt, PackedSize, error::ArrayUnpackError};
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+#[packable(unpack_error = ArrayUnpackError<Infallible>)]
+#[packable(packed_size)]
+#[packable(inherent_helpers)]
+pub struct FixedBuf<const N: usize> {
+    data: [u8; N],
+}
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+#[packable(unpack_error = ArrayUnpackError<T::UnpackError>)]
+pub struct Words<T: Packable, const N: usize = 2>([T; N]);
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8, with_error = KeyError::UnknownTag)]
+#[packable(unpack_error = KeyError)]
+pub enum Key<const N: usize> {
+    #[packable(tag = 0)]
+    Public([u8; N]),
+    #[packable(tag = 1)]
+    Empty,
+}
+
+#[derive(Debug)]
+pub enum KeyError {
+    UnknownTag(u8),
+    Array(ArrayUnpackError<Infallible>),
+}
+
+impl From<Infallible> for KeyError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<ArrayUnpackError<Infallible>> for KeyError {
+    fn from(err: ArrayUnpackError<Infallible>) -> Self {
+        Self::Array(err)
+    }
+}
+
+fn main() {
+    let buf = FixedBuf { data: [1, 2, 3] };
+    let bytes = buf.pack_to_vec();
+    assert_eq!(bytes, [1, 2, 3]);
+    assert_eq!(FixedBuf::<3>::unpack_verified(&bytes, &()).unwrap(), buf);
+    assert_eq!(FixedBuf::<32>::PACKED_SIZE, 32);
+    assert_eq!(FixedBuf::<3>::from_packed_bytes(&buf.to_packed_bytes()).unwrap(), buf);
+
+    let words = Words([0x0102u16, 0x0304]);
+    let bytes = words.pack_to_vec();
+    assert_eq!(bytes, [2, 1, 4, 3]);
+    assert_eq!(Words::<u16>::unpack_verified(&bytes, &()).unwrap(), words);
+
+    let key = Key::Public([7; 4]);
+    let bytes = key.pack_to_vec();
+    assert_eq!(bytes, [0, 7, 7, 7, 7]);
+    assert_eq!(Key::<4>::unpack_verified(&bytes, &()).unwrap(), key);
+    assert_eq!(Key::<4>::Empty.pack_to_vec(), [1]);
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::{convert::Infallible, num::TryFromIntError};
+
+use packable::{
+    error::{ArrayUnpackError, UnpackError},
+    prefix::UnpackPrefixError,
+    Packable, PackableExt,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum CountError {
+    Count(TryFromIntError),
+    Item(usize),
+}
+
+impl From<Infallible> for CountError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<E> From<UnpackPrefixError<ArrayUnpackError<E>, TryFromIntError>> for CountError {
+    fn from(err: UnpackPrefixError<ArrayUnpackError<E>, TryFromIntError>) -> Self {
+        match err {
+            UnpackPrefixError::Item(err) => Self::Item(err.index),
+            UnpackPrefixError::Prefix(err) => Self::Count(err),
+        }
+    }
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(unpack_error = CountError)]
+pub struct Records {
+    count: u16,
+    kind: u8,
+    #[packable(count = count)]
+    values: Vec<u32>,
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(unpack_error = CountError)]
+pub struct Flags(u8, #[packable(count = 0)] Box<[bool]>);
+
+fn main() {
+    let records = Records {
+        count: 2,
+        kind: 7,
+        values: vec![1, 2],
+    };
+    let bytes = records.pack_to_vec();
+
+    assert_eq!(bytes, [2, 0, 7, 1, 0, 0, 0, 2, 0, 0, 0]);
+    assert_eq!(Records::unpack_verified(&bytes, &()).unwrap(), records);
+    assert!(matches!(
+        Records::unpack_verified(&bytes[..7], &()),
+        Err(UnpackError::Unpacker(packable::error::UnexpectedEOF { required: 4, had: 0 }))
+    ));
+
+    let flags = Flags(3, vec![true, false, true].into_boxed_slice());
+    let bytes = flags.pack_to_vec();
+
+    assert_eq!(bytes, [3, 1, 0, 1]);
+    assert_eq!(Flags::unpack_verified(&bytes, &()).unwrap(), flags);
+}
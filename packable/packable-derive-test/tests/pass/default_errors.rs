@@ -5,7 +5,11 @@
 
 use core::{any::TypeId, convert::Infallible};
 
-use packable::{error::UnknownTagError, Packable};
+use packable::{
+    error::UnknownTagError,
+    prefix::{UnpackPrefixError, VecPrefix},
+    Packable,
+};
 
 #[derive(Packable)]
 pub struct Point {
@@ -27,6 +31,37 @@ pub struct Bar {
     foo: Foo,
 }
 
+#[derive(Packable)]
+pub struct Header {
+    version: u8,
+    id: [u8; 4],
+}
+
+#[derive(Packable)]
+pub struct Message {
+    version: u8,
+    foo: Foo,
+    flag: bool,
+    other: Foo,
+}
+
+#[derive(Default)]
+pub struct Cache(Vec<String>);
+
+#[derive(Packable)]
+pub struct Cached {
+    #[packable(skip)]
+    cache: Cache,
+    id: u64,
+    flag: bool,
+}
+
+#[derive(Packable)]
+pub struct Prefixed {
+    #[packable(length_prefix = u8)]
+    items: Vec<u16>,
+}
+
 fn main() {
     assert_eq!(
         TypeId::of::<Infallible>(),
@@ -42,4 +77,24 @@ fn main() {
         TypeId::of::<UnknownTagError<u8>>(),
         TypeId::of::<<Bar as Packable>::UnpackError>()
     );
+
+    assert_eq!(
+        TypeId::of::<Infallible>(),
+        TypeId::of::<<Header as Packable>::UnpackError>()
+    );
+
+    assert_eq!(
+        TypeId::of::<UnknownTagError<u8>>(),
+        TypeId::of::<<Message as Packable>::UnpackError>()
+    );
+
+    assert_eq!(
+        TypeId::of::<Infallible>(),
+        TypeId::of::<<Cached as Packable>::UnpackError>()
+    );
+
+    assert_eq!(
+        TypeId::of::<UnpackPrefixError<Infallible, Infallible>>(),
+        TypeId::of::<<Prefixed as Packable>::UnpackError>()
+    );
 }
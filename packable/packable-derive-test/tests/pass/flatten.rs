@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{error::UnpackError, Packable, PackableExt};
+
+#[derive(Debug, PartialEq)]
+pub struct VersionError(u8);
+
+impl From<Infallible> for VersionError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+fn verify_version<const VERIFY: bool>(&version: &u8) -> Result<(), VersionError> {
+    if !VERIFY || version == 1 {
+        Ok(())
+    } else {
+        Err(VersionError(version))
+    }
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(unpack_error = VersionError)]
+pub struct Header {
+    #[packable(verify_with = verify_version)]
+    version: u8,
+    id: u16,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MessageError {
+    Version(VersionError),
+}
+
+impl From<Infallible> for MessageError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<VersionError> for MessageError {
+    fn from(err: VersionError) -> Self {
+        Self::Version(err)
+    }
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(unpack_error = MessageError)]
+pub struct Message {
+    #[packable(flatten)]
+    header: Header,
+    payload: u32,
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(presence_bitfield)]
+pub struct Extended {
+    #[packable(flatten)]
+    header: Option<u8>,
+    extra: Option<u8>,
+}
+
+fn main() {
+    let message = Message {
+        header: Header { version: 1, id: 0x0203 },
+        payload: 0x04050607,
+    };
+    let bytes = message.pack_to_vec();
+    assert_eq!(bytes, [1, 0x03, 0x02, 0x07, 0x06, 0x05, 0x04]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), message);
+
+    assert!(matches!(
+        Message::unpack_verified([2, 0, 0, 0, 0, 0, 0], &()),
+        Err(UnpackError::Packable(MessageError::Version(VersionError(2))))
+    ));
+
+    // Flattened fields are never part of the presence bitfield.
+    let extended = Extended {
+        header: Some(5),
+        extra: None,
+    };
+    assert_eq!(extended.pack_to_vec(), [0, 1, 5]);
+}
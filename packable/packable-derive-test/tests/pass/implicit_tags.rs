@@ -0,0 +1,44 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnknownTagError, UnpackError},
+    Packable, PackableExt,
+};
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = u8)]
+pub enum Shape {
+    Point,
+    Circle(u8),
+    #[packable(tag = 7)]
+    Square(u8),
+    Line(u8, u8),
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = bool)]
+#[packable(unpack_error = Infallible)]
+pub enum Switch {
+    Off,
+    On,
+}
+
+fn main() {
+    assert_eq!(Shape::Point.pack_to_vec(), [0]);
+    assert_eq!(Shape::Circle(2).pack_to_vec(), [1, 2]);
+    assert_eq!(Shape::Square(3).pack_to_vec(), [7, 3]);
+    assert_eq!(Shape::Line(4, 5).pack_to_vec(), [3, 4, 5]);
+    assert_eq!(Shape::unpack_verified([3, 4, 5], &()).unwrap(), Shape::Line(4, 5));
+    assert!(matches!(
+        Shape::unpack_verified([2], &()),
+        Err(UnpackError::Packable(UnknownTagError(2)))
+    ));
+
+    assert_eq!(Switch::Off.pack_to_vec(), [0]);
+    assert_eq!(Switch::On.pack_to_vec(), [1]);
+}
@@ -0,0 +1,37 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{Packable, error::UnpackError};
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(inherent_helpers)]
+pub struct Point {
+    x: i32,
+    y: u32,
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = u8)]
+#[packable(inherent_helpers)]
+pub enum Shape {
+    #[packable(tag = 0)]
+    Dot(Point),
+    #[packable(tag = 1)]
+    Line(Point, Point),
+}
+
+fn main() {
+    let point = Point { x: -1, y: 2 };
+    let bytes = point.to_packed_bytes();
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(Point::from_packed_bytes(&bytes).unwrap(), point);
+
+    let shape = Shape::Line(Point { x: 0, y: 0 }, Point { x: 3, y: 4 });
+    assert_eq!(Shape::from_packed_bytes(&shape.to_packed_bytes()).unwrap(), shape);
+    assert!(matches!(
+        Shape::from_packed_bytes(&[2]),
+        Err(UnpackError::Packable(packable::error::UnknownTagError(2)))
+    ));
+}
@@ -0,0 +1,31 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{Packable, PackableExt};
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(inline)]
+pub struct Header {
+    kind: u8,
+    len: u16,
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = u8)]
+#[packable(inline)]
+pub enum Tag {
+    #[packable(tag = 0)]
+    Ping,
+    #[packable(tag = 1)]
+    Pong(u32),
+}
+
+fn main() {
+    let header = Header { kind: 1, len: 2 };
+    assert_eq!(Header::unpack_verified(header.pack_to_vec(), &()).unwrap(), header);
+
+    let tag = Tag::Pong(3);
+    assert_eq!(Tag::unpack_verified(tag.pack_to_vec(), &()).unwrap(), tag);
+}
@@ -0,0 +1,92 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnpackError, VariantLengthError},
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+#[derive(Debug)]
+pub enum MessageError {
+    UnknownTag(u8),
+    VariantLength(VariantLengthError),
+}
+
+impl From<Infallible> for MessageError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<VariantLengthError> for MessageError {
+    fn from(err: VariantLengthError) -> Self {
+        Self::VariantLength(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+#[packable(length_prefixed_variants)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    Data { id: u8, value: u16 },
+    #[packable(tag = 2)]
+    Empty,
+}
+
+fn main() {
+    let bytes = Message::Ping(0x0102).pack_to_vec();
+    assert_eq!(bytes, [0, 2, 0, 0, 0, 0x02, 0x01]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), Message::Ping(0x0102));
+
+    let message = Message::Data { id: 7, value: 8 };
+    let bytes = message.pack_to_vec();
+    assert_eq!(bytes, [1, 3, 0, 0, 0, 7, 8, 0]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), message);
+
+    assert_eq!(Message::Empty.pack_to_vec(), [2, 0, 0, 0, 0]);
+
+    // Bytes appended to a known variant by a newer writer are skipped.
+    let mut unpacker = SliceUnpacker::new(&[0, 4, 0, 0, 0, 0x02, 0x01, 0xFF, 0xFF, 2, 0, 0, 0, 0]);
+    assert_eq!(
+        Message::unpack::<_, true>(&mut unpacker, &()).unwrap(),
+        Message::Ping(0x0102)
+    );
+    assert_eq!(Message::unpack::<_, true>(&mut unpacker, &()).unwrap(), Message::Empty);
+
+    // The payload of an unknown variant is skipped before returning an error.
+    let mut unpacker = SliceUnpacker::new(&[9, 2, 0, 0, 0, 0xFF, 0xFF, 2, 0, 0, 0, 0]);
+    assert!(matches!(
+        Message::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Packable(MessageError::UnknownTag(9)))
+    ));
+    assert_eq!(Message::unpack::<_, true>(&mut unpacker, &()).unwrap(), Message::Empty);
+
+    // A payload longer than its declared length is rejected.
+    assert!(matches!(
+        Message::unpack_verified([0, 1, 0, 0, 0, 0x02, 0x01], &()),
+        Err(UnpackError::Packable(MessageError::VariantLength(VariantLengthError {
+            len: 1,
+            consumed: 2
+        })))
+    ));
+
+    // The payload never reads the bytes that follow its declared length, even without verification.
+    let mut unpacker = SliceUnpacker::new(&[0, 1, 0, 0, 0, 0x02, 0x01]);
+    assert!(matches!(
+        Message::unpack::<_, false>(&mut unpacker, &()),
+        Err(UnpackError::Packable(MessageError::VariantLength(VariantLengthError {
+            len: 1,
+            consumed: 2
+        })))
+    ));
+    assert_eq!(unpacker.remaining_bytes(), Some(2));
+}
@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::{convert::Infallible, time::Duration};
+
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable, PackableExt,
+};
+
+const VERSION: u8 = 2;
+
+fn pack_seconds<P: Packer>(duration: &Duration, packer: &mut P) -> Result<(), P::Error> {
+    (duration.as_secs() as u32).pack(packer)
+}
+
+fn unpack_seconds<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+) -> Result<Duration, UnpackError<Infallible, U::Error>> {
+    let secs = u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+
+    Ok(Duration::from_secs(secs.into()))
+}
+
+fn pack_version<P: Packer>(_: &u8, packer: &mut P) -> Result<(), P::Error> {
+    VERSION.pack(packer)
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(unpack_error = Infallible)]
+pub struct Session {
+    #[packable(pack_with = pack_seconds)]
+    #[packable(unpack_with = unpack_seconds)]
+    timeout: Duration,
+    #[packable(pack_with = pack_version)]
+    version: u8,
+    id: u16,
+}
+
+fn main() {
+    let session = Session {
+        timeout: Duration::from_millis(3500),
+        version: 1,
+        id: 7,
+    };
+    let bytes = session.pack_to_vec();
+
+    assert_eq!(bytes, [3, 0, 0, 0, 2, 7, 0]);
+    assert_eq!(
+        Session::unpack_verified(&bytes, &()).unwrap(),
+        Session {
+            timeout: Duration::from_secs(3),
+            version: VERSION,
+            id: 7,
+        }
+    );
+}
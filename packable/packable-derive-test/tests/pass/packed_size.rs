@@ -0,0 +1,44 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{bounded::BoundedU8, endian::Be, Packable, PackableExt, PackedSize};
+
+#[derive(Packable)]
+#[packable(packed_size)]
+pub struct Triple(u32, u32, u32);
+
+#[derive(Default)]
+pub struct Cache(Vec<u8>);
+
+#[derive(Packable)]
+#[packable(packed_size)]
+pub struct Header {
+    flags: [bool; 3],
+    port: Be<u16>,
+    #[packable(skip)]
+    cache: Cache,
+    triple: Triple,
+}
+
+#[derive(Packable)]
+#[packable(packed_size)]
+pub struct Empty;
+
+const _: () = assert!(Triple::PACKED_SIZE == 12);
+const _: () = assert!(BoundedU8::<1, 4>::PACKED_SIZE == 1);
+
+fn main() {
+    let buffer = [0u8; Header::PACKED_SIZE];
+    assert_eq!(buffer.len(), 17);
+    assert_eq!(Empty::PACKED_SIZE, 0);
+
+    let header = Header {
+        flags: [true, false, true],
+        port: Be(8080),
+        cache: Cache::default(),
+        triple: Triple(1, 2, 3),
+    };
+    assert_eq!(header.packed_len(), Header::PACKED_SIZE);
+}
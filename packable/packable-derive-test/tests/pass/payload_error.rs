@@ -0,0 +1,160 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{EnumUnpackError, TrailingBytesError, UnknownTagError, UnpackError, VariantLengthError},
+    Packable, PackableExt,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum PayloadError {
+    Flag(u8),
+    Percent(u8),
+    TrailingBytes(usize),
+    VariantLength,
+}
+
+impl From<Infallible> for PayloadError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnknownTagError<u8>> for PayloadError {
+    fn from(err: UnknownTagError<u8>) -> Self {
+        Self::Flag(err.0)
+    }
+}
+
+impl From<TrailingBytesError> for PayloadError {
+    fn from(err: TrailingBytesError) -> Self {
+        Self::TrailingBytes(err.remaining)
+    }
+}
+
+impl From<VariantLengthError> for PayloadError {
+    fn from(_: VariantLengthError) -> Self {
+        Self::VariantLength
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8)]
+pub enum Flag {
+    #[packable(tag = 0)]
+    Off,
+    #[packable(tag = 1)]
+    On,
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8)]
+#[packable(payload_error = PayloadError)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping,
+    #[packable(tag = 1)]
+    Set(u16, Flag),
+    #[packable(tag = 2)]
+    Close(Flag),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8)]
+#[packable(length_prefixed_variants)]
+#[packable(payload_error = PayloadError)]
+pub enum Versioned {
+    #[packable(tag = 0)]
+    Flag(Flag),
+    #[packable(tag = 2)]
+    #[packable(strict_len)]
+    Close(Flag),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(packed_size)]
+#[packable(unpack_error = PayloadError)]
+#[packable(verify_with = verify_percent)]
+pub struct Percent(u8);
+
+fn verify_percent<const VERIFY: bool>(percent: &Percent) -> Result<(), PayloadError> {
+    if VERIFY && percent.0 > 100 {
+        Err(PayloadError::Percent(percent.0))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8)]
+#[packable(tag_after)]
+#[packable(payload_error = PayloadError)]
+pub enum Trailing {
+    #[packable(tag = 0)]
+    Percent(Percent),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u16)]
+#[packable(payload_error = u8, with = |err: UnknownTagError<u8>| err.0)]
+pub enum Mapped {
+    #[packable(tag = 7)]
+    Flag(Flag),
+}
+
+fn main() {
+    assert_eq!(Message::unpack_verified([1, 5, 0, 1], &()).unwrap(), Message::Set(5, Flag::On));
+
+    assert!(matches!(
+        Message::unpack_verified([3], &()),
+        Err(UnpackError::Packable(EnumUnpackError::UnknownTag(3)))
+    ));
+    assert!(matches!(
+        Message::unpack_verified([1, 5, 0, 2], &()),
+        Err(UnpackError::Packable(EnumUnpackError::Payload {
+            tag: 1,
+            inner: PayloadError::Flag(2)
+        }))
+    ));
+    assert!(matches!(
+        Message::unpack_verified([1, 5], &()),
+        Err(UnpackError::Unpacker(_))
+    ));
+
+    assert_eq!(Versioned::unpack_verified([0, 1, 0, 0, 0, 1], &()).unwrap(), Versioned::Flag(Flag::On));
+    assert!(matches!(
+        Versioned::unpack_verified([1, 0, 0, 0, 0], &()),
+        Err(UnpackError::Packable(EnumUnpackError::UnknownTag(1)))
+    ));
+    assert!(matches!(
+        Versioned::unpack_verified([0, 1, 0, 0, 0, 4], &()),
+        Err(UnpackError::Packable(EnumUnpackError::Payload {
+            tag: 0,
+            inner: PayloadError::Flag(4)
+        }))
+    ));
+    assert!(matches!(
+        Versioned::unpack_verified([2, 2, 0, 0, 0, 0, 0xFF], &()),
+        Err(UnpackError::Packable(EnumUnpackError::Payload {
+            tag: 2,
+            inner: PayloadError::TrailingBytes(1)
+        }))
+    ));
+
+    assert!(matches!(
+        Trailing::unpack_verified([101, 0], &()),
+        Err(UnpackError::Packable(EnumUnpackError::Payload {
+            tag: 0,
+            inner: PayloadError::Percent(101)
+        }))
+    ));
+
+    assert!(matches!(
+        Mapped::unpack_verified([7, 0, 9], &()),
+        Err(UnpackError::Packable(EnumUnpackError::Payload { tag: 7, inner: 9 }))
+    ));
+}
@@ -0,0 +1,65 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::{any::TypeId, convert::Infallible};
+
+use packable::{Packable, PackableExt};
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(presence_bitfield)]
+pub struct Settings {
+    timeout: Option<u16>,
+    version: u8,
+    retries: Option<u8>,
+    #[packable(skip)]
+    cache: Option<u64>,
+    name: Option<[u8; 2]>,
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(presence_bitfield)]
+pub struct Wide(
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+    Option<u8>,
+);
+
+fn main() {
+    assert_eq!(
+        TypeId::of::<Infallible>(),
+        TypeId::of::<<Settings as Packable>::UnpackError>()
+    );
+
+    let settings = Settings {
+        timeout: Some(0x0102),
+        version: 3,
+        retries: None,
+        cache: None,
+        name: Some([4, 5]),
+    };
+    let bytes = settings.pack_to_vec();
+    assert_eq!(bytes, [0b101, 0x02, 0x01, 3, 4, 5]);
+    assert_eq!(Settings::unpack_verified(&bytes, &()).unwrap(), settings);
+
+    let empty = Settings {
+        timeout: None,
+        version: 7,
+        retries: None,
+        cache: None,
+        name: None,
+    };
+    assert_eq!(empty.pack_to_vec(), [0, 7]);
+
+    let wide = Wide(None, None, None, None, None, None, None, None, Some(9));
+    let bytes = wide.pack_to_vec();
+    assert_eq!(bytes, [0, 1, 9]);
+    assert_eq!(Wide::unpack_verified(&bytes, &()).unwrap(), wide);
+}
@@ -0,0 +1,85 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{TrailingBytesError, UnknownTagError, UnpackError, VariantLengthError},
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+#[derive(Debug)]
+pub enum FrameError {
+    UnknownTag(u8),
+    TrailingBytes(usize),
+    VariantLength(VariantLengthError),
+}
+
+impl From<Infallible> for FrameError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<TrailingBytesError> for FrameError {
+    fn from(err: TrailingBytesError) -> Self {
+        Self::TrailingBytes(err.remaining)
+    }
+}
+
+impl From<VariantLengthError> for FrameError {
+    fn from(err: VariantLengthError) -> Self {
+        Self::VariantLength(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8, with_error = FrameError::UnknownTag)]
+#[packable(unpack_error = FrameError)]
+#[packable(length_prefixed_variants)]
+pub enum Frame {
+    #[packable(tag = 0)]
+    #[packable(strict_len)]
+    Ping(u32),
+    #[packable(tag = 1)]
+    Pong(u32),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = FrameError)]
+pub struct Pair {
+    a: Frame,
+    b: u8,
+}
+
+fn main() {
+    assert_eq!(
+        Frame::unpack_verified([0, 4, 0, 0, 0, 1, 0, 0, 0], &()).unwrap(),
+        Frame::Ping(1)
+    );
+    assert!(matches!(
+        Frame::unpack_verified([0, 6, 0, 0, 0, 1, 0, 0, 0, 0xFF, 0xFF], &()),
+        Err(UnpackError::Packable(FrameError::TrailingBytes(2)))
+    ));
+    assert_eq!(
+        Frame::unpack_unverified([0, 6, 0, 0, 0, 1, 0, 0, 0, 0xFF, 0xFF]).unwrap(),
+        Frame::Ping(1)
+    );
+    assert_eq!(
+        Frame::unpack_verified([1, 5, 0, 0, 0, 1, 0, 0, 0, 0xFF], &()).unwrap(),
+        Frame::Pong(1)
+    );
+
+    // Only the bytes left in the payload are rejected, not the ones of the values that follow it.
+    let pair = Pair { a: Frame::Ping(1), b: 7 };
+    let bytes = pair.pack_to_vec();
+    assert_eq!(bytes, [0, 4, 0, 0, 0, 1, 0, 0, 0, 7]);
+    assert_eq!(Pair::unpack_verified(&bytes, &()).unwrap(), pair);
+
+    let mut unpacker = SliceUnpacker::new(&[0, 4, 0, 0, 0, 1, 0, 0, 0, 0xFF]);
+    assert_eq!(Frame::unpack::<_, true>(&mut unpacker, &()).unwrap(), Frame::Ping(1));
+    assert_eq!(unpacker.remaining_bytes(), Some(1));
+}
@@ -0,0 +1,28 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{error::UnknownTagError, Packable, PackableExt};
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u16)]
+#[packable(unpack_error = UnknownTagError<u16>)]
+pub enum Command {
+    #[packable(tag = "0x0A")]
+    Ping,
+    #[packable(tag = "0o17")]
+    Pong,
+    #[packable(tag = "0b1000_0000")]
+    Reset,
+    #[packable(tag = "1_000")]
+    Shutdown,
+}
+
+fn main() {
+    assert_eq!(Command::Ping.pack_to_vec(), [0x0A, 0x00]);
+    assert_eq!(Command::Pong.pack_to_vec(), [0o17, 0x00]);
+    assert_eq!(Command::Reset.pack_to_vec(), [0x80, 0x00]);
+    assert_eq!(Command::Shutdown.pack_to_vec(), 1000u16.to_le_bytes());
+    assert_eq!(Command::unpack_verified([0x80, 0x00], &()).unwrap(), Command::Reset);
+}
@@ -0,0 +1,163 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    Packable, PackableExt, PackedSize,
+    error::{TrailingBytesError, UnknownTagError, UnpackError, VariantLengthError},
+    prefix::UnpackPrefixError,
+    unpacker::ChainUnpacker,
+};
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8)]
+#[packable(tag_after)]
+pub enum Record {
+    #[packable(tag = 0)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    Data { id: u8, value: u8 },
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8)]
+#[packable(tag_after)]
+pub enum Either<T: PackedSize<UnpackError = Infallible, UnpackVisitor = ()>> {
+    #[packable(tag = 0)]
+    Left(T),
+    #[packable(tag = 1)]
+    Right(T),
+}
+
+#[derive(Debug)]
+pub enum MessageError {
+    UnknownTag(u8),
+    VariantLength(VariantLengthError),
+    TrailingBytes(usize),
+}
+
+impl From<Infallible> for MessageError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnpackPrefixError<Infallible, Infallible>> for MessageError {
+    fn from(err: UnpackPrefixError<Infallible, Infallible>) -> Self {
+        match err {}
+    }
+}
+
+impl From<VariantLengthError> for MessageError {
+    fn from(err: VariantLengthError) -> Self {
+        Self::VariantLength(err)
+    }
+}
+
+impl From<TrailingBytesError> for MessageError {
+    fn from(err: TrailingBytesError) -> Self {
+        Self::TrailingBytes(err.remaining)
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+#[packable(length_prefixed_variants)]
+#[packable(tag_after)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    Data(#[packable(length_prefix = u8)] Vec<u8>),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+#[packable(length_prefixed_variants = u8)]
+#[packable(tag_after)]
+pub enum ShortMessage {
+    #[packable(tag = 0)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    #[packable(strict_len)]
+    Pong(u16),
+}
+
+fn main() {
+    // The tag is packed after the payload of the variant.
+    let bytes = Record::Ping(0x0102).pack_to_vec();
+    assert_eq!(bytes, [0x02, 0x01, 0]);
+    assert_eq!(Record::unpack_verified(&bytes, &()).unwrap(), Record::Ping(0x0102));
+
+    let record = Record::Data { id: 7, value: 8 };
+    let bytes = record.pack_to_vec();
+    assert_eq!(bytes, [7, 8, 1]);
+    assert_eq!(Record::unpack_verified(&bytes, &()).unwrap(), record);
+
+    assert!(matches!(
+        Record::unpack_verified([7, 8, 2], &()),
+        Err(UnpackError::Packable(UnknownTagError(2)))
+    ));
+
+    let either = Either::Right(0x0102u16);
+    let bytes = either.pack_to_vec();
+    assert_eq!(bytes, [0x02, 0x01, 1]);
+    assert_eq!(Either::unpack_verified(&bytes, &()).unwrap(), either);
+
+    // Variable-length payloads are preceded by their length and followed by the tag.
+    let message = Message::Data(vec![1, 2, 3]);
+    let bytes = message.pack_to_vec();
+    assert_eq!(bytes, [4, 0, 0, 0, 3, 1, 2, 3, 1]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), message);
+
+    // Bytes appended to a known variant by a newer writer are skipped.
+    assert_eq!(
+        Message::unpack_verified([3, 0, 0, 0, 0x02, 0x01, 0xFF, 0], &()).unwrap(),
+        Message::Ping(0x0102)
+    );
+
+    // The length prefix of the enum is packed first.
+    let bytes = ShortMessage::Ping(0x0102).pack_to_vec();
+    assert_eq!(bytes, [2, 0x02, 0x01, 0]);
+    assert_eq!(
+        ShortMessage::unpack_verified(&bytes, &()).unwrap(),
+        ShortMessage::Ping(0x0102)
+    );
+
+    // The bytes left in the payload of a variant with a strict length are rejected when verifying.
+    assert!(matches!(
+        ShortMessage::unpack_verified([3, 0x02, 0x01, 0xFF, 1], &()),
+        Err(UnpackError::Packable(MessageError::TrailingBytes(1)))
+    ));
+    assert_eq!(
+        ShortMessage::unpack_unverified([3, 0x02, 0x01, 0xFF, 1]).unwrap(),
+        ShortMessage::Pong(0x0102)
+    );
+
+    // A payload shorter than its variant requires is rejected.
+    assert!(matches!(
+        Message::unpack_verified([1, 0, 0, 0, 0x02, 0], &()),
+        Err(UnpackError::Packable(MessageError::VariantLength(VariantLengthError {
+            len: 1,
+            consumed: 2
+        })))
+    ));
+
+    // An oversized length prefix fails once the bytes run out, even if the unpacker does not know its remaining bytes.
+    assert!(matches!(
+        Message::unpack_verified([0xFF, 0xFF, 0xFF, 0xFF, 0x02, 0x01, 0], &()),
+        Err(UnpackError::Unpacker(_))
+    ));
+    assert!(matches!(
+        Message::unpack::<_, true>(
+            &mut ChainUnpacker::new([[0xFF, 0xFF, 0xFF, 0xFF].as_slice(), &[0x02, 0x01, 0]]),
+            &()
+        ),
+        Err(UnpackError::Unpacker(_))
+    ));
+}
@@ -0,0 +1,50 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{unpacker::SliceUnpacker, Packable, PackableExt};
+
+const PING: u16 = 7;
+
+#[derive(Packable, Debug, PartialEq)]
+#[packable(tag_type = u16)]
+#[packable(tag_consts)]
+pub enum Message {
+    #[packable(tag = PING)]
+    Ping,
+    #[packable(tag = 0x10)]
+    HttpRequest(u32),
+    #[packable(tag = 0x20)]
+    IOError { code: u8 },
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[repr(u8)]
+#[packable(tag_consts)]
+pub enum Implicit {
+    First,
+    Second(u8),
+}
+
+fn main() {
+    assert_eq!(Message::PING_TAG, 7);
+    assert_eq!(Message::HTTP_REQUEST_TAG, 0x10);
+    assert_eq!(Message::IO_ERROR_TAG, 0x20);
+
+    for message in [Message::Ping, Message::HttpRequest(1), Message::IOError { code: 2 }] {
+        let tag = u16::unpack_verified(&message.pack_to_vec()[..2], &()).unwrap();
+        assert_eq!(message.tag(), tag);
+    }
+
+    const SECOND: u8 = Implicit::Second(0).tag();
+    assert_eq!(Implicit::FIRST_TAG, 0);
+    assert_eq!(SECOND, Implicit::SECOND_TAG);
+    assert_eq!(Implicit::Second(3).pack_to_vec(), [Implicit::SECOND_TAG, 3]);
+
+    let bytes = Implicit::Second(3).pack_to_vec();
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    assert_eq!(Implicit::peek_tag(&mut unpacker).unwrap(), Some(Implicit::SECOND_TAG));
+    assert_eq!(Implicit::unpack::<_, true>(&mut unpacker, &()).unwrap(), Implicit::Second(3));
+    assert_eq!(Implicit::peek_tag(&mut unpacker).unwrap(), None);
+}
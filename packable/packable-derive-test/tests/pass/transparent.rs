@@ -0,0 +1,46 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{Packable, PackableExt, PackedSize};
+
+#[derive(Packable, Debug, PartialEq)]
+#[repr(transparent)]
+#[packable(transparent)]
+#[packable(packed_size)]
+pub struct Id(u64);
+
+#[derive(Packable, Debug, PartialEq)]
+#[repr(transparent)]
+#[packable(transparent)]
+pub struct Name {
+    bytes: [u8; 4],
+}
+
+#[derive(Packable, Debug, PartialEq)]
+#[repr(transparent)]
+#[packable(transparent)]
+pub struct Wrapper<T: Packable>(T);
+
+// The implementation of a transparent struct uses the error of its field.
+fn unpack_error(err: <Id as Packable>::UnpackError) -> <u64 as Packable>::UnpackError {
+    err
+}
+
+fn main() {
+    let id = Id(0x0102030405060708);
+    assert_eq!(id.pack_to_vec(), 0x0102030405060708u64.pack_to_vec());
+    assert_eq!(Id::unpack_verified(id.pack_to_vec(), &()).unwrap(), id);
+    assert_eq!(Id::PACKED_SIZE, u64::PACKED_SIZE);
+
+    let name = Name { bytes: *b"name" };
+    assert_eq!(name.pack_to_vec(), b"name");
+    assert_eq!(Name::unpack_verified(b"name", &()).unwrap(), name);
+
+    let wrapper = Wrapper(Some(7u16));
+    assert_eq!(wrapper.pack_to_vec(), Some(7u16).pack_to_vec());
+    assert_eq!(Wrapper::unpack_verified(wrapper.pack_to_vec(), &()).unwrap(), wrapper);
+}
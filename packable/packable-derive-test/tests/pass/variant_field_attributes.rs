@@ -0,0 +1,105 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::UnknownTagError,
+    prefix::{UnpackPrefixError, VecPrefix},
+    Packable, PackableExt,
+};
+
+#[derive(Debug)]
+pub enum MessageError {
+    UnknownTag(u8),
+    Payload,
+}
+
+impl From<Infallible> for MessageError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnknownTagError<u8>> for MessageError {
+    fn from(err: UnknownTagError<u8>) -> Self {
+        Self::UnknownTag(err.0)
+    }
+}
+
+impl From<UnpackPrefixError<Infallible, Infallible>> for MessageError {
+    fn from(_: UnpackPrefixError<Infallible, Infallible>) -> Self {
+        Self::Payload
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping(u32),
+    #[packable(tag = 1)]
+    Data(#[packable(length_prefix = u16)] Vec<u8>),
+    #[packable(tag = 2)]
+    Cached {
+        id: u32,
+        #[packable(skip)]
+        hits: u64,
+        #[packable(length_prefix = u8)]
+        body: Vec<u16>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+#[packable(unpack_error = MessageError)]
+pub struct Envelope {
+    #[packable(skip)]
+    received: bool,
+    #[packable(length_prefix = u8)]
+    payload: Vec<u8>,
+}
+
+fn main() {
+    let data = Message::Data(vec![0xAA, 0xBB, 0xCC]);
+    let bytes = data.pack_to_vec();
+    assert_eq!(bytes, [1, 3, 0, 0xAA, 0xBB, 0xCC]);
+    assert_eq!(bytes.len(), data.packed_len());
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), data);
+
+    // The encoding matches the one of `VecPrefix`.
+    let prefixed = VecPrefix::<u8, u16>::try_from(vec![0xAA, 0xBB, 0xCC]).unwrap();
+    assert_eq!(bytes[1..], prefixed.pack_to_vec());
+
+    let cached = Message::Cached {
+        id: 7,
+        hits: 42,
+        body: vec![1, 2],
+    };
+    let bytes = cached.pack_to_vec();
+    assert_eq!(bytes, [2, 7, 0, 0, 0, 2, 1, 0, 2, 0]);
+    assert_eq!(
+        Message::unpack_verified(&bytes, &()).unwrap(),
+        Message::Cached {
+            id: 7,
+            hits: 0,
+            body: vec![1, 2],
+        }
+    );
+
+    let envelope = Envelope {
+        received: true,
+        payload: vec![9, 8],
+    };
+    let bytes = envelope.pack_to_vec();
+    assert_eq!(bytes, [2, 9, 8]);
+    assert_eq!(
+        Envelope::unpack_verified(&bytes, &()).unwrap(),
+        Envelope {
+            received: false,
+            payload: vec![9, 8],
+        }
+    );
+}
@@ -0,0 +1,110 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    Packable, PackableExt,
+    error::{UnpackError, VariantLengthError},
+    unpacker::SliceUnpacker,
+};
+
+#[derive(Debug)]
+pub enum MessageError {
+    UnknownTag(u8),
+    VariantLength(VariantLengthError),
+}
+
+impl From<Infallible> for MessageError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<VariantLengthError> for MessageError {
+    fn from(err: VariantLengthError) -> Self {
+        Self::VariantLength(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+#[packable(length_prefixed_variants)]
+pub enum Message {
+    #[packable(tag = 0)]
+    #[packable(length_prefix = u8)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    #[packable(length_prefix = u16)]
+    Data { id: u8, value: u16 },
+    #[packable(tag = 2)]
+    Count(u8),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+#[packable(length_prefixed_variants = u16)]
+pub enum Event {
+    #[packable(tag = 0)]
+    Ping(u16),
+    #[packable(tag = 1)]
+    #[packable(length_prefix = u8)]
+    Count(u8),
+}
+
+fn main() {
+    let bytes = Message::Ping(0x0102).pack_to_vec();
+    assert_eq!(bytes, [0, 2, 0x02, 0x01]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), Message::Ping(0x0102));
+
+    let message = Message::Data { id: 7, value: 8 };
+    let bytes = message.pack_to_vec();
+    assert_eq!(bytes, [1, 3, 0, 7, 8, 0]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), message);
+
+    // Variants without a `length_prefix` attribute keep the `u32` prefix.
+    let message = Message::Count(9);
+    let bytes = message.pack_to_vec();
+    assert_eq!(bytes, [2, 1, 0, 0, 0, 9]);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), message);
+
+    // Bytes appended to a known variant are skipped using the prefix of that variant.
+    let mut unpacker = SliceUnpacker::new(&[0, 3, 0x02, 0x01, 0xFF, 1, 3, 0, 7, 8, 0]);
+    assert_eq!(
+        Message::unpack::<_, true>(&mut unpacker, &()).unwrap(),
+        Message::Ping(0x0102)
+    );
+    assert_eq!(
+        Message::unpack::<_, true>(&mut unpacker, &()).unwrap(),
+        Message::Data { id: 7, value: 8 }
+    );
+
+    // Variants without a `length_prefix` attribute use the prefix of the enum.
+    let bytes = Event::Ping(0x0102).pack_to_vec();
+    assert_eq!(bytes, [0, 2, 0, 0x02, 0x01]);
+    assert_eq!(Event::unpack_verified(&bytes, &()).unwrap(), Event::Ping(0x0102));
+    assert_eq!(Event::Count(9).pack_to_vec(), [1, 1, 9]);
+
+    // The payload of an unknown variant is skipped using the prefix of the enum.
+    let mut unpacker = SliceUnpacker::new(&[9, 3, 0, 0xFF, 0xFF, 0xFF, 0, 2, 0, 0x02, 0x01]);
+    assert!(matches!(
+        Event::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Packable(MessageError::UnknownTag(9)))
+    ));
+    assert_eq!(
+        Event::unpack::<_, true>(&mut unpacker, &()).unwrap(),
+        Event::Ping(0x0102)
+    );
+
+    assert!(matches!(
+        Message::unpack_verified([1, 2, 0, 7, 8, 0], &()),
+        Err(UnpackError::Packable(MessageError::VariantLength(VariantLengthError {
+            len: 2,
+            consumed: 3
+        })))
+    ));
+}
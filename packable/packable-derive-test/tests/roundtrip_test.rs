@@ -0,0 +1,25 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The tests of this file are generated by the `roundtrip_test` attribute.
+
+use packable::Packable;
+
+#[derive(Default, Packable)]
+#[packable(roundtrip_test)]
+pub struct Settings {
+    version: u8,
+    #[packable(length_prefix = u16)]
+    peers: Vec<u32>,
+}
+
+#[derive(Default, Packable)]
+#[packable(tag_type = u8)]
+#[packable(roundtrip_test)]
+pub enum Mode {
+    #[default]
+    #[packable(tag = 0)]
+    Idle,
+    #[packable(tag = 1)]
+    Active(u32),
+}
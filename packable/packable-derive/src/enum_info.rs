@@ -1,17 +1,25 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use syn::{parse_quote, Attribute, DataEnum, Field, Ident, Result, Type};
+use syn::{
+    parse::ParseStream, parse_quote, spanned::Spanned, Attribute, DataEnum, Error, Field, Ident, Result, Token, Type,
+};
 
 use crate::{
-    parse::filter_attrs, tag_type_info::TagTypeInfo, unpack_error_info::UnpackErrorInfo,
+    parse::{filter_attrs, parse_flag, parse_kv, parse_kv_after_comma, skip_stream},
+    tag_type_info::TagTypeInfo, unpack_error_info::UnpackErrorInfo,
     unpack_visitor_info::UnpackVisitorInfo, variant_info::VariantInfo,
 };
 
 pub(crate) struct EnumInfo {
     pub(crate) unpack_error: UnpackErrorInfo,
+    /// The error type of the payloads of the variants, if unknown tags and payload errors are distinguished.
+    pub(crate) payload_error: Option<Type>,
     pub(crate) unpack_visitor: UnpackVisitorInfo,
     pub(crate) tag_type: TagTypeInfo,
+    /// The default length prefix of the payloads of the variants, if they are prefixed by their length.
+    pub(crate) length_prefixed_variants: Option<Type>,
+    pub(crate) tag_after: bool,
     pub(crate) variants_info: Vec<VariantInfo>,
 }
 
@@ -28,10 +36,87 @@ impl EnumInfo {
         let tag_type = TagTypeInfo::new(&ident, filtered_attrs.clone(), &repr_type, crate_name)?;
         let tag_ty = &tag_type.tag_type;
 
-        let unpack_error = UnpackErrorInfo::new(
-            filtered_attrs.clone(),
-            || parse_quote!(#crate_name::error::UnknownTagError<#tag_ty>),
-        )?;
+        let mut payload_error = None;
+
+        for attr in filtered_attrs.clone() {
+            if let Some(info) =
+                attr.parse_args_with(|stream: ParseStream| match parse_kv::<Type>("payload_error", stream)? {
+                    Some(payload_error) => {
+                        let with = parse_kv_after_comma("with", stream)?
+                            .unwrap_or_else(|| parse_quote!(core::convert::identity));
+
+                        Ok(Some((attr, payload_error, with)))
+                    }
+                    None => {
+                        skip_stream(stream)?;
+                        Ok(None)
+                    }
+                })?
+            {
+                payload_error = Some(info);
+                break;
+            }
+        }
+
+        let (unpack_error, payload_error) = match payload_error {
+            Some((attr, payload_error, with)) => {
+                for other_attr in filtered_attrs.clone() {
+                    if other_attr.parse_args_with(|stream: ParseStream| {
+                        let found = parse_flag("unpack_error", stream)?;
+                        skip_stream(stream)?;
+                        Ok(found)
+                    })? {
+                        return Err(Error::new(
+                            attr.span(),
+                            "The `payload_error` attribute cannot be used along with the `unpack_error` attribute.",
+                        ));
+                    }
+                }
+
+                let unpack_error = UnpackErrorInfo {
+                    unpack_error: parse_quote!(#crate_name::error::EnumUnpackError<#tag_ty, #payload_error>),
+                    with,
+                };
+
+                (unpack_error, Some(payload_error))
+            }
+            None => {
+                let unpack_error = UnpackErrorInfo::new(
+                    filtered_attrs.clone(),
+                    || parse_quote!(#crate_name::error::UnknownTagError<#tag_ty>),
+                )?;
+
+                (unpack_error, None)
+            }
+        };
+
+        let mut length_prefixed_variants = None;
+        let mut tag_after = false;
+
+        for attr in filtered_attrs.clone() {
+            if let Some(length_prefix) = attr.parse_args_with(|stream: ParseStream| {
+                if !parse_flag("length_prefixed_variants", stream)? {
+                    skip_stream(stream)?;
+                    return Ok(None);
+                }
+                // The payloads are prefixed by a `u32` unless another type is given.
+                if stream.is_empty() {
+                    return Ok(Some(parse_quote!(u32)));
+                }
+                stream.parse::<Token![=]>()?;
+                stream.parse::<Type>().map(Some)
+            })? {
+                length_prefixed_variants = Some(length_prefix);
+            } else if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("tag_after", stream)?;
+                if !found {
+                    skip_stream(stream)?;
+                }
+                Ok(found)
+            })? {
+                tag_after = true;
+            }
+        }
 
         let unpack_visitor = UnpackVisitorInfo::new(filtered_attrs, || {
             let (unpack_visitor, explicit) = match data
@@ -50,16 +135,31 @@ impl EnumInfo {
             })
         })?;
 
+        let explicit_discriminants = data.variants.iter().any(|variant| variant.discriminant.is_some());
+
         let variants_info = data
             .variants
             .iter()
-            .map(|variant| VariantInfo::new(variant, &ident, &unpack_error.with))
+            .enumerate()
+            .map(|(index, variant)| {
+                VariantInfo::new(
+                    variant,
+                    index,
+                    tag_type.is_bool(),
+                    explicit_discriminants,
+                    &ident,
+                    &unpack_error.with,
+                )
+            })
             .collect::<Result<Vec<_>>>()?;
 
         Ok(Self {
             unpack_error,
+            payload_error,
             unpack_visitor,
             tag_type,
+            length_prefixed_variants,
+            tag_after,
             variants_info,
         })
     }
@@ -3,15 +3,28 @@
 
 use proc_macro2::Span;
 use quote::{format_ident, ToTokens};
-use syn::{parse::ParseStream, Expr, Field, Ident, Index, Path, Result, Type};
+use syn::{
+    parse::ParseStream, parse_quote, spanned::Spanned, Error, Expr, Field, GenericArgument, Ident, Index, Member,
+    Path, PathArguments, Result, Type,
+};
 
-use crate::parse::{filter_attrs, parse_kv, skip_stream};
+use crate::parse::{filter_attrs, parse_flag, parse_kv, skip_stream};
 
 pub(crate) enum IdentOrIndex {
     Ident(Ident),
     Index(Index),
 }
 
+impl PartialEq<Member> for IdentOrIndex {
+    fn eq(&self, member: &Member) -> bool {
+        match (self, member) {
+            (Self::Ident(ident), Member::Named(named)) => ident == named,
+            (Self::Index(index), Member::Unnamed(unnamed)) => index.index == unnamed.index,
+            _ => false,
+        }
+    }
+}
+
 impl ToTokens for IdentOrIndex {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
@@ -24,6 +37,12 @@ impl ToTokens for IdentOrIndex {
 pub(crate) struct FieldInfo {
     pub(crate) unpack_error_with: Expr,
     pub(crate) verify_with: Option<Path>,
+    pub(crate) pack_with: Option<Path>,
+    pub(crate) unpack_with: Option<Path>,
+    pub(crate) skip: bool,
+    pub(crate) length_prefix: Option<Type>,
+    pub(crate) count: Option<Member>,
+    pub(crate) flatten: bool,
     pub(crate) pattern_ident: IdentOrIndex,
     pub(crate) ident: Ident,
     pub(crate) ty: Type,
@@ -43,6 +62,12 @@ impl FieldInfo {
 
         let mut unpack_error_with_opt = None;
         let mut verify_with_opt = None;
+        let mut pack_with_opt: Option<Path> = None;
+        let mut unpack_with_opt = None;
+        let mut skip = false;
+        let mut length_prefix_opt = None;
+        let mut count_opt: Option<Member> = None;
+        let mut flatten = false;
 
         for attr in filter_attrs(&field.attrs) {
             if let Some(verify_with) = attr.parse_args_with(|stream: ParseStream| {
@@ -64,14 +89,197 @@ impl FieldInfo {
             })? {
                 unpack_error_with_opt = Some(unpack_error_with);
             }
+
+            if let Some(pack_with) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("pack_with", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                pack_with_opt = Some(pack_with);
+            }
+
+            if let Some(unpack_with) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("unpack_with", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                unpack_with_opt = Some(unpack_with);
+            }
+
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("skip", stream)?;
+                if !found {
+                    skip_stream(stream)?;
+                }
+                Ok(found)
+            })? {
+                skip = true;
+            }
+
+            if let Some(length_prefix) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("length_prefix", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                length_prefix_opt = Some(length_prefix);
+            }
+
+            if let Some(count) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("count", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                count_opt = Some(count);
+            }
+
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("flatten", stream)?;
+                if !found {
+                    skip_stream(stream)?;
+                }
+                Ok(found)
+            })? {
+                flatten = true;
+            }
+        }
+
+        if skip && (length_prefix_opt.is_some() || verify_with_opt.is_some()) {
+            return Err(Error::new(
+                field.ty.span(),
+                "Fields with a `#[packable(skip)]` attribute cannot have `length_prefix` or `verify_with` attributes.",
+            ));
+        }
+
+        if flatten && (skip || length_prefix_opt.is_some()) {
+            return Err(Error::new(
+                field.ty.span(),
+                "Fields with a `#[packable(flatten)]` attribute cannot have `skip` or `length_prefix` attributes.",
+            ));
+        }
+
+        if let Some(count) = &count_opt {
+            if skip || flatten || length_prefix_opt.is_some() {
+                return Err(Error::new(
+                    count.span(),
+                    "Fields with a `#[packable(count = ...)]` attribute cannot have `skip`, `flatten` or `length_prefix` attributes.",
+                ));
+            }
+        }
+
+        if let Some(with) = pack_with_opt.as_ref().or(unpack_with_opt.as_ref()) {
+            if skip || flatten || length_prefix_opt.is_some() || count_opt.is_some() {
+                return Err(Error::new(
+                    with.span(),
+                    "Fields with a `pack_with` or `unpack_with` attribute cannot have `skip`, `flatten`, `length_prefix` or `count` attributes.",
+                ));
+            }
         }
 
         Ok(Self {
             unpack_error_with: unpack_error_with_opt.unwrap_or_else(|| default_unpack_error_with.clone()),
             verify_with: verify_with_opt,
+            pack_with: pack_with_opt,
+            unpack_with: unpack_with_opt,
+            skip,
+            length_prefix: length_prefix_opt,
+            count: count_opt,
+            flatten,
             ident,
             pattern_ident,
             ty: field.ty.clone(),
         })
     }
+    /// Returns the type used to pack and unpack this field, which is a `VecPrefix` if the field has a
+    /// `length_prefix`.
+    pub(crate) fn packable_type(&self, crate_name: &Ident) -> Type {
+        let ty = &self.ty;
+
+        match &self.length_prefix {
+            Some(length_prefix) => parse_quote!(#crate_name::prefix::VecPrefix<<#ty as IntoIterator>::Item, #length_prefix>),
+            None => ty.clone(),
+        }
+    }
+}
+
+/// Returns `T` if the given type is syntactically an `Option<T>`.
+pub(crate) fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let segment = type_path.path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => match arguments.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether the given type is syntactically a sequence type whose packed size depends on its length, such as a
+/// `Vec<T>`, a `String` or a `&[u8]`.
+pub(crate) fn is_variable_length_type(ty: &Type) -> bool {
+    /// Names of the sequence types provided by `std` and this crate.
+    const VARIABLE_LENGTH_TYPES: [&str; 16] = [
+        "Vec",
+        "VecDeque",
+        "String",
+        "HashMap",
+        "HashSet",
+        "BTreeMap",
+        "BTreeSet",
+        "VecPrefix",
+        "BoxedSlicePrefix",
+        "StringPrefix",
+        "BTreeSetPrefix",
+        "ByteBuf",
+        "PackedBools",
+        "ChecksummedVec",
+        "DeterministicHashMap",
+        "Box",
+    ];
+
+    match ty {
+        Type::Slice(_) => true,
+        Type::Reference(reference) => matches!(*reference.elem, Type::Slice(_)),
+        Type::Paren(paren) => is_variable_length_type(&paren.elem),
+        Type::Group(group) => is_variable_length_type(&group.elem),
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return false;
+            };
+
+            if segment.ident == "Box" {
+                // Only boxed slices are sequences, other boxes are packed like their content.
+                return match &segment.arguments {
+                    PathArguments::AngleBracketed(arguments) => matches!(
+                        arguments.args.first(),
+                        Some(GenericArgument::Type(Type::Slice(_)))
+                    ),
+                    _ => false,
+                };
+            }
+
+            VARIABLE_LENGTH_TYPES.iter().any(|name| segment.ident == name)
+        }
+        _ => false,
+    }
 }
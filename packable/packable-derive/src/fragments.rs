@@ -1,9 +1,9 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Ident, Path};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Ident, Path, Type};
 
 use crate::{record_info::RecordInfo, unpack_visitor_info::UnpackVisitorInfo};
 
@@ -20,26 +20,164 @@ impl Fragments {
     pub(crate) fn new(
         info: RecordInfo,
         verify_with: Option<Path>,
+        presence_bitfield: bool,
         unpack_visitor_info: &UnpackVisitorInfo,
         crate_name: &Ident,
     ) -> Self {
+        let presence_types = if presence_bitfield {
+            info.presence_types().map(|ty| ty.cloned()).collect()
+        } else {
+            vec![None; info.fields_type.len()]
+        };
+
         let RecordInfo {
             path,
             fields_unpack_error_with,
             fields_verify_with,
+            fields_pack_with,
+            fields_unpack_with,
+            fields_skip,
+            fields_length_prefix,
+            fields_count,
+            fields_flatten: _,
             fields_ident,
             fields_pattern_ident,
             fields_type,
         } = info;
 
-        let fields_verification = fields_verify_with.into_iter().zip(fields_ident.iter()).map(|(verify_with, field_ident)| match verify_with {
-            Some(verify_with) => if unpack_visitor_info.explicit {
-                quote!(#verify_with::<VERIFY>(&#field_ident, visitor).map_err(#crate_name::error::UnpackError::from_packable)?;)
-            } else {
-                quote!(#verify_with::<VERIFY>(&#field_ident).map_err(#crate_name::error::UnpackError::from_packable)?;)
+        let len = fields_ident.len();
+        let mut fields_pattern = Vec::with_capacity(len);
+        let mut fields_pack = Vec::with_capacity(len);
+        let mut fields_unpack = Vec::with_capacity(len);
+
+        // The presence of the optional fields is packed first, as a bitfield wide enough to hold them all.
+        let presence_ident = format_ident!("presence");
+        let presence_type: Type = match presence_types.iter().flatten().count() {
+            0..=8 => parse_quote!(u8),
+            9..=16 => parse_quote!(u16),
+            17..=32 => parse_quote!(u32),
+            _ => parse_quote!(u64),
+        };
+        let mut presence_bits = Vec::new();
+
+        for (
+            (
+                (((((unpack_error_with, verify_with), (pack_with, unpack_with)), skip), length_prefix), count),
+                (pattern_ident, field_ident),
+            ),
+            (field_type, presence_inner_type),
+        ) in fields_unpack_error_with
+            .into_iter()
+            .zip(fields_verify_with)
+            .zip(fields_pack_with.into_iter().zip(fields_unpack_with))
+            .zip(fields_skip)
+            .zip(fields_length_prefix)
+            .zip(fields_count)
+            .zip(fields_pattern_ident.iter().zip(fields_ident.iter()))
+            .zip(fields_type.into_iter().zip(presence_types))
+        {
+            // Skipped fields are neither packed nor unpacked, they are initialized with their default value instead.
+            if skip {
+                fields_pattern.push(quote!(#pattern_ident: _));
+                fields_unpack.push(quote!(let #field_ident = <#field_type as Default>::default();));
+                continue;
+            }
+
+            fields_pattern.push(quote!(#pattern_ident: #field_ident));
+
+            let field_verification = match verify_with {
+                Some(verify_with) => {
+                    if unpack_visitor_info.explicit {
+                        quote!(#verify_with::<VERIFY>(&#field_ident, visitor).map_err(#crate_name::error::UnpackError::from_packable)?;)
+                    } else {
+                        quote!(#verify_with::<VERIFY>(&#field_ident).map_err(#crate_name::error::UnpackError::from_packable)?;)
+                    }
+                }
+                None => quote!(),
+            };
+
+            // Optional fields are only packed if they are present, as their inner type.
+            if let Some(inner_type) = presence_inner_type {
+                let bit = Literal::usize_unsuffixed(presence_bits.len());
+                presence_bits.push(quote!(if #field_ident.is_some() { #presence_ident |= 1 << #bit; }));
+
+                fields_pack.push(quote! {
+                    if let Some(#field_ident) = #field_ident {
+                        <#inner_type as #crate_name::Packable>::pack(#field_ident, packer)?;
+                    }
+                });
+                fields_unpack.push(quote! {
+                    let #field_ident = if #presence_ident & (1 << #bit) != 0 {
+                        Some(<#inner_type as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, Borrow::<<#inner_type as #crate_name::Packable>::UnpackVisitor>::borrow(visitor)).map_packable_err(#unpack_error_with).coerce()?)
+                    } else {
+                        None
+                    };
+                    #field_verification
+                });
+                continue;
             }
-            None => quote!(),
-        });
+
+            // Fields with a count are packed and unpacked without a prefix, their length is held by the count field.
+            if let Some(count) = count {
+                let item_type = quote!(<#field_type as IntoIterator>::Item);
+
+                fields_pack.push(quote! {
+                    assert!(
+                        <usize as TryFrom<_>>::try_from(Clone::clone(#count)).ok() == Some(#field_ident.len()),
+                        "the length of the field does not match its count field"
+                    );
+                    for item in #field_ident.iter() {
+                        #crate_name::Packable::pack(item, packer)?;
+                    }
+                });
+                fields_unpack.push(quote! {
+                    let #field_ident: #field_type = #crate_name::__private::unpack_counted::<#item_type, _, _, VERIFY>(unpacker, Clone::clone(&#count), Borrow::<<#item_type as #crate_name::Packable>::UnpackVisitor>::borrow(visitor)).map(Into::into).map_packable_err(#unpack_error_with).coerce()?;
+                    #field_verification
+                });
+                continue;
+            }
+
+            match length_prefix {
+                // Fields with a length prefix are packed and unpacked as if they were a `VecPrefix`.
+                Some(length_prefix) => {
+                    let prefix_type = quote!(#crate_name::prefix::VecPrefix<<#field_type as IntoIterator>::Item, #length_prefix>);
+
+                    fields_pack.push(quote! {
+                        // This unwrap is consistent with the behavior of `VecPrefix::pack`.
+                        <#length_prefix as #crate_name::Packable>::pack(&<#length_prefix as TryFrom<usize>>::try_from(#field_ident.len()).ok().expect("the length of the field exceeds the bounds of its length prefix"), packer)?;
+                        for item in #field_ident.iter() {
+                            #crate_name::Packable::pack(item, packer)?;
+                        }
+                    });
+                    fields_unpack.push(quote! {
+                        let #field_ident: #field_type = <#prefix_type as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, Borrow::<<#prefix_type as #crate_name::Packable>::UnpackVisitor>::borrow(visitor)).map(Into::into).map_packable_err(#unpack_error_with).coerce()?;
+                        #field_verification
+                    });
+                }
+                None => {
+                    // Fields with `pack_with` or `unpack_with` functions use them instead of their implementation.
+                    let field_pack = match pack_with {
+                        Some(pack_with) => quote!(#pack_with(#field_ident, packer)?;),
+                        None => quote!(<#field_type as #crate_name::Packable>::pack(#field_ident, packer)?;),
+                    };
+                    let field_unpack = match unpack_with {
+                        Some(unpack_with) if unpack_visitor_info.explicit => {
+                            quote!(#unpack_with::<_, VERIFY>(unpacker, visitor))
+                        }
+                        Some(unpack_with) => quote!(#unpack_with::<_, VERIFY>(unpacker)),
+                        None => {
+                            quote!(<#field_type as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, Borrow::<<#field_type as #crate_name::Packable>::UnpackVisitor>::borrow(visitor)))
+                        }
+                    };
+
+                    fields_pack.push(field_pack);
+                    fields_unpack.push(quote! {
+                        let #field_ident: #field_type = #field_unpack.map_packable_err(#unpack_error_with).coerce()?;
+                        #field_verification
+                    });
+                }
+            }
+        }
 
         let verify_with = match verify_with {
             Some(verify_with) => {
@@ -52,17 +190,31 @@ impl Fragments {
             None => quote!(),
         };
 
+        let (presence_pack, presence_unpack) = if presence_bitfield {
+            (
+                quote! {
+                    let mut #presence_ident: #presence_type = 0;
+                    #(#presence_bits)*
+                    <#presence_type as #crate_name::Packable>::pack(&#presence_ident, packer)?;
+                },
+                quote! {
+                    let #presence_ident = <#presence_type as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+                },
+            )
+        } else {
+            (quote!(), quote!())
+        };
+
         Self {
-            pattern: quote!(#path { #(#fields_pattern_ident: #fields_ident),* }),
+            pattern: quote!(#path { #(#fields_pattern),* }),
             pack: quote! {
-                #(<#fields_type as #crate_name::Packable>::pack(#fields_ident, packer)?;) *
+                #presence_pack
+                #(#fields_pack) *
                 Ok(())
             },
             unpack: quote! {
-                #(
-                    let #fields_ident = <#fields_type as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, Borrow::<<#fields_type as #crate_name::Packable>::UnpackVisitor>::borrow(visitor)).map_packable_err(#fields_unpack_error_with).coerce()?;
-                    #fields_verification
-                )*
+                #presence_unpack
+                #(#fields_unpack)*
 
                 let unpacked = #path {
                     #(#fields_pattern_ident: #fields_ident,)*
@@ -8,6 +8,7 @@ mod field_info;
 mod fragments;
 mod parse;
 mod record_info;
+mod ref_trait_impl;
 mod struct_info;
 mod tag_type_info;
 mod trait_impl;
@@ -19,21 +20,30 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use proc_macro_crate::{crate_name, FoundCrate};
 use quote::ToTokens;
-use syn::{parse_macro_input, Ident};
+use syn::{parse_macro_input, DeriveInput, Ident};
 
-use self::trait_impl::TraitImpl;
+use self::{ref_trait_impl::RefTraitImpl, trait_impl::TraitImpl};
 
 #[proc_macro_derive(Packable, attributes(packable))]
 pub fn packable(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input);
+    let input = parse_macro_input!(input as DeriveInput);
 
     let crate_string = match crate_name("packable").expect("packable should be present in `Cargo.toml`") {
         FoundCrate::Itself => "packable_crate".to_owned(),
         FoundCrate::Name(name) => name,
     };
 
-    match TraitImpl::new(input, Ident::new(&crate_string, Span::call_site())) {
-        Ok(trait_impl) => trait_impl.into_token_stream(),
+    let crate_name = Ident::new(&crate_string, Span::call_site());
+
+    // Types with a lifetime parameter borrow data, so they implement `PackableRef` instead of `Packable`.
+    let trait_impl = if input.generics.lifetimes().next().is_some() {
+        RefTraitImpl::new(input, crate_name).map(ToTokens::into_token_stream)
+    } else {
+        TraitImpl::new(input, crate_name).map(ToTokens::into_token_stream)
+    };
+
+    match trait_impl {
+        Ok(trait_impl) => trait_impl,
         Err(err) => err.into_compile_error(),
     }
     .into()
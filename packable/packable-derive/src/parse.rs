@@ -32,6 +32,13 @@ pub(crate) fn parse_kv<T: Parse>(ident: &'static str, stream: ParseStream) -> Re
     }
 }
 
+pub(crate) fn parse_flag(ident: &'static str, stream: ParseStream) -> Result<bool> {
+    let found_ident = stream.parse::<Ident>()?;
+    validate_ident(&found_ident)?;
+
+    Ok(found_ident == ident)
+}
+
 pub(crate) fn parse_kv_after_comma<T: Parse>(ident: &'static str, stream: ParseStream) -> Result<Option<T>> {
     if stream.is_empty() {
         return Ok(None);
@@ -47,12 +54,29 @@ fn validate_ident(ident: &Ident) -> Result<()> {
     const KNOWN_IDENTS: &[&str] = &[
         "unpack_error",
         "unpack_error_with",
+        "payload_error",
         "unpack_visitor",
         "tag_type",
         "tag",
         "with",
         "with_error",
         "verify_with",
+        "pack_with",
+        "unpack_with",
+        "skip",
+        "length_prefix",
+        "count",
+        "packed_size",
+        "strict_len",
+        "inherent_helpers",
+        "inline",
+        "transparent",
+        "presence_bitfield",
+        "flatten",
+        "roundtrip_test",
+        "tag_consts",
+        "length_prefixed_variants",
+        "tag_after",
     ];
 
     if KNOWN_IDENTS.iter().any(|known_ident| ident == known_ident) {
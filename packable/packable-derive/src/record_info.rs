@@ -1,14 +1,23 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use syn::{Expr, Fields, Ident, Path, Result, Type};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Error, Expr, Fields, Ident, Path, Result, Type};
 
-use crate::field_info::{FieldInfo, IdentOrIndex};
+use crate::field_info::{is_variable_length_type, option_inner_type, FieldInfo, IdentOrIndex};
 
 pub(crate) struct RecordInfo {
     pub(crate) path: Path,
     pub(crate) fields_unpack_error_with: Vec<Expr>,
     pub(crate) fields_verify_with: Vec<Option<Path>>,
+    pub(crate) fields_pack_with: Vec<Option<Path>>,
+    pub(crate) fields_unpack_with: Vec<Option<Path>>,
+    pub(crate) fields_skip: Vec<bool>,
+    pub(crate) fields_length_prefix: Vec<Option<Type>>,
+    /// The identifier of the field holding the number of elements of each field with a `count` attribute.
+    pub(crate) fields_count: Vec<Option<Ident>>,
+    pub(crate) fields_flatten: Vec<bool>,
     pub(crate) fields_pattern_ident: Vec<IdentOrIndex>,
     pub(crate) fields_ident: Vec<Ident>,
     pub(crate) fields_type: Vec<Type>,
@@ -19,7 +28,13 @@ impl RecordInfo {
         let len = fields.len();
         let mut fields_unpack_error_with = Vec::with_capacity(len);
         let mut fields_verify_with = Vec::with_capacity(len);
-        let mut fields_ident = Vec::with_capacity(len);
+        let mut fields_pack_with = Vec::with_capacity(len);
+        let mut fields_unpack_with: Vec<Option<Path>> = Vec::with_capacity(len);
+        let mut fields_skip: Vec<bool> = Vec::with_capacity(len);
+        let mut fields_length_prefix = Vec::with_capacity(len);
+        let mut fields_count = Vec::with_capacity(len);
+        let mut fields_flatten = Vec::with_capacity(len);
+        let mut fields_ident: Vec<Ident> = Vec::with_capacity(len);
         let mut fields_pattern_ident = Vec::with_capacity(len);
         let mut fields_type = Vec::with_capacity(len);
 
@@ -27,13 +42,45 @@ impl RecordInfo {
             let FieldInfo {
                 unpack_error_with,
                 verify_with,
+                pack_with,
+                unpack_with,
+                skip,
+                length_prefix,
+                count,
+                flatten,
                 ident,
                 pattern_ident,
                 ty,
             } = FieldInfo::new(field, default_unpack_error_with, index)?;
 
+            // The count of a field must be unpacked before it, so it can only be held by a previous field.
+            let count = match count {
+                Some(count) => match fields_pattern_ident.iter().position(|pattern_ident| *pattern_ident == count) {
+                    Some(position) if !fields_skip[position] => Some(fields_ident[position].clone()),
+                    Some(_) => {
+                        return Err(Error::new(
+                            count.span(),
+                            "The `count` attribute cannot refer to a field with a `#[packable(skip)]` attribute.",
+                        ));
+                    }
+                    None => {
+                        return Err(Error::new(
+                            count.span(),
+                            "The `count` attribute must refer to a field declared before this one, so that it is unpacked first.",
+                        ));
+                    }
+                },
+                None => None,
+            };
+
             fields_unpack_error_with.push(unpack_error_with);
             fields_verify_with.push(verify_with);
+            fields_pack_with.push(pack_with);
+            fields_unpack_with.push(unpack_with);
+            fields_skip.push(skip);
+            fields_length_prefix.push(length_prefix);
+            fields_count.push(count);
+            fields_flatten.push(flatten);
             fields_ident.push(ident);
             fields_pattern_ident.push(pattern_ident);
             fields_type.push(ty);
@@ -43,9 +90,91 @@ impl RecordInfo {
             path,
             fields_unpack_error_with,
             fields_verify_with,
+            fields_pack_with,
+            fields_unpack_with,
+            fields_skip,
+            fields_length_prefix,
+            fields_count,
+            fields_flatten,
             fields_pattern_ident,
             fields_ident,
             fields_type,
         })
     }
+    /// Returns the inner type of every field whose presence is encoded in a presence bitfield, which are the fields of
+    /// type `Option<T>` that are not skipped, flattened, length-prefixed or packed with functions, or `None` for the
+    /// other fields.
+    pub(crate) fn presence_types(&self) -> impl Iterator<Item = Option<&Type>> {
+        self.fields_type
+            .iter()
+            .zip(&self.fields_skip)
+            .zip(&self.fields_length_prefix)
+            .zip(&self.fields_flatten)
+            .zip(self.fields_pack_with.iter().zip(&self.fields_unpack_with))
+            .map(|((((ty, skip), length_prefix), flatten), (pack_with, unpack_with))| {
+                if *skip || *flatten || length_prefix.is_some() || pack_with.is_some() || unpack_with.is_some() {
+                    None
+                } else {
+                    option_inner_type(ty)
+                }
+            })
+    }
+
+    /// Returns the fields whose presence is encoded in a presence bitfield.
+    pub(crate) fn presence_fields(&self) -> impl Iterator<Item = &Type> {
+        self.presence_types().flatten()
+    }
+
+    /// Returns an expression with the number of bytes used to pack the record. This requires every field that is not
+    /// skipped to implement `PackedSize`.
+    pub(crate) fn packed_size(&self, crate_name: &Ident) -> Result<TokenStream> {
+        let mut sizes = Vec::with_capacity(self.fields_type.len());
+
+        for (index, (((ty, skip), length_prefix), count)) in self
+            .fields_type
+            .iter()
+            .zip(&self.fields_skip)
+            .zip(&self.fields_length_prefix)
+            .zip(&self.fields_count)
+            .enumerate()
+        {
+            if let Some(length_prefix) = length_prefix {
+                return Err(Error::new(
+                    length_prefix.span(),
+                    "Fields with a `length_prefix` attribute do not have a fixed packed size.",
+                ));
+            }
+
+            if count.is_some() {
+                return Err(Error::new(
+                    ty.span(),
+                    "Fields with a `count` attribute do not have a fixed packed size.",
+                ));
+            }
+
+            if self.fields_pack_with[index].is_some() || self.fields_unpack_with[index].is_some() {
+                return Err(Error::new(
+                    ty.span(),
+                    "Fields with a `pack_with` or `unpack_with` attribute do not have a fixed packed size.",
+                ));
+            }
+
+            if !skip && is_variable_length_type(ty) {
+                let message = if self.fields_skip[index + 1..].contains(&false) {
+                    "Fields with a variable-length type do not have a fixed packed size, so the fields after them \
+                     cannot have a constant layout."
+                } else {
+                    "Fields with a variable-length type do not have a fixed packed size."
+                };
+
+                return Err(Error::new(ty.span(), message));
+            }
+
+            if !skip {
+                sizes.push(quote!(<#ty as #crate_name::PackedSize>::PACKED_SIZE));
+            }
+        }
+
+        Ok(quote!(0 #(+ #sizes)*))
+    }
 }
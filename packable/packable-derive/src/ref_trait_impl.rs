@@ -0,0 +1,230 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Data, DeriveInput, Error, Generics, Ident, Lifetime, Path, Result, parse::ParseStream, parse_quote,
+    spanned::Spanned,
+};
+
+use crate::{
+    field_info::FieldInfo,
+    parse::{filter_attrs, parse_kv, skip_stream},
+    record_info::RecordInfo,
+    unpack_error_info::UnpackErrorInfo,
+};
+
+/// The attributes supported by types with a lifetime parameter, which implement `PackableRef` instead of `Packable`.
+const SUPPORTED_ATTRS: &[&str] = &["unpack_error", "verify_with"];
+const SUPPORTED_FIELD_ATTRS: &[&str] = &["unpack_error_with", "verify_with", "skip"];
+
+pub(crate) struct RefTraitImpl {
+    ident: Ident,
+    generics: Generics,
+    lifetime: Lifetime,
+    unpack_error: TokenStream,
+    pack: TokenStream,
+    unpack: TokenStream,
+    crate_name: Ident,
+}
+
+impl RefTraitImpl {
+    pub(crate) fn new(input: DeriveInput, crate_name: Ident) -> Result<Self> {
+        let mut lifetimes = input.generics.lifetimes();
+        // This unwrap is fine because this type is only used for types with a lifetime parameter.
+        let lifetime = lifetimes.next().unwrap().lifetime.clone();
+
+        if let Some(extra) = lifetimes.next() {
+            return Err(Error::new(
+                extra.span(),
+                "Types with a lifetime parameter cannot derive `Packable` if they have more than one lifetime parameter.",
+            ));
+        }
+
+        let Data::Struct(data) = input.data else {
+            return Err(Error::new(
+                input.ident.span(),
+                "Types with a lifetime parameter can only derive `Packable` if they are structs.",
+            ));
+        };
+
+        check_attrs(&input.attrs, SUPPORTED_ATTRS)?;
+
+        for field in &data.fields {
+            check_attrs(&field.attrs, SUPPORTED_FIELD_ATTRS)?;
+        }
+
+        let filtered_attrs = filter_attrs(&input.attrs);
+
+        // Skipped fields are never unpacked so they cannot decide the default `UnpackError`.
+        let mut first_field_type = None;
+
+        for (index, field) in data.fields.iter().enumerate() {
+            let info = FieldInfo::new(field, &parse_quote!(core::convert::identity), index)?;
+
+            if !info.skip {
+                first_field_type = Some(info.ty);
+                break;
+            }
+        }
+
+        let unpack_error = UnpackErrorInfo::new(filtered_attrs.clone(), || match &first_field_type {
+            Some(ty) => parse_quote!(<#ty as #crate_name::PackableRef<#lifetime>>::UnpackError),
+            None => parse_quote!(core::convert::Infallible),
+        })?;
+
+        let mut verify_with_opt = None;
+
+        for attr in filtered_attrs {
+            if let Some(verify_with) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv::<Path>("verify_with", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                verify_with_opt = Some(verify_with);
+                break;
+            }
+        }
+
+        let RecordInfo {
+            path,
+            fields_unpack_error_with,
+            fields_verify_with,
+            fields_pack_with: _,
+            fields_unpack_with: _,
+            fields_skip,
+            fields_length_prefix: _,
+            fields_count: _,
+            fields_flatten: _,
+            fields_ident,
+            fields_pattern_ident,
+            fields_type,
+        } = RecordInfo::new(input.ident.clone().into(), &data.fields, &unpack_error.with)?;
+
+        let len = fields_ident.len();
+        let mut fields_pattern = Vec::with_capacity(len);
+        let mut fields_pack = Vec::with_capacity(len);
+        let mut fields_unpack = Vec::with_capacity(len);
+
+        for ((((unpack_error_with, verify_with), skip), (pattern_ident, field_ident)), field_type) in
+            fields_unpack_error_with
+                .into_iter()
+                .zip(fields_verify_with)
+                .zip(fields_skip)
+                .zip(fields_pattern_ident.iter().zip(fields_ident.iter()))
+                .zip(fields_type)
+        {
+            if skip {
+                fields_pattern.push(quote!(#pattern_ident: _));
+                fields_unpack.push(quote!(let #field_ident = <#field_type as Default>::default();));
+                continue;
+            }
+
+            let field_verification = match verify_with {
+                Some(verify_with) => {
+                    quote!(#verify_with::<VERIFY>(&#field_ident).map_err(#crate_name::error::UnpackError::from_packable)?;)
+                }
+                None => quote!(),
+            };
+
+            fields_pattern.push(quote!(#pattern_ident: #field_ident));
+            fields_pack
+                .push(quote!(<#field_type as #crate_name::PackableRef<#lifetime>>::pack_ref(#field_ident, packer)?;));
+            fields_unpack.push(quote! {
+                let #field_ident = <#field_type as #crate_name::PackableRef<#lifetime>>::unpack_ref::<VERIFY>(unpacker).map_packable_err(#unpack_error_with).coerce()?;
+                #field_verification
+            });
+        }
+
+        let verify_with = match verify_with_opt {
+            Some(verify_with) => {
+                quote!(#verify_with::<VERIFY>(&unpacked).map_err(#crate_name::error::UnpackError::from_packable)?;)
+            }
+            None => quote!(),
+        };
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics,
+            lifetime,
+            unpack_error: unpack_error.unpack_error.into_token_stream(),
+            pack: quote! {
+                let #path { #(#fields_pattern),* } = self;
+                #(#fields_pack)*
+                Ok(())
+            },
+            unpack: quote! {
+                #(#fields_unpack)*
+
+                let unpacked = #path {
+                    #(#fields_pattern_ident: #fields_ident,)*
+                };
+
+                #verify_with
+
+                Ok(unpacked)
+            },
+            crate_name,
+        })
+    }
+}
+
+/// Returns an error if any of the `#[packable(...)]` attributes is not one of the supported ones.
+fn check_attrs(attrs: &[syn::Attribute], supported: &[&str]) -> Result<()> {
+    for attr in filter_attrs(attrs) {
+        attr.parse_args_with(|stream: ParseStream| {
+            let ident = stream.parse::<Ident>()?;
+            skip_stream(stream)?;
+
+            if supported.iter().any(|supported| ident == supported) {
+                Ok(())
+            } else {
+                Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "The `{}` attribute is not supported by types with a lifetime parameter.",
+                        ident
+                    ),
+                ))
+            }
+        })?;
+    }
+
+    Ok(())
+}
+
+impl ToTokens for RefTraitImpl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            ident: type_name,
+            generics,
+            lifetime,
+            unpack_error,
+            pack,
+            unpack,
+            crate_name,
+        } = &self;
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let impl_tokens = quote! {
+            impl #impl_generics #crate_name::PackableRef<#lifetime> for #type_name #ty_generics #where_clause {
+                type UnpackError = #unpack_error;
+
+                fn pack_ref<P: #crate_name::packer::Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                    #pack
+                }
+
+                fn unpack_ref<const VERIFY: bool>(unpacker: &mut #crate_name::unpacker::SliceUnpacker<#lifetime>) -> Result<Self, #crate_name::error::UnpackError<Self::UnpackError, #crate_name::error::UnexpectedEOF>> {
+                    use #crate_name::error::UnpackErrorExt;
+                    #unpack
+                }
+            }
+        };
+
+        impl_tokens.to_tokens(tokens);
+    }
+}
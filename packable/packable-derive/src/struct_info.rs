@@ -1,18 +1,47 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use syn::{parse::ParseStream, parse_quote, Attribute, Field, Fields, Ident, Path, Result};
+use quote::ToTokens;
+use syn::{
+    parse::ParseStream, parse_quote, spanned::Spanned, Attribute, Error, Field, Fields, Ident, Path, Result, Type,
+};
 
 use crate::{
-    parse::{filter_attrs, parse_kv, skip_stream},
+    field_info::{option_inner_type, FieldInfo},
+    parse::{filter_attrs, parse_flag, parse_kv, skip_stream},
     record_info::RecordInfo,
     unpack_error_info::UnpackErrorInfo,
     unpack_visitor_info::UnpackVisitorInfo,
 };
 
+/// The maximum number of optional fields of a struct with a presence bitfield, which is the width of a `u64`.
+const MAX_PRESENCE_FIELDS: usize = 64;
+
+/// The primitive types whose `UnpackError` is `Infallible`.
+const INFALLIBLE_TYPES: &[&str] = &[
+    "bool", "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32", "f64",
+];
+
+/// Returns whether the given type is syntactically known to never fail to unpack, which is the case of the primitive
+/// types in [`INFALLIBLE_TYPES`] and of arrays of such types.
+fn is_infallible(ty: &Type) -> bool {
+    match ty {
+        Type::Array(array) => is_infallible(&array.elem),
+        Type::Group(group) => is_infallible(&group.elem),
+        Type::Paren(paren) => is_infallible(&paren.elem),
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .get_ident()
+            .is_some_and(|ident| INFALLIBLE_TYPES.iter().any(|infallible| ident == infallible)),
+        _ => false,
+    }
+}
+
 pub(crate) struct StructInfo {
     pub(crate) unpack_error: UnpackErrorInfo,
     pub(crate) verify_with: Option<Path>,
+    pub(crate) packed_size: bool,
+    pub(crate) presence_bitfield: bool,
     pub(crate) unpack_visitor: UnpackVisitorInfo,
     pub(crate) inner: RecordInfo,
 }
@@ -21,9 +50,65 @@ impl StructInfo {
     pub(crate) fn new(path: Path, fields: &Fields, attrs: &[Attribute], crate_name: &Ident) -> Result<Self> {
         let filtered_attrs = filter_attrs(attrs);
 
-        let unpack_error = UnpackErrorInfo::new(filtered_attrs.clone(), || match fields.iter().next() {
-            Some(Field { ty, .. }) => parse_quote!(<#ty as #crate_name::Packable>::UnpackError),
-            None => parse_quote!(core::convert::Infallible),
+        let mut presence_bitfield = None;
+
+        for attr in filtered_attrs.clone() {
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("presence_bitfield", stream)?;
+                if !found {
+                    skip_stream(stream)?;
+                }
+                Ok(found)
+            })? {
+                presence_bitfield = Some(attr);
+                break;
+            }
+        }
+
+        // Skipped fields are never unpacked, and fields with an `unpack_with` function are not unpacked as their type, so
+        // they cannot decide the default `UnpackError` and `UnpackVisitor`.
+        let mut unpacked_fields = Vec::new();
+
+        for (index, field) in fields.iter().enumerate() {
+            let info = FieldInfo::new(field, &parse_quote!(core::convert::identity), index)?;
+
+            if !info.skip && info.unpack_with.is_none() {
+                // Optional fields of a struct with a presence bitfield are unpacked as their inner type.
+                let ty = match option_inner_type(&info.ty) {
+                    Some(inner)
+                        if presence_bitfield.is_some()
+                            && !info.flatten
+                            && info.length_prefix.is_none()
+                            && info.pack_with.is_none() =>
+                    {
+                        inner.clone()
+                    }
+                    _ => info.packable_type(crate_name),
+                };
+
+                unpacked_fields.push((field, ty));
+            }
+        }
+
+        // If the fields that are not known to be infallible all have the same type, its error is the default
+        // `UnpackError`, and the errors of the infallible fields are converted into it.
+        let mut fallible_types = unpacked_fields
+            .iter()
+            .map(|(_, ty)| ty)
+            .filter(|ty| !is_infallible(ty));
+        let fallible_type = fallible_types
+            .next()
+            .filter(|first| {
+                let first = first.to_token_stream().to_string();
+                fallible_types.all(|ty| ty.to_token_stream().to_string() == first)
+            })
+            .cloned();
+
+        let first_field = unpacked_fields.into_iter().next();
+
+        let unpack_error = UnpackErrorInfo::new(filtered_attrs.clone(), || match (&fallible_type, &first_field) {
+            (Some(ty), _) | (None, Some((_, ty))) => parse_quote!(<#ty as #crate_name::Packable>::UnpackError),
+            (None, None) => parse_quote!(core::convert::Infallible),
         })?;
 
         let mut verify_with_opt = None;
@@ -41,9 +126,24 @@ impl StructInfo {
             }
         }
 
+        let mut packed_size = false;
+
+        for attr in filtered_attrs.clone() {
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("packed_size", stream)?;
+                if !found {
+                    skip_stream(stream)?;
+                }
+                Ok(found)
+            })? {
+                packed_size = true;
+                break;
+            }
+        }
+
         let unpack_visitor = UnpackVisitorInfo::new(filtered_attrs, || {
-            let (unpack_visitor, explicit) = match fields.iter().next() {
-                Some(Field { attrs, ty, .. }) => {
+            let (unpack_visitor, explicit) = match first_field {
+                Some((Field { attrs, .. }, ty)) => {
                     let mut explicit = false;
 
                     for attr in filter_attrs(attrs) {
@@ -74,9 +174,30 @@ impl StructInfo {
 
         let inner = RecordInfo::new(path, fields, &unpack_error.with)?;
 
+        if let Some(attr) = presence_bitfield {
+            if packed_size {
+                return Err(Error::new(
+                    attr.span(),
+                    "Structs with a `presence_bitfield` attribute do not have a fixed packed size.",
+                ));
+            }
+
+            if inner.presence_fields().count() > MAX_PRESENCE_FIELDS {
+                return Err(Error::new(
+                    attr.span(),
+                    format!(
+                        "Structs with a `presence_bitfield` attribute cannot have more than {} optional fields.",
+                        MAX_PRESENCE_FIELDS
+                    ),
+                ));
+            }
+        }
+
         Ok(Self {
             unpack_error,
             verify_with: verify_with_opt,
+            packed_size,
+            presence_bitfield: presence_bitfield.is_some(),
             unpack_visitor,
             inner,
         })
@@ -6,7 +6,7 @@ use syn::{parse::ParseStream, parse_quote, spanned::Spanned, Attribute, Error, E
 
 use crate::parse::{parse_kv, parse_kv_after_comma, skip_stream};
 
-const VALID_TAG_TYPES: &[&str] = &["u8", "u16", "u32", "u64"];
+const VALID_TAG_TYPES: &[&str] = &["bool", "u8", "u16", "u32", "u64"];
 
 pub(crate) struct TagTypeInfo {
     pub(crate) tag_type: Type,
@@ -14,6 +14,11 @@ pub(crate) struct TagTypeInfo {
 }
 
 impl TagTypeInfo {
+    /// Returns whether the tag is a `bool`, which can only tell two variants apart.
+    pub(crate) fn is_bool(&self) -> bool {
+        self.tag_type.to_token_stream().to_string() == "bool"
+    }
+
     pub(crate) fn new<'a>(
         enum_ident: &Ident,
         filtered_attrs: impl Iterator<Item = &'a Attribute>,
@@ -26,7 +31,7 @@ impl TagTypeInfo {
                     if !VALID_TAG_TYPES.contains(&tag_type.to_token_stream().to_string().as_str()) {
                         return Err(Error::new(
                             tag_type.span(),
-                            "Tags for enums can only be of type `u8`, `u16`, `u32` or `u64`.",
+                            "Tags for enums can only be of type `bool`, `u8`, `u16`, `u32` or `u64`.",
                         ));
                     }
 
@@ -3,10 +3,16 @@
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use syn::{spanned::Spanned, Data, DeriveInput, Generics, Ident};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Attribute, Data, DeriveInput, Generics, Ident, Index, Member, Result, Type,
+};
 
 use crate::{
-    enum_info::EnumInfo, fragments::Fragments, struct_info::StructInfo, tag_type_info::TagTypeInfo,
+    enum_info::EnumInfo,
+    fragments::Fragments,
+    parse::{filter_attrs, parse_flag, skip_stream},
+    struct_info::StructInfo,
+    tag_type_info::TagTypeInfo,
     variant_info::VariantInfo,
 };
 
@@ -17,11 +23,39 @@ pub(crate) struct TraitImpl {
     unpack_visitor: TokenStream,
     pack: TokenStream,
     unpack: TokenStream,
+    packed_size: Option<TokenStream>,
+    tag_consts: Option<TokenStream>,
+    inherent_helpers: bool,
+    inline: bool,
+    roundtrip_test: bool,
     crate_name: Ident,
 }
 
 impl TraitImpl {
     pub(crate) fn new(input: DeriveInput, crate_name: Ident) -> syn::Result<Self> {
+        let inherent_helpers = Self::flag(&input.attrs, "inherent_helpers")?;
+        let inline = Self::flag(&input.attrs, "inline")?;
+        let roundtrip_test = Self::flag(&input.attrs, "roundtrip_test")?;
+        let tag_consts = Self::flag(&input.attrs, "tag_consts")?;
+
+        if tag_consts && !matches!(input.data, Data::Enum(_)) {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "The `tag_consts` attribute can only be used on enums.",
+            ));
+        }
+
+        if roundtrip_test && !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                input.generics.span(),
+                "The `roundtrip_test` attribute cannot be used on generic types.",
+            ));
+        }
+
+        if Self::flag(&input.attrs, "transparent")? {
+            return Self::new_transparent(input, crate_name, inherent_helpers, roundtrip_test);
+        }
+
         match input.data {
             Data::Struct(data) => {
                 let info = StructInfo::new(input.ident.clone().into(), &data.fields, &input.attrs, &crate_name)?;
@@ -29,8 +63,19 @@ impl TraitImpl {
                 let unpack_error = info.unpack_error.unpack_error.clone().into_token_stream();
                 let unpack_visitor = info.unpack_visitor.unpack_visitor.clone().into_token_stream();
 
-                let Fragments { pattern, pack, unpack } =
-                    Fragments::new(info.inner, info.verify_with, &info.unpack_visitor, &crate_name);
+                let packed_size = if info.packed_size {
+                    Some(info.inner.packed_size(&crate_name)?)
+                } else {
+                    None
+                };
+
+                let Fragments { pattern, pack, unpack } = Fragments::new(
+                    info.inner,
+                    info.verify_with,
+                    info.presence_bitfield,
+                    &info.unpack_visitor,
+                    &crate_name,
+                );
 
                 Ok(Self {
                     ident: input.ident,
@@ -42,6 +87,11 @@ impl TraitImpl {
                         #pack
                     },
                     unpack,
+                    packed_size,
+                    tag_consts: None,
+                    inherent_helpers,
+                    inline,
+                    roundtrip_test,
                     crate_name,
                 })
             }
@@ -49,6 +99,16 @@ impl TraitImpl {
                 let enum_ident = &input.ident;
                 let info = EnumInfo::new(enum_ident.clone(), data, &input.attrs, &crate_name)?;
 
+                // Both values of a `bool` tag are used by the variants, so no tag is unknown.
+                let bool_tag = info.tag_type.is_bool();
+
+                if bool_tag && info.variants_info.len() != 2 {
+                    return Err(syn::Error::new(
+                        info.tag_type.tag_type.span(),
+                        "Enums with a `bool` tag type must have exactly two variants.",
+                    ));
+                }
+
                 let TagTypeInfo {
                     tag_type,
                     with_error: tag_with_error,
@@ -57,34 +117,200 @@ impl TraitImpl {
                 let unpack_error = info.unpack_error.unpack_error.into_token_stream();
                 let unpack_visitor = info.unpack_visitor.unpack_visitor.clone().into_token_stream();
 
+                // The payloads are unpacked with their own error type if it is distinguished from unknown tags.
+                let variant_error = match &info.payload_error {
+                    Some(payload_error) => payload_error.to_token_stream(),
+                    None => quote!(Self::UnpackError),
+                };
+
                 let len = info.variants_info.len();
                 let mut pack_arms = Vec::with_capacity(len);
                 let mut unpack_arms = Vec::with_capacity(len);
                 let mut tag_decls = Vec::with_capacity(len);
                 let mut tag_variants_and_idents = Vec::with_capacity(len);
+                let mut variant_tag_consts = Vec::with_capacity(len);
+                let mut payload_sizes = Vec::with_capacity(len);
 
-                for (index, VariantInfo { tag, inner }) in info.variants_info.into_iter().enumerate() {
+                for (
+                    index,
+                    VariantInfo {
+                        tag,
+                        strict_len,
+                        length_prefix,
+                        inner,
+                    },
+                ) in info.variants_info.into_iter().enumerate()
+                {
                     let variant_ident = inner.path.segments.last().unwrap().clone();
 
+                    if let Some(length_prefix) = &length_prefix {
+                        if info.length_prefixed_variants.is_none() {
+                            return Err(syn::Error::new(
+                                length_prefix.span(),
+                                "The `length_prefix` attribute can only be used on the variants of an enum with the `length_prefixed_variants` attribute.",
+                            ));
+                        }
+                        // The length prefix of a payload that precedes the tag is read before the variant is known.
+                        if info.tag_after {
+                            return Err(syn::Error::new(
+                                length_prefix.span(),
+                                "The `length_prefix` attribute cannot be used on the variants of an enum with the `tag_after` attribute.",
+                            ));
+                        }
+                    }
+                    // Only the payload of a length-prefixed variant is a region whose end is known.
+                    if strict_len && info.length_prefixed_variants.is_none() {
+                        return Err(syn::Error::new(
+                            variant_ident.ident.span(),
+                            "The `strict_len` attribute can only be used on the variants of an enum with the `length_prefixed_variants` attribute, use `unpack_exact` to reject the bytes left after a value.",
+                        ));
+                    }
+                    let length_prefix = length_prefix.or_else(|| info.length_prefixed_variants.clone());
+
+                    // Without a length prefix, the payload that precedes the tag can only be read if its size is known.
+                    if info.tag_after && info.length_prefixed_variants.is_none() {
+                        payload_sizes.push(inner.packed_size(&crate_name).map_err(|err| {
+                            syn::Error::new(
+                                err.span(),
+                                "The payloads of an enum with the `tag_after` attribute require a fixed packed size unless the enum has the `length_prefixed_variants` attribute.",
+                            )
+                        })?);
+                    }
+
                     let Fragments { pattern, pack, unpack } =
-                        Fragments::new(inner, None, &info.unpack_visitor, &crate_name);
+                        Fragments::new(inner, None, false, &info.unpack_visitor, &crate_name);
 
                     // @pvdrz: The span here is very important, otherwise the compiler won't detect
                     // unreachable patterns in the generated code for some reason. I think this is related
                     // to `https://github.com/rust-lang/rust/pull/80632`
                     let tag_ident = format_ident!("__TAG_{}", index, span = tag.span());
 
-                    pack_arms.push(quote!(#pattern => {
-                        <#tag_type as #crate_name::Packable>::pack(&#tag, packer)?;
-                        #pack
-                    }));
+                    // The bytes left in the payload of a variant with a strict length are rejected instead of being skipped.
+                    let strict_unpack = |unpacker_error: TokenStream, remaining: TokenStream| {
+                        if strict_len {
+                            quote! {
+                                let unpacked: Result<Self, #crate_name::error::UnpackError<#variant_error, #unpacker_error>> = { #unpack };
+                                let unpacked = unpacked?;
+                                let remaining = #remaining;
+
+                                if VERIFY && remaining != 0 {
+                                    return Err(#crate_name::error::UnpackError::from_packable(#crate_name::error::TrailingBytesError { remaining }));
+                                }
+
+                                Ok(unpacked)
+                            }
+                        } else {
+                            unpack.clone()
+                        }
+                    };
+
+                    // The length prefix of a payload that precedes the tag is read along with the payload.
+                    let payload_unpack = strict_unpack(
+                        quote!(#crate_name::error::UnexpectedEOF),
+                        quote!(#crate_name::unpacker::Unpacker::remaining_bytes(unpacker).unwrap_or(0)),
+                    );
+                    let limited_unpack = strict_unpack(
+                        quote!(#crate_name::error::LimitedUnpackerError<U::Error>),
+                        quote!(unpacker.remaining_budget()),
+                    );
+
+                    let (pack, unpack) = if let Some(length_prefix) = length_prefix {
+                        let payload_len = payload_len(&length_prefix, &crate_name);
+
+                        (
+                            quote! {
+                                // The payload is packed into a packer that only counts its bytes to know its length.
+                                let mut len_packer = #crate_name::__private::LenPacker(0);
+                                let packed: Result<(), core::convert::Infallible> = (|packer: &mut #crate_name::__private::LenPacker| { #pack })(&mut len_packer);
+                                if let Err(err) = packed {
+                                    match err {}
+                                }
+                                <#length_prefix as #crate_name::Packable>::pack(&<#length_prefix as TryFrom<usize>>::try_from(len_packer.0).ok().expect("the length of the variant payload exceeds the bounds of its length prefix"), packer)?;
+                                #pack
+                            },
+                            quote! {
+                                let len = #payload_len;
+                                // The payload is unpacked within its declared length, so that it never reads the bytes that follow it.
+                                let mut limited = #crate_name::unpacker::LimitedUnpacker::new(&mut *unpacker, len);
+                                let unpacked: Result<Self, #crate_name::error::UnpackError<#variant_error, #crate_name::error::LimitedUnpackerError<U::Error>>> = (|unpacker: &mut #crate_name::unpacker::LimitedUnpacker<_>| { #limited_unpack })(&mut limited);
+                                let unpacked = unpacked.map_err(|err| match err {
+                                    #crate_name::error::UnpackError::Packable(err) => #crate_name::error::UnpackError::Packable(err),
+                                    #crate_name::error::UnpackError::Unpacker(#crate_name::error::LimitedUnpackerError::Unpacker(err)) => #crate_name::error::UnpackError::Unpacker(err),
+                                    #crate_name::error::UnpackError::Unpacker(#crate_name::error::LimitedUnpackerError::SizeLimitExceeded { limit, required }) => {
+                                        #crate_name::error::UnpackError::from_packable(#crate_name::error::VariantLengthError { len: limit, consumed: required })
+                                    }
+                                })?;
+
+                                // Bytes of the payload that are not known to this version of the variant are skipped.
+                                let remaining = limited.remaining_budget();
+                                unpacker.skip_bytes(remaining)?;
+
+                                Ok(unpacked)
+                            },
+                        )
+                    } else {
+                        (pack, unpack)
+                    };
+
+                    let unpack = if info.tag_after {
+                        // The payload has already been read, it is unpacked from a slice once the tag is known.
+                        let payload_eof = if info.length_prefixed_variants.is_some() {
+                            quote!(#crate_name::error::UnpackError::Unpacker(err) => #crate_name::error::UnpackError::from_packable(#crate_name::error::VariantLengthError { len, consumed: len - err.had + err.required }))
+                        } else {
+                            quote!(#crate_name::error::UnpackError::Unpacker(_) => unreachable!("the payload has the packed size of the variant"))
+                        };
 
-                    unpack_arms.push(quote!(#tag_ident => {
-                        #unpack
-                    }));
+                        quote! {
+                            let unpacked: Result<Self, #crate_name::error::UnpackError<#variant_error, #crate_name::error::UnexpectedEOF>> = (|unpacker: &mut #crate_name::unpacker::SliceUnpacker<'_>| { #payload_unpack })(&mut #crate_name::unpacker::SliceUnpacker::new(&payload));
+
+                            unpacked.map_err(|err| match err {
+                                #crate_name::error::UnpackError::Packable(err) => #crate_name::error::UnpackError::Packable(err),
+                                #payload_eof,
+                            })
+                        }
+                    } else {
+                        unpack
+                    };
+
+                    if info.tag_after {
+                        pack_arms.push(quote!(#pattern => {
+                            let packed: Result<(), P::Error> = (|| { #pack })();
+                            packed?;
+                            <#tag_type as #crate_name::Packable>::pack(&#tag, packer)
+                        }));
+                    } else {
+                        pack_arms.push(quote!(#pattern => {
+                            <#tag_type as #crate_name::Packable>::pack(&#tag, packer)?;
+                            #pack
+                        }));
+                    }
+
+                    if info.payload_error.is_some() {
+                        // The errors of the payload are wrapped along with the tag of its variant.
+                        unpack_arms.push(quote!(#tag_ident => {
+                            let unpacked: Result<Self, #crate_name::error::UnpackError<#variant_error, U::Error>> = (|| { #unpack })();
+
+                            unpacked.map_packable_err(|inner| #crate_name::error::EnumUnpackError::Payload { tag: #tag_ident, inner })
+                        }));
+                    } else {
+                        unpack_arms.push(quote!(#tag_ident => {
+                            #unpack
+                        }));
+                    }
 
                     tag_decls.push(quote!(const #tag_ident: #tag_type = #tag;));
 
+                    if tag_consts {
+                        let const_ident = format_ident!(
+                            "{}_TAG",
+                            screaming_snake_case(&variant_ident.ident.to_string()),
+                            span = variant_ident.ident.span()
+                        );
+                        let doc = format!("The tag of the `{}` variant.", variant_ident.ident);
+
+                        variant_tag_consts.push((const_ident, variant_ident.ident.clone(), doc, tag.clone()));
+                    }
+
                     tag_variants_and_idents.push((tag_ident, variant_ident));
                 }
 
@@ -101,6 +327,66 @@ impl TraitImpl {
                     }
                 }
 
+                // The payload of an unknown variant is skipped so that unpacking can go on after the error.
+                let skip_unknown_payload = match &info.length_prefixed_variants {
+                    Some(length_prefix) if !info.tag_after => {
+                        let payload_len = payload_len(length_prefix, &crate_name);
+
+                        quote! {
+                            let len = #payload_len;
+                            unpacker.skip_bytes(len)?;
+                        }
+                    }
+                    _ => quote!(),
+                };
+
+                let unknown_tag_arm = if bool_tag {
+                    quote!()
+                } else {
+                    quote! {
+                        tag => {
+                            #skip_unknown_payload
+                            Err(#crate_name::error::UnpackError::from_packable(#tag_with_error(tag)))
+                        }
+                    }
+                };
+
+                // The payload that precedes the tag is read beforehand, so that its variant is known when unpacking it.
+                let read_payload = if info.tag_after {
+                    let len = if let Some(length_prefix) = &info.length_prefixed_variants {
+                        payload_len(length_prefix, &crate_name)
+                    } else {
+                        let mut payload_sizes = payload_sizes.into_iter();
+                        let len = payload_sizes.next().unwrap_or_else(|| quote!(0));
+                        let size_asserts = payload_sizes.map(|size| {
+                            let size_assert = quote!(assert!(#size == #len, concat!("The payloads of the variants of enum `", stringify!(#enum_ident), "` must have the same packed size to use `tag_after`")));
+
+                            // Sizes depending on generic parameters can only be checked once the types are known.
+                            if input.generics.params.is_empty() {
+                                quote!(const _: () = #size_assert;)
+                            } else {
+                                quote!(const { #size_assert };)
+                            }
+                        });
+
+                        quote! {
+                            {
+                                #(#size_asserts)*
+                                #len
+                            }
+                        }
+                    };
+
+                    // The payload is only allocated upfront if the unpacker knows that it holds it, so that a hostile length
+                    // prefix fails with the error of the unpacker instead of allocating its length.
+                    quote! {
+                        let len = #len;
+                        let payload = unpacker.unpack_slice(len)?.into_owned();
+                    }
+                } else {
+                    quote!()
+                };
+
                 Ok(Self {
                     ident: enum_ident.clone(),
                     generics: input.generics,
@@ -112,12 +398,54 @@ impl TraitImpl {
                     unpack: quote! {
                         #(#tag_decls)*
                         #(#tag_asserts)*
+                        #read_payload
 
                         match <#tag_type as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, Borrow::<<#tag_type as #crate_name::Packable>::UnpackVisitor>::borrow(visitor)).coerce()? {
                             #(#unpack_arms)*
-                            tag => Err(#crate_name::error::UnpackError::from_packable(#tag_with_error(tag)))
+                            #unknown_tag_arm
                         }
                     },
+                    packed_size: None,
+                    tag_consts: tag_consts.then(|| {
+                        let consts = variant_tag_consts.iter().map(|(const_ident, _, doc, tag)| {
+                            quote! {
+                                #[doc = #doc]
+                                pub const #const_ident: #tag_type = #tag;
+                            }
+                        });
+                        let arms = variant_tag_consts
+                            .iter()
+                            .map(|(const_ident, variant_ident, ..)| quote!(Self::#variant_ident { .. } => Self::#const_ident,));
+
+                        // Only a tag made of a single byte packed before the payload can be peeked.
+                        let peek_tag = if tag_type.to_token_stream().to_string() == "u8" && !info.tag_after {
+                            quote! {
+                                /// Returns the tag of the next value of the unpacker without consuming it, or `None` if
+                                /// there are no bytes left, so that the value can be unpacked speculatively.
+                                pub fn peek_tag<U: #crate_name::unpacker::Unpacker>(unpacker: &mut U) -> Result<Option<u8>, U::Error> {
+                                    unpacker.peek_u8()
+                                }
+                            }
+                        } else {
+                            quote!()
+                        };
+
+                        quote! {
+                            #(#consts)*
+
+                            /// Returns the tag of the variant of this value.
+                            pub const fn tag(&self) -> #tag_type {
+                                match self {
+                                    #(#arms)*
+                                }
+                            }
+
+                            #peek_tag
+                        }
+                    }),
+                    inherent_helpers,
+                    inline,
+                    roundtrip_test,
                     crate_name,
                 })
             }
@@ -127,6 +455,112 @@ impl TraitImpl {
             )),
         }
     }
+
+    /// Builds the implementation of a `#[repr(transparent)]` struct with the `#[packable(transparent)]` attribute, which
+    /// delegates to the implementation of its single field.
+    fn new_transparent(
+        input: DeriveInput,
+        crate_name: Ident,
+        inherent_helpers: bool,
+        roundtrip_test: bool,
+    ) -> Result<Self> {
+        /// Container attributes that would make the struct differ from its field.
+        const CONFLICTING_IDENTS: &[&str] = &["unpack_error", "unpack_visitor", "verify_with", "presence_bitfield"];
+
+        let fields = match input.data {
+            Data::Struct(data) => data.fields,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "The `transparent` attribute can only be used on structs.",
+                ))
+            }
+        };
+
+        let repr_transparent = input.attrs.iter().any(|attr| {
+            attr.path().is_ident("repr") && attr.parse_args::<Ident>().is_ok_and(|repr| repr == "transparent")
+        });
+
+        if !repr_transparent {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "The `transparent` attribute requires the struct to be `#[repr(transparent)]`.",
+            ));
+        }
+
+        if fields.len() != 1 {
+            return Err(syn::Error::new(
+                fields.span(),
+                "The `transparent` attribute can only be used on structs with a single field.",
+            ));
+        }
+
+        for attr in filter_attrs(&input.attrs) {
+            let ident = attr.parse_args_with(|stream: ParseStream| {
+                let ident = stream.parse::<Ident>()?;
+                skip_stream(stream)?;
+                Ok(ident)
+            })?;
+
+            if CONFLICTING_IDENTS.iter().any(|conflicting| ident == conflicting) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("The `transparent` attribute cannot be combined with the `{}` attribute.", ident),
+                ));
+            }
+        }
+
+        let field = fields.into_iter().next().unwrap();
+
+        if let Some(attr) = filter_attrs(&field.attrs).next() {
+            return Err(syn::Error::new(
+                attr.span(),
+                "The field of a struct with the `transparent` attribute cannot have `packable` attributes.",
+            ));
+        }
+
+        let ty = field.ty;
+        let member = field.ident.map_or_else(|| Member::Unnamed(Index::from(0)), Member::Named);
+
+        let packed_size = if Self::flag(&input.attrs, "packed_size")? {
+            Some(quote!(<#ty as #crate_name::PackedSize>::PACKED_SIZE))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics,
+            unpack_error: quote!(<#ty as #crate_name::Packable>::UnpackError),
+            unpack_visitor: quote!(<#ty as #crate_name::Packable>::UnpackVisitor),
+            pack: quote!(<#ty as #crate_name::Packable>::pack(&self.#member, packer)),
+            unpack: quote!(<#ty as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, visitor).map(|inner| Self { #member: inner })),
+            packed_size,
+            tag_consts: None,
+            inherent_helpers,
+            // The methods only forward to the ones of the field, so they are always worth inlining.
+            inline: true,
+            roundtrip_test,
+            crate_name,
+        })
+    }
+
+    /// Returns whether the container has the `#[packable(<ident>)]` flag.
+    fn flag(attrs: &[Attribute], ident: &'static str) -> Result<bool> {
+        for attr in filter_attrs(attrs) {
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag(ident, stream)?;
+                if !found {
+                    skip_stream(stream)?;
+                }
+                Ok(found)
+            })? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
 }
 
 impl ToTokens for TraitImpl {
@@ -138,9 +572,16 @@ impl ToTokens for TraitImpl {
             unpack_visitor,
             pack,
             unpack,
+            packed_size,
+            tag_consts,
+            inherent_helpers,
+            inline,
+            roundtrip_test,
             crate_name,
         } = &self;
 
+        let inline = if *inline { quote!(#[inline]) } else { quote!() };
+
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let impl_tokens = quote! {
@@ -148,11 +589,13 @@ impl ToTokens for TraitImpl {
                 type UnpackError = #unpack_error;
                 type UnpackVisitor = #unpack_visitor;
 
+                #inline
                 fn pack<P: #crate_name::packer::Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
                     use #crate_name::error::UnpackErrorExt;
                     #pack
                 }
 
+                #inline
                 fn unpack<U: #crate_name::unpacker::Unpacker, const VERIFY: bool>(unpacker: &mut U, visitor: &Self::UnpackVisitor) -> Result<Self, #crate_name::error::UnpackError<Self::UnpackError, U::Error>> {
                     use #crate_name::error::UnpackErrorExt;
                     use core::borrow::Borrow;
@@ -162,5 +605,103 @@ impl ToTokens for TraitImpl {
         };
 
         impl_tokens.to_tokens(tokens);
+
+        if let Some(packed_size) = packed_size {
+            let packed_size_tokens = quote! {
+                impl #impl_generics #crate_name::PackedSize for #type_name #ty_generics #where_clause {
+                    const PACKED_SIZE: usize = #packed_size;
+                }
+            };
+
+            packed_size_tokens.to_tokens(tokens);
+        }
+
+        if let Some(tag_consts) = tag_consts {
+            let tag_consts_tokens = quote! {
+                impl #impl_generics #type_name #ty_generics #where_clause {
+                    #tag_consts
+                }
+            };
+
+            tag_consts_tokens.to_tokens(tokens);
+        }
+
+        if *inherent_helpers {
+            let inherent_helpers_tokens = quote! {
+                impl #impl_generics #type_name #ty_generics #where_clause {
+                    /// Packs this value into a vector of bytes.
+                    pub fn to_packed_bytes(&self) -> #crate_name::__private::Vec<u8> {
+                        <Self as #crate_name::PackableExt>::pack_to_vec(self)
+                    }
+
+                    /// Unpacks a value from a sequence of bytes doing syntactical checks, using the default unpack
+                    /// visitor.
+                    pub fn from_packed_bytes(bytes: &[u8]) -> Result<Self, #crate_name::error::UnpackError<<Self as #crate_name::Packable>::UnpackError, #crate_name::error::UnexpectedEOF>> {
+                        <Self as #crate_name::PackableExt>::unpack_verified(bytes, &core::default::Default::default())
+                    }
+                }
+            };
+
+            inherent_helpers_tokens.to_tokens(tokens);
+        }
+
+        if *roundtrip_test {
+            let module_ident = format_ident!("__packable_roundtrip_test_{}", type_name);
+
+            let roundtrip_test_tokens = quote! {
+                #[cfg(test)]
+                #[allow(non_snake_case)]
+                mod #module_ident {
+                    use super::*;
+
+                    // Bytes are compared instead of values so that the type does not need to implement `PartialEq`.
+                    #[test]
+                    fn packable_roundtrip() {
+                        let value = <#type_name as core::default::Default>::default();
+                        let bytes = <#type_name as #crate_name::PackableExt>::pack_to_vec(&value);
+                        let unpacked = <#type_name as #crate_name::PackableExt>::unpack_verified(&bytes, &core::default::Default::default())
+                            .expect("the default value cannot be unpacked from its packed bytes");
+
+                        assert_eq!(<#type_name as #crate_name::PackableExt>::pack_to_vec(&unpacked), bytes);
+                    }
+                }
+            };
+
+            roundtrip_test_tokens.to_tokens(tokens);
+        }
+    }
+}
+
+/// Converts the `CamelCase` identifier of a variant into the `SCREAMING_SNAKE_CASE` of a constant.
+///
+/// A word starts at every uppercase letter following a lowercase letter or a digit, and at the last uppercase letter
+/// of an acronym followed by a lowercase letter, e.g. `HttpRequest` and `HTTPRequest` both become `HTTP_REQUEST`.
+fn screaming_snake_case(ident: &str) -> String {
+    let chars = ident.chars().collect::<Vec<_>>();
+    let mut screaming_snake_case = String::with_capacity(ident.len());
+
+    for (index, &c) in chars.iter().enumerate() {
+        if index > 0 && c.is_uppercase() {
+            let prev = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lowercase) {
+                screaming_snake_case.push('_');
+            }
+        }
+
+        screaming_snake_case.extend(c.to_uppercase());
+    }
+
+    screaming_snake_case
+}
+
+/// Unpacks the length prefix of a variant payload as a `usize`.
+///
+/// A length that does not fit in a `usize` saturates instead of wrapping around, so that it still bounds the payload
+/// and the unpacker fails to provide its bytes rather than the payload being read as a shorter one.
+fn payload_len(length_prefix: &Type, crate_name: &Ident) -> TokenStream {
+    quote! {
+        usize::try_from(<#length_prefix as #crate_name::Packable>::unpack::<_, VERIFY>(unpacker, &()).coerce()?).unwrap_or(usize::MAX)
     }
 }
@@ -5,11 +5,11 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
-    parse_quote, Error, Expr, ExprLit, ExprPath, Result, Variant,
+    parse_quote, Error, Expr, ExprLit, ExprPath, Ident, Lit, LitBool, LitInt, LitStr, Result, Type, Variant,
 };
 
 use crate::{
-    parse::{filter_attrs, parse_kv},
+    parse::{filter_attrs, parse_flag, parse_kv, skip_stream},
     record_info::RecordInfo,
 };
 
@@ -22,6 +22,13 @@ pub(crate) enum ExprTag {
 impl Parse for ExprTag {
     fn parse(input: ParseStream) -> Result<Self> {
         match ExprLit::parse(input) {
+            Ok(ExprLit {
+                attrs,
+                lit: Lit::Str(lit),
+            }) => Ok(Self::Lit(ExprLit {
+                attrs,
+                lit: Lit::Int(parse_int_str(&lit)?),
+            })),
             Ok(lit) => Ok(Self::Lit(lit)),
             Err(_) => Ok(Self::Path(ExprPath::parse(input).map_err(|err| {
                 Error::new(err.span(), "Tags for variants can only be literal or path expressions.")
@@ -30,6 +37,29 @@ impl Parse for ExprTag {
     }
 }
 
+/// Parses a string literal holding an integer, like `"0x0A"`, into an unsuffixed integer literal.
+///
+/// The `0x`, `0o` and `0b` prefixes are supported and underscores are ignored.
+fn parse_int_str(lit: &LitStr) -> Result<LitInt> {
+    let value = lit.value().replace('_', "");
+
+    let (digits, radix) = match value.get(..2) {
+        Some("0x" | "0X") => (&value[2..], 16),
+        Some("0o" | "0O") => (&value[2..], 8),
+        Some("0b" | "0B") => (&value[2..], 2),
+        _ => (value.as_str(), 10),
+    };
+
+    let value = u128::from_str_radix(digits, radix).map_err(|_| {
+        Error::new(
+            lit.span(),
+            "String tags for variants must contain an integer, optionally prefixed by `0x`, `0o` or `0b`.",
+        )
+    })?;
+
+    Ok(LitInt::new(&value.to_string(), lit.span()))
+}
+
 impl ToTokens for ExprTag {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
@@ -41,39 +71,73 @@ impl ToTokens for ExprTag {
 
 pub(crate) struct VariantInfo {
     pub(crate) tag: ExprTag,
+    pub(crate) strict_len: bool,
+    pub(crate) length_prefix: Option<Type>,
     pub(crate) inner: RecordInfo,
 }
 
 impl VariantInfo {
-    pub(crate) fn new(variant: &Variant, enum_ident: &syn::Ident, default_unpack_error_with: &Expr) -> Result<Self> {
+    pub(crate) fn new(
+        variant: &Variant,
+        index: usize,
+        bool_tag: bool,
+        explicit_discriminants: bool,
+        enum_ident: &Ident,
+        default_unpack_error_with: &Expr,
+    ) -> Result<Self> {
         let variant_ident = variant.ident.clone();
 
+        let mut tag_opt = None;
+        let mut strict_len = false;
+        let mut length_prefix_opt = None;
+
         for attr in filter_attrs(&variant.attrs) {
-            if let Some(tag) = attr.parse_args_with(|stream: ParseStream| parse_kv("tag", stream))? {
-                return Ok(Self {
-                    tag,
-                    inner: RecordInfo::new(
-                        parse_quote!(#enum_ident::#variant_ident),
-                        &variant.fields,
-                        default_unpack_error_with,
-                    )?,
-                });
+            let ident = attr.parse_args_with(|stream: ParseStream| {
+                let ident = stream.parse::<Ident>()?;
+                skip_stream(stream)?;
+                Ok(ident)
+            })?;
+
+            if ident == "strict_len" {
+                strict_len = attr.parse_args_with(|stream: ParseStream| parse_flag("strict_len", stream))?;
+            } else if ident == "length_prefix" {
+                length_prefix_opt = attr.parse_args_with(|stream: ParseStream| parse_kv("length_prefix", stream))?;
+            } else if tag_opt.is_none() {
+                tag_opt = attr.parse_args_with(|stream: ParseStream| parse_kv("tag", stream))?;
             }
         }
 
-        match &variant.discriminant {
-            Some((_, tag)) => Ok(Self {
-                tag: parse_quote!(#tag),
-                inner: RecordInfo::new(
-                    parse_quote!(#enum_ident::#variant_ident),
-                    &variant.fields,
-                    default_unpack_error_with,
-                )?,
-            }),
-            None => Err(Error::new(
+        let tag = match (tag_opt, &variant.discriminant) {
+            (Some(tag), _) => tag,
+            (None, Some((_, tag))) => parse_quote!(#tag),
+            // Implicit discriminants follow the previous discriminant, so they cannot be used as tags.
+            (None, None) if explicit_discriminants => {
+                return Err(Error::new(
                 variant_ident.span(),
                 "All variants of an enum that derives `Packable` require a `#[packable(tag = ...)]` attribute or an explicitly set discriminant.",
-            )),
-        }
+            ))
+            }
+            // Variants without a tag are numbered in declaration order, regardless of the tags of other variants.
+            (None, None) => {
+                let lit = if bool_tag {
+                    Lit::Bool(LitBool::new(index != 0, variant_ident.span()))
+                } else {
+                    Lit::Int(LitInt::new(&index.to_string(), variant_ident.span()))
+                };
+
+                ExprTag::Lit(ExprLit { attrs: Vec::new(), lit })
+            }
+        };
+
+        Ok(Self {
+            tag,
+            strict_len,
+            length_prefix: length_prefix_opt,
+            inner: RecordInfo::new(
+                parse_quote!(#enum_ident::#variant_ident),
+                &variant.fields,
+                default_unpack_error_with,
+            )?,
+        })
     }
 }
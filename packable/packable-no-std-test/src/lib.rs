@@ -0,0 +1,108 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Smoke test checking that `packable` can be used without `std` and `alloc`.
+//!
+//! This crate depends on `packable` with its default features disabled, so it fails to build if `packable` or the code
+//! generated by its derive macros use items that are only declared behind the `alloc` feature, like `Vec`. The `alloc`
+//! crate itself is available on any target with `std`, so the CI checks this crate for a target without `std`, e.g.
+//! with `cargo check --no-default-features --target=riscv64gc-unknown-none-elf --manifest-path
+//! packable/packable-no-std-test/Cargo.toml`. Build it on its own, as building the whole workspace unifies the
+//! features of `packable` with the ones enabled by other crates.
+
+#![no_std]
+#![deny(missing_docs)]
+
+use packable::{
+    Packable,
+    error::{UnexpectedEOF, UnpackError, VariantLengthError},
+    packer::{Packer, SlicePacker},
+    unpacker::SliceUnpacker,
+};
+
+/// A fixed-size header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Packable)]
+pub struct Header {
+    /// The version of the protocol.
+    pub version: u8,
+    /// An identifier.
+    pub id: [u8; 4],
+    /// Whether the command must be acknowledged.
+    pub ack: bool,
+}
+
+/// A command made of a header and a payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8, with_error = UnknownCommand)]
+#[packable(unpack_error = UnknownCommand)]
+pub enum Command {
+    /// Checks that the peer is alive.
+    #[packable(tag = 0)]
+    Ping(Header),
+    /// Acknowledges a previous command.
+    #[packable(tag = 1)]
+    Ack {
+        /// The header of the command.
+        header: Header,
+        /// The acknowledged sequence number.
+        sequence: u32,
+    },
+}
+
+/// Error raised when unpacking a [`Command`] with an unknown tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownCommand(pub u8);
+
+impl From<core::convert::Infallible> for UnknownCommand {
+    fn from(err: core::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+/// An event whose payload is prefixed by its length, so that the payloads of unknown events can be skipped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8, with_error = EventError::UnknownTag)]
+#[packable(unpack_error = EventError)]
+#[packable(length_prefixed_variants)]
+pub enum Event {
+    /// A command has been received.
+    #[packable(tag = 0)]
+    #[packable(length_prefix = u8)]
+    Received(Header),
+}
+
+/// Error raised when unpacking an [`Event`].
+#[derive(Debug)]
+pub enum EventError {
+    /// The tag of the event is unknown.
+    UnknownTag(u8),
+    /// The payload of the event is longer than its prefix declares.
+    VariantLength(VariantLengthError),
+}
+
+impl From<core::convert::Infallible> for EventError {
+    fn from(err: core::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<VariantLengthError> for EventError {
+    fn from(err: VariantLengthError) -> Self {
+        Self::VariantLength(err)
+    }
+}
+
+/// Packs a [`Command`] into a buffer and returns the number of written bytes.
+pub fn encode(command: &Command, buffer: &mut [u8]) -> Result<usize, UnexpectedEOF> {
+    let mut packer = SlicePacker::new(buffer);
+
+    command.pack(&mut packer)?;
+
+    // A `SlicePacker` always keeps track of its written bytes.
+    Ok(packer.written_bytes().unwrap_or_default())
+}
+
+/// Unpacks a [`Command`] from a buffer.
+pub fn decode(buffer: &[u8]) -> Result<Command, UnpackError<UnknownCommand, UnexpectedEOF>> {
+    Command::unpack::<_, true>(&mut SliceUnpacker::new(buffer), &())
+}
@@ -0,0 +1,27 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::error::UnpackError;
+use packable_no_std_test::{Command, Header, UnknownCommand, decode, encode};
+
+#[test]
+fn roundtrip() {
+    let command = Command::Ack {
+        header: Header {
+            version: 1,
+            id: [1, 2, 3, 4],
+            ack: false,
+        },
+        sequence: 42,
+    };
+    let mut buffer = [0u8; 32];
+
+    let len = encode(&command, &mut buffer).unwrap();
+
+    assert_eq!(decode(&buffer[..len]).unwrap(), command);
+}
+
+#[test]
+fn unknown_command() {
+    assert!(matches!(decode(&[2]), Err(UnpackError::Packable(UnknownCommand(2)))));
+}
@@ -115,6 +115,140 @@ impl From<UnpackError<Infallible, Infallible>> for Infallible {
     }
 }
 
+/// Error type raised when an element of an array or a sequence cannot be unpacked.
+#[derive(Debug)]
+pub struct ArrayUnpackError<E> {
+    /// The zero-based position of the element that could not be unpacked.
+    pub index: usize,
+    /// The error raised while unpacking the element.
+    pub inner: E,
+}
+
+impl<E> ArrayUnpackError<E> {
+    /// Consumes the value to return the error raised while unpacking the element.
+    pub fn into_inner(self) -> E {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ArrayUnpackError<E> where E: fmt::Display + fmt::Debug {}
+
+impl<E> From<Infallible> for ArrayUnpackError<E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<ArrayUnpackError<Infallible>> for Infallible {
+    fn from(err: ArrayUnpackError<Infallible>) -> Self {
+        match err.inner {}
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ArrayUnpackError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot unpack element at index {}: {}", self.index, self.inner)
+    }
+}
+
+/// Error type raised when a sized region still has bytes left after unpacking a value that must consume it
+/// completely.
+#[derive(Debug)]
+pub struct TrailingBytesError {
+    /// The number of bytes left in the region.
+    pub remaining: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TrailingBytesError {}
+
+impl fmt::Display for TrailingBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} trailing bytes were not consumed", self.remaining)
+    }
+}
+
+/// Error type raised when [`PackableExt::unpack_exact`](crate::PackableExt::unpack_exact) cannot unpack a value from
+/// all the given bytes.
+#[derive(Debug)]
+pub enum ExactUnpackError {
+    /// There are not enough bytes to unpack the value.
+    UnexpectedEOF(UnexpectedEOF),
+    /// Bytes are left after unpacking the value.
+    TrailingBytes(TrailingBytesError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExactUnpackError {}
+
+impl From<UnexpectedEOF> for ExactUnpackError {
+    fn from(err: UnexpectedEOF) -> Self {
+        Self::UnexpectedEOF(err)
+    }
+}
+
+impl From<TrailingBytesError> for ExactUnpackError {
+    fn from(err: TrailingBytesError) -> Self {
+        Self::TrailingBytes(err)
+    }
+}
+
+impl fmt::Display for ExactUnpackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEOF(err) => err.fmt(f),
+            Self::TrailingBytes(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Error type raised when a declared number of fixed-size elements does not fit in the bytes left in a sized region.
+#[derive(Debug)]
+pub struct ElementCountError {
+    /// The declared number of elements.
+    pub count: usize,
+    /// The packed size of each element.
+    pub element_size: usize,
+    /// The number of bytes left in the region.
+    pub remaining: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ElementCountError {}
+
+impl fmt::Display for ElementCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} elements of {} bytes do not fit in the {} remaining bytes",
+            self.count, self.element_size, self.remaining
+        )
+    }
+}
+
+/// Error type raised when the payload of an enum variant with a length prefix is longer than its prefix declares.
+#[derive(Debug)]
+pub struct VariantLengthError {
+    /// The length of the payload, as declared by its prefix.
+    pub len: usize,
+    /// The number of bytes required while unpacking the payload.
+    pub consumed: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VariantLengthError {}
+
+impl fmt::Display for VariantLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "variant payload of {} bytes consumed {} bytes while unpacking",
+            self.len, self.consumed
+        )
+    }
+}
+
 /// Error type raised when an unknown tag is found while unpacking.
 #[derive(Debug)]
 pub struct UnknownTagError<T>(pub T);
@@ -134,9 +268,58 @@ impl<T: fmt::Display> fmt::Display for UnknownTagError<T> {
     }
 }
 
+/// Error type raised when unpacking an enum with the `#[packable(payload_error = ...)]` attribute, telling unknown tags
+/// apart from malformed payloads of known variants.
+///
+/// An unknown tag typically comes from a newer version of a protocol, while a malformed payload is usually a sign of
+/// corrupted data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumUnpackError<T, E> {
+    /// The tag does not belong to any variant of the enum.
+    UnknownTag(T),
+    /// The tag belongs to a variant of the enum, but its payload could not be unpacked.
+    Payload {
+        /// The tag of the variant.
+        tag: T,
+        /// The error raised while unpacking the payload.
+        inner: E,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<T, E> std::error::Error for EnumUnpackError<T, E>
+where
+    T: fmt::Display + fmt::Debug,
+    E: fmt::Display + fmt::Debug,
+{
+}
+
+impl<T, E> From<Infallible> for EnumUnpackError<T, E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<T, E> From<UnknownTagError<T>> for EnumUnpackError<T, E> {
+    fn from(err: UnknownTagError<T>) -> Self {
+        Self::UnknownTag(err.0)
+    }
+}
+
+impl<T: fmt::Display, E: fmt::Display> fmt::Display for EnumUnpackError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownTag(tag) => write!(f, "unknown tag value {}", tag),
+            Self::Payload { tag, inner } => write!(f, "invalid payload for tag value {}: {}", tag, inner),
+        }
+    }
+}
+
 /// Error type to be raised when `&[u8]` does not have enough bytes to unpack something or when
-/// [`SlicePacker`]('crate::packer::SlicePacker') does not have enough space to pack something.
-#[derive(Debug)]
+/// [`SlicePacker`](crate::packer::SlicePacker) does not have enough space to pack something.
+///
+/// The counts refer to the read or write that failed, not to the whole value being packed or unpacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnexpectedEOF {
     /// The required number of bytes.
     pub required: usize,
@@ -152,3 +335,145 @@ impl fmt::Display for UnexpectedEOF {
         write!(f, "not enough bytes, required {} but had {}", self.required, self.had)
     }
 }
+
+/// Error type raised when [`LimitedPacker`](crate::packer::LimitedPacker) fails to pack bytes.
+#[derive(Debug)]
+pub enum LimitedPackerError<E> {
+    /// The inner packer failed to write the bytes.
+    Packer(E),
+    /// Writing the bytes would exceed the size limit of the packer.
+    SizeLimitExceeded {
+        /// The maximum number of bytes the packer can receive.
+        limit: usize,
+        /// The total number of bytes that would have been written.
+        required: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for LimitedPackerError<E> {}
+
+impl<E: fmt::Display> fmt::Display for LimitedPackerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Packer(err) => err.fmt(f),
+            Self::SizeLimitExceeded { limit, required } => {
+                write!(f, "size limit of {} bytes exceeded, required {} bytes", limit, required)
+            }
+        }
+    }
+}
+
+/// Error type raised when [`Packer::pack_prefixed_bytes_with`](crate::packer::Packer::pack_prefixed_bytes_with)
+/// fails, where `P` is the error raised while converting the length of the bytes into the prefix and `E` the error of
+/// the packer.
+#[derive(Debug)]
+pub enum PackPrefixedBytesError<P, E> {
+    /// The length of the bytes is out of the bounds of the prefix.
+    Prefix(P),
+    /// The packer failed to write the prefix or the bytes.
+    Packer(E),
+}
+
+#[cfg(feature = "std")]
+impl<P: fmt::Debug + fmt::Display, E: fmt::Debug + fmt::Display> std::error::Error for PackPrefixedBytesError<P, E> {}
+
+impl<P: fmt::Display, E: fmt::Display> fmt::Display for PackPrefixedBytesError<P, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix(err) => write!(f, "the length of the bytes is out of the bounds of the prefix: {}", err),
+            Self::Packer(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Error type raised when [`LimitedUnpacker`](crate::unpacker::LimitedUnpacker) fails to unpack bytes.
+#[derive(Debug)]
+pub enum LimitedUnpackerError<E> {
+    /// The inner unpacker failed to read the bytes.
+    Unpacker(E),
+    /// Reading the bytes would exceed the size limit of the unpacker.
+    SizeLimitExceeded {
+        /// The maximum number of bytes the unpacker can read.
+        limit: usize,
+        /// The total number of bytes that would have been read.
+        required: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for LimitedUnpackerError<E> {}
+
+impl<E: fmt::Display> fmt::Display for LimitedUnpackerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unpacker(err) => err.fmt(f),
+            Self::SizeLimitExceeded { limit, required } => {
+                write!(f, "size limit of {} bytes exceeded, required {} bytes", limit, required)
+            }
+        }
+    }
+}
+
+/// Error type raised when [`HexUnpacker`](crate::unpacker::HexUnpacker) cannot decode its input.
+#[derive(Debug)]
+pub enum HexError {
+    /// The hexadecimal string has an odd number of digits.
+    OddLength,
+    /// The hexadecimal string contains a character that is not a hexadecimal digit.
+    InvalidCharacter {
+        /// The invalid character.
+        c: char,
+        /// The position of the invalid character in the string.
+        index: usize,
+    },
+    /// There are not enough bytes to unpack a value.
+    UnexpectedEOF(UnexpectedEOF),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexError {}
+
+impl From<UnexpectedEOF> for HexError {
+    fn from(err: UnexpectedEOF) -> Self {
+        Self::UnexpectedEOF(err)
+    }
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => write!(f, "odd number of hexadecimal digits"),
+            Self::InvalidCharacter { c, index } => {
+                write!(f, "invalid hexadecimal character {:?} at index {}", c, index)
+            }
+            Self::UnexpectedEOF(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Error type raised when [`Base64Unpacker`](crate::unpacker::Base64Unpacker) cannot decode its input.
+#[cfg(feature = "base64")]
+#[derive(Debug)]
+pub enum Base64Error<E> {
+    /// The inner unpacker failed to read the encoded input.
+    Unpacker(E),
+    /// The encoded input is not valid base64.
+    Decode(base64::DecodeError),
+    /// There are not enough bytes to unpack a value.
+    UnexpectedEOF(UnexpectedEOF),
+}
+
+#[cfg(all(feature = "base64", feature = "std"))]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for Base64Error<E> {}
+
+#[cfg(feature = "base64")]
+impl<E: fmt::Display> fmt::Display for Base64Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unpacker(err) => err.fmt(f),
+            Self::Decode(err) => write!(f, "invalid base64: {}", err),
+            Self::UnexpectedEOF(err) => err.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate std;
+
+use std::io::{self, ErrorKind};
+
+use crate::{
+    Packable,
+    error::{TrailingBytesError, UnexpectedEOF, UnpackError},
+    unpacker::{SliceUnpacker, Unpacker},
+};
+
+/// Unpacks a value from the bytes of a frame, which it must consume completely.
+///
+/// The errors of the bytes are reported as [`io::Error`]s: an [`UnexpectedEOF`] is of kind
+/// [`ErrorKind::UnexpectedEof`], and a [`TrailingBytesError`] of kind [`ErrorKind::InvalidData`].
+pub(crate) fn unpack_frame<P: Packable>(
+    bytes: &[u8],
+    visitor: &P::UnpackVisitor,
+) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+    let mut unpacker = SliceUnpacker::new(bytes);
+    let value = P::unpack::<_, true>(&mut unpacker, visitor).map_err(|err| match err {
+        UnpackError::Packable(err) => UnpackError::Packable(err),
+        UnpackError::Unpacker(err) => UnpackError::Unpacker(eof_error(err)),
+    })?;
+
+    match unpacker.remaining_bytes() {
+        Some(0) | None => Ok(value),
+        Some(remaining) => Err(UnpackError::Unpacker(io::Error::new(
+            ErrorKind::InvalidData,
+            TrailingBytesError { remaining },
+        ))),
+    }
+}
+
+/// Returns an [`io::Error`] of kind [`ErrorKind::InvalidData`] if the length of a frame is larger than `max_len`.
+pub(crate) fn check_frame_len(len: usize, max_len: usize) -> io::Result<()> {
+    if len > max_len {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            std::format!("the frame length {} exceeds the maximum of {}", len, max_len),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Converts an [`UnexpectedEOF`] into an [`io::Error`] of kind [`ErrorKind::UnexpectedEof`].
+pub(crate) fn eof_error(err: UnexpectedEOF) -> io::Error {
+    io::Error::new(ErrorKind::UnexpectedEof, err)
+}
@@ -0,0 +1,75 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate std;
+
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    vec::Vec,
+};
+
+use crate::{
+    Packable, PackableExt,
+    error::UnpackError,
+    frame::{check_frame_len, unpack_frame},
+    packer::IoPacker,
+    unpacker::IoUnpacker,
+};
+
+/// Packs a value into a writer implementing [`Write`], using an [`IoPacker`].
+///
+/// Every packed field is written separately, so the writer should be buffered, e.g. with a
+/// [`BufWriter`](std::io::BufWriter), if writes are expensive.
+pub fn to_writer<P: Packable, W: Write>(value: &P, writer: W) -> io::Result<()> {
+    value.pack(&mut IoPacker::new(writer))
+}
+
+/// Unpacks a value from a reader implementing [`Read`] doing syntactical checks, using an [`IoUnpacker`].
+///
+/// Every packed field is read separately, so the reader should be buffered, e.g. with a
+/// [`BufReader`](std::io::BufReader), if reads are expensive. Bytes following the value are not read.
+pub fn from_reader<P: Packable, R: Read>(
+    reader: R,
+    visitor: &P::UnpackVisitor,
+) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+    P::unpack::<_, true>(&mut IoUnpacker::new(reader), visitor)
+}
+
+/// Packs a value into a writer implementing [`Write`] as a frame, prefixed by the length of its packed bytes as a
+/// [`u32`].
+///
+/// The frame is written with a single call. An error of kind [`ErrorKind::InvalidInput`] is returned if the packed
+/// value is longer than [`u32::MAX`] bytes.
+pub fn write_frame<P: Packable, W: Write>(mut writer: W, value: &P) -> io::Result<()> {
+    let len = u32::try_from(value.packed_len())
+        .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "the packed value is too long for a frame"))?;
+    let mut frame = Vec::with_capacity(4 + len as usize);
+
+    // Packing to a `Vec<u8>` cannot fail.
+    len.pack(&mut frame).unwrap();
+    value.pack(&mut frame).unwrap();
+
+    writer.write_all(&frame)
+}
+
+/// Reads a frame written by [`write_frame`] from a reader implementing [`Read`] and unpacks a value from it, doing
+/// syntactical checks.
+///
+/// An error of kind [`ErrorKind::InvalidData`] is returned, before reading the bytes of the frame, if its length is
+/// larger than `max_len`, so that a corrupted or malicious length cannot exhaust memory. The value must consume the
+/// whole frame, otherwise an error of the same kind is returned.
+pub fn read_frame<P: Packable, R: Read>(
+    mut reader: R,
+    max_len: usize,
+    visitor: &P::UnpackVisitor,
+) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    check_frame_len(len, max_len)?;
+
+    let mut frame = std::vec![0u8; len];
+    reader.read_exact(&mut frame)?;
+
+    unpack_frame(&frame, visitor)
+}
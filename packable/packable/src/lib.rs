@@ -29,23 +29,65 @@
 //! and `false` as a `0` byte. However, boolean unpacking is less strict and unpacks any non-zero
 //! byte as `true`.
 //!
-//! Types such as `Box<[T]>`, `[T; N]` and [`Option<T>`] implement [`Packable`] if `T` implements
-//! [`Packable`].
+//! Types such as `Box<[T]>`, `[T; N]`, [`Option<T>`], [`Bound<T>`](core::ops::Bound) and
+//! [`RefCell<T>`](core::cell::RefCell) implement [`Packable`] if `T` implements [`Packable`], as does
+//! [`Cell<T>`](core::cell::Cell) if `T` is also [`Copy`]. Binary payloads can be wrapped in a [`ByteBuf`], which is
+//! packed like a `Vec<u8>` without requiring the `usize` feature.
+//!
+//! IP addresses are packed as their octets, while socket addresses are packed as their IP address followed by their
+//! port and, for [`SocketAddrV6`](core::net::SocketAddrV6), their flow information and scope ID.
 //!
 //! This crate also provides bounded integers under the [`mod@bounded`] module which have additional
 //! syntactical checks to guarantee that the deserialized values are in-bounds. It is also possible
 //! to serialize and deserialize sequences of values by using the types provided in the [`prefix`]
 //! module, which represent linear sequences of values with a length prefix.
 //!
+//! Integers can be packed using an explicit byte order by wrapping them in the types provided by the
+//! [`endian`] module. Floating point values can be packed with a single canonical NaN representation using
+//! the [`float`] module. Sequences of booleans can be packed as bits by using the [`bools`] module. Signed integers
+//! can be packed using zigzag encoding with the [`zigzag`] module. Optional non-zero integers can be packed as the
+//! integer itself, without a tag, with the [`OptionalNonZero`](option::OptionalNonZero) wrapper. Sequences whose
+//! elements must be individually protected against corruption can be packed with a CRC-32 checksum after every
+//! element by using the [`checksum`] module.
+//!
 //! Check the [`Packable`] `impl` section for further information.
 //!
+//! Newtypes wrapping a type that already implements [`Packable`] can use the [`delegate_packable`] macro to reuse
+//! the implementation of the wrapped type.
+//!
+//! The most commonly used traits and error types can be imported at once from the [`prelude`] module.
+//!
 //! # Features
 //!
+//! ## `alloc`
+//!
+//! This feature is enabled by default and implements [`Packable`] for the types that require an allocator, such as
+//! `Box<[T]>`, [`ByteBuf`], [`PackedBools`](bools::PackedBools), [`ChecksummedVec`](checksum::ChecksummedVec) and the
+//! types provided in the [`prefix`] module. It also provides [`PackableExt::pack_to_vec`], the
+//! [`Packer`](packer::Packer) implementation for `Vec<u8>` and
+//! [`Unpacker::unpack_prefixed_bytes`](unpacker::Unpacker::unpack_prefixed_bytes). Without it, this crate can be used
+//! on targets without a global allocator, packing and unpacking values from byte slices.
+//!
+//! ## `base64`
+//!
+//! This feature provides the types [`Base64Packer`](packer::Base64Packer) and
+//! [`Base64Unpacker`](unpacker::Base64Unpacker) which encode packed bytes as base64 on top of another
+//! packer or unpacker, e.g. to store values in text-only formats.
+//!
+//! ## `bytes`
+//!
+//! This feature provides the types [`BytesMutPacker`](packer::BytesMutPacker) and
+//! [`BytesUnpacker`](unpacker::BytesUnpacker) which allow packing into a
+//! [`BytesMut`](bytes::BytesMut) and unpacking from a [`Bytes`](bytes::Bytes) buffer, handing out
+//! zero-copy slices of it if required.
+//!
 //! ## `io`
 //!
 //! This feature provides the types [`IoPacker`](packer::IoPacker) and
 //! [`IoUnpacker`](unpacker::IoUnpacker) which allow packing and unpacking from values whose types
-//! implement [`Write`](std::io::Write) and [`Read`](std::io::Read) respectively.
+//! implement [`Write`](std::io::Write) and [`Read`](std::io::Read) respectively, and the [`to_writer`] and
+//! [`from_reader`] functions using them, as well as [`write_frame`] and [`read_frame`] for values prefixed by their
+//! length.
 //!
 //! ## `primitive-types`
 //!
@@ -59,13 +101,30 @@
 //!
 //! ## `std`
 //!
-//! This feature implements [`Error`](std::error::Error) for all the error types provided by this
-//! crate.
+//! This feature enables the `alloc` feature and implements [`Error`](std::error::Error) for all the error types
+//! provided by this crate. Combined with the `usize` feature, it also implements [`Packable`] for
+//! [`HashMap`](std::collections::HashMap) and provides the [`DeterministicHashMap`](map::DeterministicHashMap) wrapper
+//! which packs its entries sorted by the packed bytes of their keys. It also implements [`Packable`] for
+//! [`CString`](std::ffi::CString), packed as its bytes followed by its trailing NUL byte.
+//!
+//! ## `test-util`
+//!
+//! This feature provides the [`test_util`] module with helpers to check that values round-trip
+//! through packing and unpacking, e.g. [`assert_packable_roundtrip`](test_util::assert_packable_roundtrip), and
+//! that encodings are canonical with [`repack_stable`](test_util::repack_stable).
+//!
+//! ## `tokio`
+//!
+//! This feature provides the types [`AsyncPacker`](packer::AsyncPacker) and
+//! [`AsyncUnpacker`](unpacker::AsyncUnpacker) which allow packing and unpacking from values whose types
+//! implement [`AsyncWrite`](tokio::io::AsyncWrite) and [`AsyncRead`](tokio::io::AsyncRead) respectively, without
+//! blocking the executor. As [`Packable`] is synchronous, the bytes of every value are buffered, and values are
+//! read back as length-prefixed frames or from their fixed packed size.
 //!
 //! ## `usize`
 //!
-//! This feature implements [`Packable`] for [`usize`], [`isize`], [`Vec<T>`](std::vec::Vec),
-//! `Box<[T]>` and [`String`]. This is done serializing and deserializing
+//! This feature implements [`Packable`] for [`usize`], [`isize`] and, combined with the `alloc` feature,
+//! [`Vec<T>`](std::vec::Vec), `Box<[T]>` and [`String`]. This is done serializing and deserializing
 //! pointer sized integers as 64-bit integers. This feature will not work for targets with a
 //! pointer width larger than 64.
 
@@ -75,10 +134,72 @@
 #[cfg(any(doc, feature = "std"))]
 extern crate std;
 
+#[cfg(any(feature = "io", feature = "tokio"))]
+mod frame;
+#[cfg(feature = "io")]
+mod io;
+mod macros;
 mod packable;
 
 pub mod error;
 pub mod packer;
+pub mod prelude;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod unpacker;
 
+#[cfg(feature = "io")]
+pub use crate::io::{from_reader, read_frame, to_writer, write_frame};
 pub use crate::packable::*;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    extern crate alloc;
+
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "alloc")]
+    use core::num::TryFromIntError;
+
+    pub use crate::packer::len::LenPacker;
+
+    #[cfg(feature = "alloc")]
+    use crate::{
+        error::{ArrayUnpackError, UnpackError, UnpackErrorExt},
+        prefix::UnpackPrefixError,
+        unpacker::Unpacker,
+        Packable,
+    };
+
+    /// Error type raised when the elements of a field whose number is held by another field cannot be unpacked, where
+    /// `E` is the error raised while unpacking an element and `U` the error of the unpacker.
+    #[cfg(feature = "alloc")]
+    pub type UnpackCountedError<E, U> = UnpackError<UnpackPrefixError<ArrayUnpackError<E>, TryFromIntError>, U>;
+
+    /// Unpacks the elements of a field whose number is held by another field, the same way the elements of a
+    /// `Vec<T>` are unpacked after its length prefix.
+    #[cfg(feature = "alloc")]
+    pub fn unpack_counted<T: Packable, C: TryInto<usize>, U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        count: C,
+        visitor: &T::UnpackVisitor,
+    ) -> Result<Vec<T>, UnpackCountedError<T::UnpackError, U::Error>>
+    where
+        TryFromIntError: From<C::Error>,
+    {
+        let count = count
+            .try_into()
+            .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err.into())))?;
+
+        let mut vec = Vec::new();
+
+        for index in 0..count {
+            let item = T::unpack::<_, VERIFY>(unpacker, visitor)
+                .map_packable_err(|inner| UnpackPrefixError::Item(ArrayUnpackError { index, inner }))?;
+            vec.push(item);
+        }
+
+        Ok(vec)
+    }
+}
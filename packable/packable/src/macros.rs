@@ -0,0 +1,48 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Implements [`Packable`](crate::Packable) for a tuple struct with a single field by delegating to the implementation
+/// of the type of that field.
+///
+/// The packed representation of the wrapper is identical to the one of the wrapped value and both the
+/// [`UnpackError`](crate::Packable::UnpackError) and [`UnpackVisitor`](crate::Packable::UnpackVisitor) associated
+/// types are the ones of the wrapped type.
+///
+/// # Example
+///
+/// ```rust
+/// use packable::{delegate_packable, PackableExt};
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct BlockId(u64);
+///
+/// delegate_packable!(BlockId => u64);
+///
+/// let id = BlockId(42);
+/// let bytes = id.pack_to_vec();
+///
+/// assert_eq!(bytes, 42u64.pack_to_vec());
+/// assert_eq!(BlockId::unpack_verified(bytes, &()).unwrap(), id);
+/// ```
+#[macro_export]
+macro_rules! delegate_packable {
+    ($ty:ty => $inner:ty) => {
+        impl $crate::Packable for $ty {
+            type UnpackError = <$inner as $crate::Packable>::UnpackError;
+            type UnpackVisitor = <$inner as $crate::Packable>::UnpackVisitor;
+
+            #[inline]
+            fn pack<P: $crate::packer::Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                <$inner as $crate::Packable>::pack(&self.0, packer)
+            }
+
+            #[inline]
+            fn unpack<U: $crate::unpacker::Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, $crate::error::UnpackError<Self::UnpackError, U::Error>> {
+                <$inner as $crate::Packable>::unpack::<_, VERIFY>(unpacker, visitor).map(Self)
+            }
+        }
+    };
+}
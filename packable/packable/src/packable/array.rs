@@ -3,10 +3,15 @@
 
 use core::{any::TypeId, mem::MaybeUninit};
 
-use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+use crate::{
+    error::{ArrayUnpackError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable, PackedSize,
+};
 
 impl<T: Packable, const N: usize> Packable for [T; N] {
-    type UnpackError = T::UnpackError;
+    type UnpackError = ArrayUnpackError<T::UnpackError>;
     type UnpackVisitor = T::UnpackVisitor;
 
     #[inline]
@@ -39,8 +44,9 @@ impl<T: Packable, const N: usize> Packable for [T; N] {
             // FIXME: replace with [`MaybeUninit::uninit_array`] when stabilized.
             let mut array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
 
-            for item in array.iter_mut() {
-                let unpacked = T::unpack::<_, VERIFY>(unpacker, visitor)?;
+            for (index, item) in array.iter_mut().enumerate() {
+                let unpacked = T::unpack::<_, VERIFY>(unpacker, visitor)
+                    .map_packable_err(|inner| ArrayUnpackError { index, inner })?;
 
                 // Safety: each `item` is only visited once so we are never overwriting nor dropping values that are
                 // already initialized.
@@ -55,3 +61,7 @@ impl<T: Packable, const N: usize> Packable for [T; N] {
         }
     }
 }
+
+impl<T: PackedSize, const N: usize> PackedSize for [T; N] {
+    const PACKED_SIZE: usize = N * T::PACKED_SIZE;
+}
@@ -7,7 +7,7 @@ use crate::{
     error::{UnpackError, UnpackErrorExt},
     packer::Packer,
     unpacker::Unpacker,
-    Packable,
+    Packable, PackedSize,
 };
 
 impl Packable for bool {
@@ -29,3 +29,7 @@ impl Packable for bool {
         Ok(u8::unpack::<_, VERIFY>(unpacker, visitor).coerce()? != 0)
     }
 }
+
+impl PackedSize for bool {
+    const PACKED_SIZE: usize = 1;
+}
@@ -0,0 +1,115 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities used to pack and unpack sequences of booleans as bits.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{convert::Infallible, fmt, ops::Deref};
+
+use crate::{
+    Packable,
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+};
+
+/// Error type raised when a semantic error occurs while unpacking a [`PackedBools`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnpackPackedBoolsError {
+    /// The number of booleans does not fit in a `usize`.
+    InvalidLength(u64),
+    /// The unused bits of the last byte are not zero.
+    NonZeroPadding,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnpackPackedBoolsError {}
+
+impl From<Infallible> for UnpackPackedBoolsError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for UnpackPackedBoolsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "invalid number of packed booleans {}", len),
+            Self::NonZeroPadding => write!(f, "the padding bits of the packed booleans are not zero"),
+        }
+    }
+}
+
+/// Wrapper type for a sequence of booleans that packs 8 booleans per byte.
+///
+/// The number of booleans is packed first as a [`u64`], followed by the booleans themselves, the first boolean being
+/// the least significant bit of the first byte. The unused bits of the last byte are zero.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct PackedBools(pub Vec<bool>);
+
+impl PackedBools {
+    /// Consumes the wrapper and returns the inner booleans.
+    #[inline(always)]
+    pub fn into_inner(self) -> Vec<bool> {
+        self.0
+    }
+}
+
+impl From<Vec<bool>> for PackedBools {
+    fn from(value: Vec<bool>) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for PackedBools {
+    type Target = [bool];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Packable for PackedBools {
+    type UnpackError = UnpackPackedBoolsError;
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (self.0.len() as u64).pack(packer)?;
+
+        for chunk in self.0.chunks(8) {
+            let byte = chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (index, &bit)| byte | ((bit as u8) << index));
+            byte.pack(packer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let len = u64::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let len =
+            usize::try_from(len).map_err(|_| UnpackError::Packable(UnpackPackedBoolsError::InvalidLength(len)))?;
+
+        let bytes = unpacker.unpack_slice(len.div_ceil(8))?;
+
+        if VERIFY && len % 8 != 0 && bytes.last().is_some_and(|byte| byte >> (len % 8) != 0) {
+            return Err(UnpackError::Packable(UnpackPackedBoolsError::NonZeroPadding));
+        }
+
+        Ok(Self(
+            (0..len)
+                .map(|index| bytes[index / 8] & (1 << (index % 8)) != 0)
+                .collect(),
+        ))
+    }
+}
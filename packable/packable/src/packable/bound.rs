@@ -0,0 +1,83 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities related to packing and unpacking [`Bound`] values.
+
+use core::{convert::Infallible, fmt, ops::Bound};
+
+use crate::{
+    Packable,
+    error::{UnknownTagError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+};
+
+/// Error type raised when a semantic error occurs while unpacking a bound.
+#[derive(Debug)]
+pub enum UnpackBoundError<E> {
+    /// The tag found while unpacking is not valid.
+    UnknownTag(UnknownTagError<u8>),
+    /// A semantic error for the underlying type was raised.
+    Inner(E),
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for UnpackBoundError<E> where E: std::error::Error {}
+
+impl<E> From<Infallible> for UnpackBoundError<E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<E> From<UnknownTagError<u8>> for UnpackBoundError<E> {
+    fn from(err: UnknownTagError<u8>) -> Self {
+        Self::UnknownTag(err)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for UnpackBoundError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownTag(err) => write!(f, "{} for bound", err),
+            Self::Inner(err) => write!(f, "cannot unpack bound value: {}", err),
+        }
+    }
+}
+
+/// Bounds are packed and unpacked using `0u8` as the prefix for `Unbounded`, `1u8` as the prefix for `Included` and
+/// `2u8` as the prefix for `Excluded`.
+impl<T: Packable> Packable for Bound<T> {
+    type UnpackError = UnpackBoundError<T::UnpackError>;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        match self {
+            Self::Unbounded => 0u8.pack(packer),
+            Self::Included(item) => {
+                1u8.pack(packer)?;
+                item.pack(packer)
+            }
+            Self::Excluded(item) => {
+                2u8.pack(packer)?;
+                item.pack(packer)
+            }
+        }
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack::<_, VERIFY>(unpacker, &()).coerce()? {
+            0 => Ok(Self::Unbounded),
+            1 => Ok(Self::Included(
+                T::unpack::<_, VERIFY>(unpacker, visitor).map_packable_err(UnpackBoundError::Inner)?,
+            )),
+            2 => Ok(Self::Excluded(
+                T::unpack::<_, VERIFY>(unpacker, visitor).map_packable_err(UnpackBoundError::Inner)?,
+            )),
+            n => Err(UnpackError::from_packable(UnknownTagError(n))),
+        }
+    }
+}
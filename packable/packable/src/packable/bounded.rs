@@ -23,6 +23,7 @@ macro_rules! bounded {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Packable)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #[packable(unpack_error = $invalid_error<MIN, MAX>)]
+        #[packable(packed_size)]
         pub struct $wrapper<const MIN: $ty, const MAX: $ty>(
             #[packable(verify_with = Self::verify)]
             $ty
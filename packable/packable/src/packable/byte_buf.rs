@@ -0,0 +1,78 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{convert::Infallible, num::TryFromIntError};
+
+use crate::{error::UnpackError, packer::Packer, prefix::UnpackPrefixError, unpacker::Unpacker, Packable};
+
+/// Wrapper type for a [`Vec<u8>`] holding a binary payload.
+///
+/// A `ByteBuf` is packed exactly like a [`Vec<u8>`]: its length as a [`u64`] followed by its bytes. The bytes are
+/// always packed and unpacked with a single call to [`Packer::pack_bytes`] and [`Unpacker::unpack_bytes`], and the
+/// length is checked against the remaining bytes of the unpacker before allocating. Unlike [`Vec<u8>`], this type does
+/// not require the `usize` feature.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct ByteBuf(pub Vec<u8>);
+
+impl ByteBuf {
+    /// Consumes the wrapper and returns the inner bytes.
+    #[inline(always)]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for ByteBuf {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ByteBuf> for Vec<u8> {
+    fn from(value: ByteBuf) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<[u8]> for ByteBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for ByteBuf {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for ByteBuf {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Packable for ByteBuf {
+    type UnpackError = UnpackPrefixError<Infallible, TryFromIntError>;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_prefixed_bytes(&self.0)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        (): &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        unpacker.unpack_prefixed_bytes::<VERIFY>().map(Self)
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cell::{Cell, RefCell};
+
+use crate::{Packable, PackedSize, error::UnpackError, packer::Packer, unpacker::Unpacker};
+
+impl<T: Packable + Copy> Packable for Cell<T> {
+    type UnpackError = T::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.get().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Cell::new(T::unpack::<_, VERIFY>(unpacker, visitor)?))
+    }
+}
+
+impl<T: PackedSize + Copy> PackedSize for Cell<T> {
+    const PACKED_SIZE: usize = T::PACKED_SIZE;
+}
+
+/// Packing a [`RefCell`] borrows its value, so it panics if the value is currently mutably borrowed.
+impl<T: Packable> Packable for RefCell<T> {
+    type UnpackError = T::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.borrow().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(RefCell::new(T::unpack::<_, VERIFY>(unpacker, visitor)?))
+    }
+}
+
+impl<T: PackedSize> PackedSize for RefCell<T> {
+    const PACKED_SIZE: usize = T::PACKED_SIZE;
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities used to pack and unpack sequences whose elements are followed by a checksum.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{convert::Infallible, fmt, num::TryFromIntError, ops::Deref};
+
+use crate::{
+    Packable,
+    endian::ByteOrder,
+    error::{ArrayUnpackError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    prefix::UnpackPrefixError,
+    unpacker::Unpacker,
+};
+
+/// The remainders of every byte for the reflected CRC-32 polynomial.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+
+    while index < table.len() {
+        let mut remainder = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            remainder = if remainder & 1 != 0 {
+                (remainder >> 1) ^ 0xEDB8_8320
+            } else {
+                remainder >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = remainder;
+        index += 1;
+    }
+
+    table
+};
+
+/// The initial state of a CRC-32 computation.
+const CRC32_INIT: u32 = 0xFFFF_FFFF;
+
+/// Updates the state of a CRC-32 computation with the given bytes.
+fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(crc, |crc, &byte| {
+        CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Computes the CRC-32 checksum of a sequence of bytes, as defined by ISO-HDLC and used by Ethernet, zlib or PNG.
+///
+/// ```
+/// use packable::checksum::crc32;
+///
+/// assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+/// ```
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !crc32_update(CRC32_INIT, bytes)
+}
+
+/// Error type raised when an element of a [`ChecksummedVec`] cannot be unpacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumError<E> {
+    /// Semantic error raised while unpacking the element. Typically this is
+    /// [`Packable::UnpackError`](crate::Packable::UnpackError).
+    Item(E),
+    /// The checksum following the element does not match the checksum of its packed bytes.
+    Mismatch {
+        /// The checksum following the element.
+        expected: u32,
+        /// The checksum of the packed bytes of the element.
+        computed: u32,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ChecksumError<E> where E: fmt::Display + fmt::Debug {}
+
+impl<E> From<Infallible> for ChecksumError<E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ChecksumError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Item(err) => err.fmt(f),
+            Self::Mismatch { expected, computed } => write!(
+                f,
+                "checksum mismatch, expected {:#010x} but computed {:#010x}",
+                expected, computed
+            ),
+        }
+    }
+}
+
+/// Wrapper type for a [`Vec<T>`] whose elements are each followed by the CRC-32 checksum of their packed bytes.
+///
+/// The number of elements is packed first as a [`u64`], followed by every element and its checksum as a [`u32`]. When
+/// verifying, unpacking fails at the first element whose checksum does not match, with the index of this element in
+/// the [`ArrayUnpackError`], so that the corruption of a large sequence can be located.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct ChecksummedVec<T>(pub Vec<T>);
+
+impl<T> ChecksummedVec<T> {
+    /// Consumes the wrapper and returns the inner elements.
+    #[inline(always)]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for ChecksummedVec<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for ChecksummedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Packable> Packable for ChecksummedVec<T> {
+    type UnpackError = UnpackPrefixError<ArrayUnpackError<ChecksumError<T::UnpackError>>, TryFromIntError>;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (self.0.len() as u64).pack(packer)?;
+
+        for item in self.0.iter() {
+            let mut crc_packer = Crc32Packer {
+                inner: &mut *packer,
+                crc: CRC32_INIT,
+            };
+            item.pack(&mut crc_packer)?;
+            (!crc_packer.crc).pack(packer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let len = u64::unpack::<_, VERIFY>(unpacker, &())
+            .coerce()?
+            .try_into()
+            .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
+
+        let mut vec = Vec::new();
+
+        for index in 0..len {
+            let item_err = |inner| UnpackPrefixError::Item(ArrayUnpackError { index, inner });
+
+            let mut crc_unpacker = Crc32Unpacker {
+                inner: &mut *unpacker,
+                crc: CRC32_INIT,
+            };
+            let item = T::unpack::<_, VERIFY>(&mut crc_unpacker, visitor)
+                .map_packable_err(|err| item_err(ChecksumError::Item(err)))?;
+            let computed = !crc_unpacker.crc;
+
+            let expected = u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+
+            if VERIFY && expected != computed {
+                return Err(UnpackError::Packable(item_err(ChecksumError::Mismatch {
+                    expected,
+                    computed,
+                })));
+            }
+
+            vec.push(item);
+        }
+
+        Ok(Self(vec))
+    }
+}
+
+/// A [`Packer`] computing the CRC-32 checksum of the bytes written into its inner [`Packer`].
+struct Crc32Packer<'a, P: Packer> {
+    inner: &'a mut P,
+    crc: u32,
+}
+
+impl<P: Packer> Packer for Crc32Packer<'_, P> {
+    type Error = P::Error;
+
+    const BYTE_ORDER: ByteOrder = P::BYTE_ORDER;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_ref();
+
+        self.inner.pack_bytes(bytes)?;
+        self.crc = crc32_update(self.crc, bytes);
+
+        Ok(())
+    }
+}
+
+/// An [`Unpacker`] computing the CRC-32 checksum of the bytes read from its inner [`Unpacker`].
+///
+/// Skipped bytes are read through [`Unpacker::unpack_bytes`] so that they are part of the checksum.
+struct Crc32Unpacker<'a, U: Unpacker> {
+    inner: &'a mut U,
+    crc: u32,
+}
+
+impl<U: Unpacker> Unpacker for Crc32Unpacker<'_, U> {
+    type Error = U::Error;
+
+    const BYTE_ORDER: ByteOrder = U::BYTE_ORDER;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+
+        self.inner.unpack_bytes(&mut *bytes)?;
+        self.crc = crc32_update(self.crc, bytes);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        self.inner.peek_u8()
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        self.inner.ensure_bytes(len)
+    }
+
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        self.inner.remaining_bytes()
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cmp::{Ordering, Reverse};
+
+use crate::{
+    Packable, PackedSize,
+    error::{UnknownTagError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+};
+
+impl<T: Packable> Packable for Reverse<T> {
+    type UnpackError = T::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.0.pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        T::unpack::<_, VERIFY>(unpacker, visitor).map(Reverse)
+    }
+}
+
+impl<T: PackedSize> PackedSize for Reverse<T> {
+    const PACKED_SIZE: usize = T::PACKED_SIZE;
+}
+
+/// Orderings are packed and unpacked as a single byte: `0u8` for `Less`, `1u8` for `Equal` and `2u8` for `Greater`.
+impl Packable for Ordering {
+    type UnpackError = UnknownTagError<u8>;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        let tag: u8 = match self {
+            Self::Less => 0,
+            Self::Equal => 1,
+            Self::Greater => 2,
+        };
+
+        tag.pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack::<_, VERIFY>(unpacker, visitor).coerce()? {
+            0 => Ok(Self::Less),
+            1 => Ok(Self::Equal),
+            2 => Ok(Self::Greater),
+            n => Err(UnpackError::Packable(UnknownTagError(n))),
+        }
+    }
+}
+
+impl PackedSize for Ordering {
+    const PACKED_SIZE: usize = u8::PACKED_SIZE;
+}
@@ -0,0 +1,43 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+use std::{ffi::CString, vec::Vec};
+
+use crate::{
+    Packable,
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+};
+
+/// C strings are packed as their bytes followed by their trailing NUL byte, without length prefix.
+///
+/// Unpacking reads bytes until the first NUL byte and fails with the error of the unpacker if the bytes end before
+/// it. As the first NUL byte terminates the string, unpacked strings cannot contain interior NUL bytes.
+impl Packable for CString {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes(self.as_bytes_with_nul())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let mut bytes = Vec::new();
+
+        loop {
+            match u8::unpack::<_, VERIFY>(unpacker, visitor).coerce()? {
+                0 => break,
+                byte => bytes.push(byte),
+            }
+        }
+
+        // Safety: the bytes were read up to the first NUL byte, which was not pushed.
+        Ok(unsafe { CString::from_vec_unchecked(bytes) })
+    }
+}
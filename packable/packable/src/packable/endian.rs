@@ -0,0 +1,136 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities used to pack and unpack integers with an explicit byte order.
+
+use core::convert::Infallible;
+
+use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable, PackedSize};
+
+/// The order in which the bytes of a numeric value are packed.
+///
+/// Packers and unpackers report the byte order they expect with [`Packer::BYTE_ORDER`] and
+/// [`Unpacker::BYTE_ORDER`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// The least significant byte is packed first.
+    Little,
+    /// The most significant byte is packed first.
+    Big,
+}
+
+/// Wrapper type for a numeric value that is always packed in little-endian order.
+///
+/// This is the same byte order used by the [`Packable`] implementations of the numeric types, the wrapper is provided
+/// to make the byte order explicit at the type level.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Le<T>(pub T);
+
+/// Wrapper type for a numeric value that is always packed in big-endian order.
+///
+/// This is useful for fields that follow network byte order, like ports, while the rest of the fields keep using
+/// little-endian order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Be<T>(pub T);
+
+macro_rules! impl_endian_wrapper {
+    ($wrapper:ident) => {
+        impl<T> $wrapper<T> {
+            /// Consumes the wrapper and returns the inner value.
+            #[inline(always)]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> From<T> for $wrapper<T> {
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T> core::ops::Deref for $wrapper<T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+}
+
+impl_endian_wrapper!(Le);
+impl_endian_wrapper!(Be);
+
+macro_rules! impl_packable_for_endian {
+    ($ty:ty) => {
+        impl Packable for Le<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                packer.pack_bytes(&self.0.to_le_bytes())
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                (): &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let mut bytes = [0u8; core::mem::size_of::<$ty>()];
+                unpacker.unpack_bytes(&mut bytes)?;
+                Ok(Self(<$ty>::from_le_bytes(bytes)))
+            }
+        }
+
+        impl Packable for Be<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                packer.pack_bytes(&self.0.to_be_bytes())
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                (): &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let mut bytes = [0u8; core::mem::size_of::<$ty>()];
+                unpacker.unpack_bytes(&mut bytes)?;
+                Ok(Self(<$ty>::from_be_bytes(bytes)))
+            }
+        }
+
+        impl PackedSize for Le<$ty> {
+            const PACKED_SIZE: usize = core::mem::size_of::<$ty>();
+        }
+
+        impl PackedSize for Be<$ty> {
+            const PACKED_SIZE: usize = core::mem::size_of::<$ty>();
+        }
+    };
+}
+
+impl_packable_for_endian!(u8);
+impl_packable_for_endian!(u16);
+impl_packable_for_endian!(u32);
+impl_packable_for_endian!(u64);
+#[cfg(has_u128)]
+impl_packable_for_endian!(u128);
+
+impl_packable_for_endian!(i8);
+impl_packable_for_endian!(i16);
+impl_packable_for_endian!(i32);
+impl_packable_for_endian!(i64);
+#[cfg(has_i128)]
+impl_packable_for_endian!(i128);
+
+impl_packable_for_endian!(f32);
+impl_packable_for_endian!(f64);
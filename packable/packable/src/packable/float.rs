@@ -0,0 +1,181 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities used to pack and unpack floating point values deterministically, or
+//! restricted to finite values.
+
+use core::{convert::Infallible, fmt};
+
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable, PackedSize,
+};
+
+/// Wrapper type for a floating point value that packs every NaN as the same canonical NaN.
+///
+/// The [`Packable`] implementations of [`f32`] and [`f64`] keep the exact bits of the value, including the payload of
+/// NaNs, so two NaN values might be packed differently. This wrapper packs any NaN as [`f32::NAN`] or [`f64::NAN`]
+/// respectively, which is useful when the packed bytes must be deterministic. Values are unpacked as they are.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct CanonicalNan<T>(pub T);
+
+impl<T> CanonicalNan<T> {
+    /// Consumes the wrapper and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for CanonicalNan<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> core::ops::Deref for CanonicalNan<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_packable_for_canonical_nan {
+    ($ty:ident) => {
+        impl Packable for CanonicalNan<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                let value = if self.0.is_nan() { $ty::NAN } else { self.0 };
+                value.pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                $ty::unpack::<_, VERIFY>(unpacker, visitor).map(Self)
+            }
+        }
+
+        impl PackedSize for CanonicalNan<$ty> {
+            const PACKED_SIZE: usize = $ty::PACKED_SIZE;
+        }
+    };
+}
+
+impl_packable_for_canonical_nan!(f32);
+impl_packable_for_canonical_nan!(f64);
+
+/// Error type raised when a floating point value is NaN or infinite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFiniteError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonFiniteError {}
+
+impl From<Infallible> for NonFiniteError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the floating point value is not finite")
+    }
+}
+
+/// Wrapper type for a floating point value that is neither NaN nor infinite.
+///
+/// This is useful for formats that forbid non-finite values. The wrapper can only be created from a finite value, so
+/// it is always packed as one, and unpacking fails with a [`NonFiniteError`] if the unpacked value is not finite when
+/// verifying. Values unpacked without verification are not checked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Finite<T>(T);
+
+impl<T> Finite<T> {
+    /// Consumes the wrapper and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> core::ops::Deref for Finite<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_packable_for_finite {
+    ($ty:ident) => {
+        impl Finite<$ty> {
+            /// Creates a new [`Finite`] if the value is neither NaN nor infinite.
+            #[inline]
+            pub fn new(value: $ty) -> Result<Self, NonFiniteError> {
+                if value.is_finite() {
+                    Ok(Self(value))
+                } else {
+                    Err(NonFiniteError)
+                }
+            }
+        }
+
+        impl TryFrom<$ty> for Finite<$ty> {
+            type Error = NonFiniteError;
+
+            fn try_from(value: $ty) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<Finite<$ty>> for $ty {
+            fn from(value: Finite<$ty>) -> Self {
+                value.0
+            }
+        }
+
+        impl Packable for Finite<$ty> {
+            type UnpackError = NonFiniteError;
+            type UnpackVisitor = ();
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.0.pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let value = $ty::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+
+                if VERIFY && !value.is_finite() {
+                    return Err(UnpackError::Packable(NonFiniteError));
+                }
+
+                Ok(Self(value))
+            }
+        }
+
+        impl PackedSize for Finite<$ty> {
+            const PACKED_SIZE: usize = $ty::PACKED_SIZE;
+        }
+    };
+}
+
+impl_packable_for_finite!(f32);
+impl_packable_for_finite!(f64);
@@ -0,0 +1,300 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and implementations for packing and unpacking map values.
+
+use core::{convert::Infallible, fmt};
+
+/// Error type raised when a semantic error occurs while unpacking a map.
+pub enum UnpackMapError<K, KE, VE, P> {
+    /// A duplicate map key.
+    DuplicateKey(K),
+    /// Semantic error raised while unpacking a key of the map. Typically this is
+    /// [`Packable::UnpackError`](crate::Packable::UnpackError).
+    Key(KE),
+    /// Semantic error raised while unpacking a value of the map. Typically this is
+    /// [`Packable::UnpackError`](crate::Packable::UnpackError).
+    Value(VE),
+    /// Semantic error raised when the length prefix cannot be unpacked.
+    Prefix(P),
+}
+
+impl<K, KE: fmt::Debug, VE: fmt::Debug, P: fmt::Debug> fmt::Debug for UnpackMapError<K, KE, VE, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateKey(_) => f.debug_tuple("DuplicateKey").finish(),
+            Self::Key(arg0) => f.debug_tuple("Key").field(arg0).finish(),
+            Self::Value(arg0) => f.debug_tuple("Value").field(arg0).finish(),
+            Self::Prefix(arg0) => f.debug_tuple("Prefix").field(arg0).finish(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, KE, VE, P> std::error::Error for UnpackMapError<K, KE, VE, P>
+where
+    KE: std::error::Error,
+    VE: std::error::Error,
+    P: std::error::Error,
+{
+}
+
+impl<K, KE, VE, P> From<Infallible> for UnpackMapError<K, KE, VE, P> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<K, KE: fmt::Display, VE: fmt::Display, P: fmt::Display> fmt::Display for UnpackMapError<K, KE, VE, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateKey(_) => write!(f, "duplicate key in map"),
+            Self::Key(err) => write!(f, "cannot unpack key: {}", err),
+            Self::Value(err) => write!(f, "cannot unpack value: {}", err),
+            Self::Prefix(err) => write!(f, "cannot unpack prefix: {}", err),
+        }
+    }
+}
+
+/// Error type raised when a semantic error occurs while unpacking an ordered map.
+pub enum UnpackOrderedMapError<K, KE, VE, P> {
+    /// A map error.
+    Map(UnpackMapError<K, KE, VE, P>),
+    /// An unordered map.
+    Unordered,
+}
+
+impl<K, KE, VE, P> From<UnpackMapError<K, KE, VE, P>> for UnpackOrderedMapError<K, KE, VE, P> {
+    fn from(value: UnpackMapError<K, KE, VE, P>) -> Self {
+        Self::Map(value)
+    }
+}
+
+impl<K, KE: fmt::Debug, VE: fmt::Debug, P: fmt::Debug> fmt::Debug for UnpackOrderedMapError<K, KE, VE, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Map(arg0) => f.debug_tuple("Map").field(arg0).finish(),
+            Self::Unordered => f.debug_tuple("Unordered").finish(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, KE, VE, P> std::error::Error for UnpackOrderedMapError<K, KE, VE, P>
+where
+    KE: std::error::Error,
+    VE: std::error::Error,
+    P: std::error::Error,
+{
+}
+
+impl<K, KE, VE, P> From<Infallible> for UnpackOrderedMapError<K, KE, VE, P> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<K, KE: fmt::Display, VE: fmt::Display, P: fmt::Display> fmt::Display for UnpackOrderedMapError<K, KE, VE, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Map(m) => m.fmt(f),
+            Self::Unordered => write!(f, "unordered map"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use hashmap::DeterministicHashMap;
+
+#[cfg(feature = "std")]
+mod hashmap {
+    use std::{
+        collections::{HashMap, hash_map::RandomState},
+        hash::{BuildHasher, Hash},
+    };
+
+    /// Wrapper type for a [`HashMap`] that packs its entries in a deterministic order.
+    ///
+    /// The [`Packable`](crate::Packable) implementation of [`HashMap`] packs its entries in iteration order, which is
+    /// arbitrary, so two equal maps might be packed differently. This wrapper sorts the entries by the packed bytes of
+    /// their keys before packing them, which is useful when the packed bytes must be deterministic, e.g. to hash them,
+    /// at the cost of packing every key twice. Unpacking rejects entries that are not sorted this way.
+    #[derive(Debug, Default, Clone)]
+    #[repr(transparent)]
+    pub struct DeterministicHashMap<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+    impl<K, V, S> DeterministicHashMap<K, V, S> {
+        /// Consumes the wrapper and returns the inner map.
+        #[inline(always)]
+        pub fn into_inner(self) -> HashMap<K, V, S> {
+            self.0
+        }
+    }
+
+    impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq for DeterministicHashMap<K, V, S> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl<K: Eq + Hash, V: Eq, S: BuildHasher> Eq for DeterministicHashMap<K, V, S> {}
+
+    impl<K, V, S> From<HashMap<K, V, S>> for DeterministicHashMap<K, V, S> {
+        fn from(value: HashMap<K, V, S>) -> Self {
+            Self(value)
+        }
+    }
+
+    impl<K, V, S> core::ops::Deref for DeterministicHashMap<K, V, S> {
+        type Target = HashMap<K, V, S>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<K, V, S> core::ops::DerefMut for DeterministicHashMap<K, V, S> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    #[cfg(feature = "usize")]
+    mod packable {
+        use core::{borrow::Borrow, convert::Infallible};
+
+        use super::*;
+        use crate::{
+            Packable, PackableExt,
+            error::{UnpackError, UnpackErrorExt},
+            map::{UnpackMapError, UnpackOrderedMapError},
+            packer::Packer,
+            unpacker::Unpacker,
+        };
+
+        /// Unpacks the entries of a map, calling `check_key` on every key before inserting it.
+        fn unpack_entries<K, V, S, E, U, const VERIFY: bool>(
+            unpacker: &mut U,
+            visitor: &K::UnpackVisitor,
+            mut check_key: impl FnMut(&K) -> Result<(), E>,
+        ) -> Result<HashMap<K, V, S>, UnpackError<E, U::Error>>
+        where
+            K: Packable + Eq + Hash,
+            V: Packable,
+            K::UnpackVisitor: Borrow<V::UnpackVisitor>,
+            S: BuildHasher + Default,
+            E: From<Infallible>
+                + From<UnpackMapError<K, K::UnpackError, V::UnpackError, <usize as Packable>::UnpackError>>,
+            U: Unpacker,
+        {
+            let len: usize = u64::unpack::<_, VERIFY>(unpacker, &())
+                .coerce()?
+                .try_into()
+                .map_err(|err| UnpackError::Packable(UnpackMapError::Prefix(err).into()))?;
+
+            let mut map = HashMap::with_hasher(S::default());
+
+            for _ in 0..len {
+                let key = K::unpack::<_, VERIFY>(unpacker, visitor)
+                    .map_packable_err(UnpackMapError::Key)
+                    .map_packable_err(E::from)?;
+                check_key(&key).map_err(UnpackError::Packable)?;
+                let value = V::unpack::<_, VERIFY>(unpacker, visitor.borrow())
+                    .map_packable_err(UnpackMapError::Value)
+                    .map_packable_err(E::from)?;
+
+                if map.contains_key(&key) {
+                    return Err(UnpackError::Packable(UnpackMapError::DuplicateKey(key).into()));
+                }
+                map.insert(key, value);
+            }
+
+            Ok(map)
+        }
+
+        impl<K, V, S> Packable for HashMap<K, V, S>
+        where
+            K: Packable + Eq + Hash,
+            V: Packable,
+            K::UnpackVisitor: Borrow<V::UnpackVisitor>,
+            S: BuildHasher + Default + 'static,
+        {
+            type UnpackError = UnpackMapError<K, K::UnpackError, V::UnpackError, <usize as Packable>::UnpackError>;
+            type UnpackVisitor = K::UnpackVisitor;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                // This cast is fine because we know `usize` is not larger than `64` bits.
+                (self.len() as u64).pack(packer)?;
+
+                for (key, value) in self.iter() {
+                    key.pack(packer)?;
+                    value.pack(packer)?;
+                }
+
+                Ok(())
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                unpack_entries::<_, _, _, _, _, VERIFY>(unpacker, visitor, |_| Ok(()))
+            }
+        }
+
+        impl<K, V, S> Packable for DeterministicHashMap<K, V, S>
+        where
+            K: Packable + Eq + Hash,
+            V: Packable,
+            K::UnpackVisitor: Borrow<V::UnpackVisitor>,
+            S: BuildHasher + Default + 'static,
+        {
+            type UnpackError =
+                UnpackOrderedMapError<K, K::UnpackError, V::UnpackError, <usize as Packable>::UnpackError>;
+            type UnpackVisitor = K::UnpackVisitor;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                let mut entries = self
+                    .0
+                    .iter()
+                    .map(|(key, value)| (key.pack_to_vec(), value))
+                    .collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                // This cast is fine because we know `usize` is not larger than `64` bits.
+                (entries.len() as u64).pack(packer)?;
+
+                for (key, value) in entries {
+                    packer.pack_bytes(key)?;
+                    value.pack(packer)?;
+                }
+
+                Ok(())
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let mut last = None::<Vec<u8>>;
+
+                unpack_entries::<_, _, _, _, _, VERIFY>(unpacker, visitor, |key: &K| {
+                    let bytes = key.pack_to_vec();
+
+                    // Equal keys are rejected as duplicates when they are inserted.
+                    if last.as_ref().is_some_and(|last| *last > bytes) {
+                        return Err(Self::UnpackError::Unordered);
+                    }
+                    last = Some(bytes);
+
+                    Ok(())
+                })
+                .map(Self)
+            }
+        }
+    }
+}
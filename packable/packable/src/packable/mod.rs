@@ -3,25 +3,47 @@
 
 //! A module that provides a [`Packable`] trait to serialize and deserialize types.
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+pub mod bools;
+#[cfg(feature = "alloc")]
+pub mod checksum;
+pub mod bound;
 pub mod bounded;
+pub mod endian;
+pub mod float;
+pub mod map;
 pub mod option;
 pub mod prefix;
 pub mod set;
+pub mod zigzag;
 
 mod array;
 mod bool;
+#[cfg(feature = "alloc")]
 mod r#box;
+#[cfg(feature = "alloc")]
+mod byte_buf;
+mod cell;
+mod cmp;
+#[cfg(feature = "std")]
+mod cstring;
+mod net;
 mod num;
+mod packable_ref;
 #[cfg(feature = "primitive-types")]
 mod primitive_types;
-#[cfg(feature = "usize")]
+#[cfg(all(feature = "usize", feature = "alloc"))]
 mod string;
 mod tuple;
-#[cfg(feature = "usize")]
+#[cfg(all(feature = "usize", feature = "alloc"))]
 mod vec;
+#[cfg(all(feature = "usize", feature = "alloc"))]
+mod vec_deque;
 
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::{
     borrow::Borrow,
@@ -30,11 +52,14 @@ use core::{
 };
 
 pub use packable_derive::Packable;
+pub use self::packable_ref::PackableRef;
+#[cfg(feature = "alloc")]
+pub use self::byte_buf::ByteBuf;
 
 use crate::{
-    error::{UnexpectedEOF, UnpackError},
+    error::{ExactUnpackError, LimitedUnpackerError, TrailingBytesError, UnexpectedEOF, UnpackError},
     packer::{LenPacker, Packer},
-    unpacker::{SliceUnpacker, Unpacker},
+    unpacker::{LimitedUnpacker, SliceUnpacker, Unpacker},
 };
 
 /// A type that can be packed and unpacked.
@@ -124,14 +149,28 @@ use crate::{
 /// for each variant is specified with the `#[packable(tag = ...)]` attribute and can only contain
 /// integer literal without any type prefixes (e.g. `42` is valid but `42u8` is not).
 ///
+/// Tags can also be written as string literals holding an integer, which is convenient to mirror
+/// tags documented in hexadecimal: `#[packable(tag = "0x2A")]`. The `0x`, `0o` and `0b` prefixes
+/// are supported and underscores are ignored, so `"0b0010_1010"` resolves to the same tag.
+///
+/// Enums with exactly two variants can also use `#[packable(tag_type = bool)]`, with the `true` and `false` tags,
+/// which packs the tag like a [`bool`](prim@bool). As both values are used, unpacking never fails because of an
+/// unknown tag. Compilation fails if the enum does not have exactly two variants.
+///
 /// In the example above, the `tag` type is `[u8]`, the `Nothing` variant has a `tag` value of `0`
 /// and the `Just` variant has a `tag` value of `1`. This means that the packed version of
 /// `Maybe::Nothing` is `[0]` and the packed version of `Maybe::Just(7)` is `[1, 0, 0, 0, 7]`.
 ///
-/// The `tag_type` and `tag` attributes are mandatory for enums unless the enum has a
-/// `#[repr(...)]` attribute identifier, in which case the `repr` type will be used as the
-/// `tag_type` and each variant discriminant will be used as the `tag`. The `tag_type` and `tag`
-/// attributes take precedence over the `repr` attribute.
+/// The `tag_type` attribute is mandatory for enums unless the enum has a `#[repr(...)]` attribute
+/// identifier, in which case the `repr` type will be used as the `tag_type` and each variant
+/// discriminant will be used as the `tag`. The `tag_type` and `tag` attributes take precedence
+/// over the `repr` attribute.
+///
+/// Variants without a `tag` attribute nor an explicit discriminant are tagged with their position
+/// in declaration order, starting at `0` (or `false` and `true` for a `bool` tag type), regardless of
+/// the tags of the other variants. Compilation fails if such a tag is equal to an explicit one. As
+/// implicit discriminants follow the previous discriminant instead, enums with explicit
+/// discriminants still require a `tag` or a discriminant for every variant.
 ///
 /// ## The `UnpackError` associated type
 ///
@@ -139,9 +178,22 @@ use crate::{
 /// specify the `UnpackError` associated type. The macro also provides sensible defaults for cases
 /// when the attribute is not used.
 ///
-/// For structs, the default [`UnpackError`](Packable::UnpackError) type is the
-/// [`UnpackError`](Packable::UnpackError) of any of the fields type or
-/// [`Infallible`] in case the struct has no fields.
+/// For structs, the default [`UnpackError`](Packable::UnpackError) type is decided by the fields
+/// that are unpacked as their type, i.e. that are not skipped and do not have an `unpack_with`
+/// function:
+/// - If the fields whose type is not infallible to unpack all have the same type, the default is
+///   the [`UnpackError`](Packable::UnpackError) of this type. A type is infallible to unpack if it
+///   is written as `bool`, `f32`, `f64`, an integer type other than `usize` and `isize`, or an array
+///   of such types. Types are compared as they are written, so `Foo` and `crate::Foo` are distinct.
+/// - Otherwise, the default is the [`UnpackError`](Packable::UnpackError) of the first field, or
+///   [`Infallible`] in case the struct has no such fields.
+///
+/// This means that structs whose fields are all infallible to unpack, like integers, or that have
+/// a single fallible field, do not need the `unpack_error` attribute. The errors of the remaining
+/// fields are converted into the default type using [`Into`], so an explicit `unpack_error` is
+/// still required when the fields have unrelated error types. Arrays have an
+/// [`ArrayUnpackError`](crate::error::ArrayUnpackError) that only converts into [`Infallible`], so
+/// they also require it when they are mixed with a fallible field.
 ///
 /// For enums, the default  [`UnpackError`](Packable::UnpackError) type is
 /// [`UnknownTagError<T>`](crate::error::UnknownTagError) where `T` is the type specified according
@@ -166,6 +218,54 @@ use crate::{
 /// specified using the `with_error` optional argument for the `tag_type` attribute:
 /// `#[packable(tag_type = ..., with_error = ...)]`. This argument must be a valid Rust expression.
 ///
+/// ## Payload errors
+///
+/// With a single `UnpackError` type, an unknown tag and a malformed payload of a known variant can
+/// end up as the same error. Enums can tell them apart with the
+/// `#[packable(payload_error = ...)]` attribute, which makes their `UnpackError` an
+/// [`EnumUnpackError<T, E>`](crate::error::EnumUnpackError), where `T` is the tag type and `E` is
+/// the type given to the attribute. Unknown tags are raised as `EnumUnpackError::UnknownTag`,
+/// while the errors raised by the payload of a variant, including the ones of the `strict_len` and
+/// `length_prefixed_variants` attributes, are converted into `E` and raised as
+/// `EnumUnpackError::Payload` along with the tag of the variant. Like `unpack_error`, this
+/// attribute can receive a `with` argument to map the errors of the fields, and it cannot be used
+/// along with the `unpack_error` attribute.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{
+///     error::{EnumUnpackError, UnknownTagError, UnpackError},
+///     Packable, PackableExt,
+/// };
+///
+/// #[derive(Packable)]
+/// #[packable(tag_type = u8)]
+/// pub enum Flag {
+///     #[packable(tag = 0)]
+///     Off,
+///     #[packable(tag = 1)]
+///     On,
+/// }
+///
+/// #[derive(Packable)]
+/// #[packable(tag_type = u8)]
+/// #[packable(payload_error = UnknownTagError<u8>)]
+/// pub enum Command {
+///     #[packable(tag = 0)]
+///     Ping,
+///     #[packable(tag = 1)]
+///     Set(Flag),
+/// }
+///
+/// assert!(matches!(
+///     Command::unpack_verified([2], &()),
+///     Err(UnpackError::Packable(EnumUnpackError::UnknownTag(2)))
+/// ));
+/// assert!(matches!(
+///     Command::unpack_verified([1, 2], &()),
+///     Err(UnpackError::Packable(EnumUnpackError::Payload { tag: 1, .. }))
+/// ));
+/// ```
+///
 /// ## Additional semantic verifications
 ///
 /// From time to time it is required to do additional semantic verifications over one of more
@@ -178,6 +278,436 @@ use crate::{
 /// where `F` is the type of the field being verified, `P` is the type of the `struct` or `enum`
 /// and `VERIFY` is the same constant parameter used inside `Packable::unpack`. This verification
 /// function will be run immediately after unpacking the field.
+///
+/// ## Skipping fields
+///
+/// Fields of a `struct` or an `enum`'s variant can be excluded from the packed representation using the
+/// `#[packable(skip)]` attribute. Skipped fields are not packed and they are initialized using their [`Default`]
+/// implementation when unpacking.
+///
+/// ## Length prefixes
+///
+/// Fields of type [`Vec<T>`] of a `struct` or an `enum`'s variant can use the `#[packable(length_prefix = ...)]`
+/// attribute to specify the type of their length prefix. The field is then packed and unpacked exactly like a
+/// [`VecPrefix<T, B>`](crate::prefix::VecPrefix) where `B` is the type given to the attribute, meaning that its
+/// [`UnpackError`](Packable::UnpackError) is [`UnpackPrefixError`](crate::prefix::UnpackPrefixError). Packing
+/// panics if the length of the field does not fit in the prefix type.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// #[derive(Packable)]
+/// pub struct Frame {
+///     #[packable(skip)]
+///     received: bool,
+///     #[packable(length_prefix = u16)]
+///     data: Vec<u8>,
+/// }
+///
+/// let frame = Frame {
+///     received: true,
+///     data: vec![42],
+/// };
+///
+/// assert_eq!(frame.pack_to_vec(), [1, 0, 42]);
+/// ```
+///
+/// ## Counts held by other fields
+///
+/// Fields of type [`Vec<T>`] or `Box<[T]>` can instead use the `#[packable(count = ...)]` attribute, naming another
+/// field, or giving its index in tuple structs, that holds their number of elements, as in many C struct layouts. The
+/// elements are then packed without any prefix, and unpacked like the elements of a [`Vec<T>`], so the
+/// [`UnpackError`](Packable::UnpackError) of the field is the same. The count field must be declared **before** the
+/// field using it, so that it is unpacked first, and cannot be skipped. Packing panics if the count field does not
+/// match the length of the field.
+/// ```rust
+/// # use packable as packable_crate;
+/// use core::{convert::Infallible, num::TryFromIntError};
+///
+/// use packable::{error::ArrayUnpackError, prefix::UnpackPrefixError, Packable, PackableExt};
+///
+/// #[derive(Packable, Debug, PartialEq)]
+/// #[packable(unpack_error = UnpackPrefixError<ArrayUnpackError<Infallible>, TryFromIntError>)]
+/// pub struct Samples {
+///     len: u16,
+///     rate: u8,
+///     #[packable(count = len)]
+///     values: Vec<u8>,
+/// }
+///
+/// let samples = Samples {
+///     len: 2,
+///     rate: 10,
+///     values: vec![3, 4],
+/// };
+/// let bytes = samples.pack_to_vec();
+///
+/// assert_eq!(bytes, [2, 0, 10, 3, 4]);
+/// assert_eq!(Samples::unpack_verified(bytes, &()).unwrap(), samples);
+/// ```
+///
+/// ## Flattened fields
+///
+/// Structs are packed without any framing, so a field whose type is a struct is already packed as if its fields were
+/// declared inline. The `#[packable(flatten)]` attribute makes this explicit for fields used to share common parts,
+/// like headers, across types. Flattened fields cannot have `skip` or `length_prefix` attributes and are never
+/// part of a presence bitfield. As for any other field, the [`UnpackError`](Packable::UnpackError) of the outer type
+/// must implement [`From`] the one of the flattened field.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// #[derive(Packable)]
+/// pub struct Header {
+///     version: u8,
+///     id: u16,
+/// }
+///
+/// #[derive(Packable)]
+/// pub struct Ping {
+///     #[packable(flatten)]
+///     header: Header,
+///     nonce: u8,
+/// }
+///
+/// let ping = Ping {
+///     header: Header { version: 1, id: 2 },
+///     nonce: 3,
+/// };
+///
+/// assert_eq!(ping.pack_to_vec(), [1, 2, 0, 3]);
+/// ```
+///
+/// ## Packing and unpacking functions
+///
+/// Fields of a `struct` or an `enum`'s variant can be packed or unpacked by functions instead of the [`Packable`]
+/// implementation of their type, independently of each other. The `#[packable(pack_with = ...)]` attribute receives
+/// a path to a function with the signature
+/// ```ignore
+/// fn<P: Packer>(field: &F, packer: &mut P) -> Result<(), P::Error>
+/// ```
+/// and the `#[packable(unpack_with = ...)]` attribute a path to a function with the signature
+/// ```ignore
+/// fn<U: Unpacker, const VERIFY: bool>(unpacker: &mut U) -> Result<F, UnpackError<E, U::Error>>
+/// ```
+/// where `F` is the type of the field and `E` is converted like the `UnpackError` of any other field. If the type has
+/// an explicit `unpack_visitor`, the unpacking function also receives it as a second `visitor: &V` argument, as
+/// `verify_with` functions do. A field with only one of these attributes uses the [`Packable`] implementation of its
+/// type for the other direction, so it can, for example, be packed as a value derived from it and unpacked as is.
+///
+/// Fields are still packed and unpacked in declaration order: each function is called in place of the implementation
+/// of the field type, after the previous fields, and the `verify_with` function of the field runs after its unpacking
+/// function. These attributes cannot be combined with `skip`, `flatten`, `length_prefix` or `count` attributes, and
+/// the fields using them are never part of a presence bitfield nor of a fixed packed size. Fields with an
+/// `unpack_with` function do not decide the default `UnpackError` and `UnpackVisitor` of the type.
+/// ```rust
+/// # use packable as packable_crate;
+/// use core::{convert::Infallible, time::Duration};
+///
+/// use packable::{
+///     error::{UnpackError, UnpackErrorExt},
+///     packer::Packer,
+///     unpacker::Unpacker,
+///     Packable, PackableExt,
+/// };
+///
+/// fn pack_secs<P: Packer>(duration: &Duration, packer: &mut P) -> Result<(), P::Error> {
+///     (duration.as_secs() as u32).pack(packer)
+/// }
+///
+/// fn unpack_secs<U: Unpacker, const VERIFY: bool>(
+///     unpacker: &mut U,
+/// ) -> Result<Duration, UnpackError<Infallible, U::Error>> {
+///     let secs = u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+///
+///     Ok(Duration::from_secs(secs.into()))
+/// }
+///
+/// #[derive(Packable, Debug, PartialEq)]
+/// #[packable(unpack_error = Infallible)]
+/// pub struct Lease {
+///     id: u8,
+///     #[packable(pack_with = pack_secs)]
+///     #[packable(unpack_with = unpack_secs)]
+///     ttl: Duration,
+/// }
+///
+/// let lease = Lease {
+///     id: 1,
+///     ttl: Duration::from_secs(60),
+/// };
+/// let bytes = lease.pack_to_vec();
+///
+/// assert_eq!(bytes, [1, 60, 0, 0, 0]);
+/// assert_eq!(Lease::unpack_verified(bytes, &()).unwrap(), lease);
+/// ```
+///
+/// ## Length-prefixed variants
+///
+/// Enums can use the `#[packable(length_prefixed_variants)]` attribute to pack the payload of every variant after its
+/// tag, prefixed by its length in bytes as a `u32`. This lets older readers handle newer data: bytes appended to the
+/// payload of a known variant are skipped with [`Unpacker::skip_bytes`](crate::unpacker::Unpacker::skip_bytes), and
+/// the payload of a variant with an unknown tag is skipped before returning the error, so unpacking can go on with
+/// the next value. The payload is unpacked within its declared length, so one that turns out to be longer than its
+/// prefix declares is rejected without reading the bytes that follow it, and the `UnpackError` of the `enum` must
+/// implement [`From<VariantLengthError>`](crate::error::VariantLengthError).
+/// ```rust
+/// # use packable as packable_crate;
+/// use core::convert::Infallible;
+///
+/// use packable::{error::VariantLengthError, Packable, PackableExt};
+///
+/// #[derive(Debug)]
+/// pub enum MessageError {
+///     UnknownTag(u8),
+///     VariantLength(VariantLengthError),
+/// }
+///
+/// impl From<Infallible> for MessageError {
+///     fn from(err: Infallible) -> Self {
+///         match err {}
+///     }
+/// }
+///
+/// impl From<VariantLengthError> for MessageError {
+///     fn from(err: VariantLengthError) -> Self {
+///         Self::VariantLength(err)
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// #[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+/// #[packable(unpack_error = MessageError)]
+/// #[packable(length_prefixed_variants)]
+/// pub enum Message {
+///     #[packable(tag = 0)]
+///     Ping(u16),
+/// }
+///
+/// assert_eq!(Message::Ping(1).pack_to_vec(), [0, 2, 0, 0, 0, 1, 0]);
+/// // The trailing byte is part of the payload but unknown to this version of `Ping`.
+/// assert_eq!(
+///     Message::unpack_verified([0, 3, 0, 0, 0, 1, 0, 42], &()).unwrap(),
+///     Message::Ping(1)
+/// );
+/// ```
+///
+/// The length prefix of every variant can be set to another unsigned integer type with the
+/// `#[packable(length_prefixed_variants = ...)]` attribute, e.g. `u8` for small payloads. The payloads of unknown
+/// variants are skipped using this prefix. The length prefix of a single variant can also be set with the
+/// `#[packable(length_prefix = ...)]` attribute on the variant, which cannot be used together with `tag_after`. As
+/// older readers skip the variants they do not know using the prefix of the `enum`, variants that may be added later
+/// should keep it. Packing panics if the length of a payload does not fit in the prefix type of its variant.
+///
+/// ## Strict lengths for variants
+///
+/// Variants of an `enum` with length-prefixed variants can use the `#[packable(strict_len)]` attribute to reject the
+/// bytes left in their payload after unpacking it instead of skipping them, which helps detecting schema mismatches
+/// between peers. The check is only done when verifying, and the `UnpackError` of the `enum` must implement
+/// [`From<TrailingBytesError>`](crate::error::TrailingBytesError). The bytes left after a whole value are rejected by
+/// [`PackableExt::unpack_exact`] instead.
+///
+/// ## Tag after the payload
+///
+/// Enums can use the `#[packable(tag_after)]` attribute to pack the tag after the payload of the variant instead of
+/// before it. As the variant is only known once the payload has been read, the payload is first unpacked into a buffer
+/// so this requires the `alloc` feature. The payload can only be read without knowing its variant if its length is
+/// known, so either:
+/// - the `enum` also uses the `length_prefixed_variants` attribute, and the length prefix is packed first, followed by
+///   the payload and the tag, or
+/// - every field of every variant implements [`PackedSize`] and all the variants have the same packed size.
+///
+/// Compilation fails otherwise, e.g. if a field has a `length_prefix` attribute or does not implement [`PackedSize`].
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// #[packable(tag_type = u8)]
+/// #[packable(tag_after)]
+/// pub enum Record {
+///     #[packable(tag = 0)]
+///     Temperature(i16),
+///     #[packable(tag = 1)]
+///     Humidity { value: u8, precision: u8 },
+/// }
+///
+/// let bytes = Record::Temperature(-2).pack_to_vec();
+/// assert_eq!(bytes, [0xFE, 0xFF, 0]);
+/// assert_eq!(Record::unpack_verified(&bytes, &()).unwrap(), Record::Temperature(-2));
+/// ```
+///
+/// ## Fixed packed sizes
+///
+/// Structs whose fields are all packed using a fixed number of bytes can use the `#[packable(packed_size)]`
+/// attribute to also implement [`PackedSize`]. Compilation fails if any field that is not skipped does not implement
+/// [`PackedSize`] or has a `length_prefix` attribute. Fields of sequence types, like `Vec<T>` or `String`, are
+/// rejected with an error explaining that the fields after them cannot have a constant layout.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackedSize};
+///
+/// #[derive(Packable)]
+/// #[packable(packed_size)]
+/// pub struct Rgb {
+///     r: u32,
+///     g: u32,
+///     b: u32,
+/// }
+///
+/// let buffer = [0u8; Rgb::PACKED_SIZE];
+/// assert_eq!(buffer.len(), 12);
+/// ```
+///
+/// ## Inherent helpers
+///
+/// The `#[packable(inherent_helpers)]` attribute generates the inherent `to_packed_bytes` and `from_packed_bytes`
+/// methods, which delegate to [`PackableExt::pack_to_vec`] and [`PackableExt::unpack_verified`] with the default
+/// [`UnpackVisitor`](Packable::UnpackVisitor). They avoid importing [`PackableExt`] at call sites.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::Packable;
+///
+/// #[derive(Packable, Debug, PartialEq)]
+/// #[packable(inherent_helpers)]
+/// pub struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let point = Point { x: 1, y: -1 };
+/// let bytes = point.to_packed_bytes();
+///
+/// assert_eq!(Point::from_packed_bytes(&bytes).unwrap(), point);
+/// ```
+///
+/// ## Tag constants
+///
+/// The `#[packable(tag_consts)]` attribute generates an inherent constant holding the tag of every variant of an enum,
+/// named after the variant in `SCREAMING_SNAKE_CASE` with a `_TAG` suffix, and an inherent `const fn tag(&self)`
+/// returning the tag of the variant of a value. Tags can then be referenced without duplicating their values, e.g. in
+/// lookup tables. Compilation fails if the attribute is used on a struct.
+///
+/// Enums with a `u8` tag packed before the payload also get an inherent `peek_tag` method returning the tag of the
+/// next value of an [`Unpacker`](crate::unpacker::Unpacker) without consuming it, based on
+/// [`Unpacker::peek_u8`](crate::unpacker::Unpacker::peek_u8). Callers can then decide how to parse the value before
+/// unpacking it.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{unpacker::SliceUnpacker, Packable, PackableExt};
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// #[packable(tag_type = u8)]
+/// #[packable(tag_consts)]
+/// pub enum Request {
+///     #[packable(tag = 1)]
+///     Ping,
+///     #[packable(tag = 4)]
+///     GetBlock(u32),
+/// }
+///
+/// assert_eq!(Request::GET_BLOCK_TAG, 4);
+/// assert_eq!(Request::Ping.tag(), Request::PING_TAG);
+///
+/// let bytes = Request::GetBlock(7).pack_to_vec();
+/// let mut unpacker = SliceUnpacker::new(&bytes);
+///
+/// assert_eq!(Request::peek_tag(&mut unpacker).unwrap(), Some(Request::GET_BLOCK_TAG));
+/// assert_eq!(Request::unpack::<_, true>(&mut unpacker, &()).unwrap(), Request::GetBlock(7));
+/// ```
+///
+/// ## Transparent newtypes
+///
+/// Structs with `#[repr(transparent)]` and a single field can use the `#[packable(transparent)]` attribute to
+/// implement [`Packable`] by forwarding to the implementation of their field. The struct is packed exactly like its
+/// field, with the same [`UnpackError`](Packable::UnpackError) and [`UnpackVisitor`](Packable::UnpackVisitor), and the
+/// generated methods are marked as `#[inline]`. Compilation fails if the struct is not `#[repr(transparent)]`, if it
+/// does not have exactly one field, if that field has `packable` attributes or if the struct also has an
+/// `unpack_error`, `unpack_visitor`, `verify_with` or `presence_bitfield` attribute.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// #[derive(Packable)]
+/// #[repr(transparent)]
+/// #[packable(transparent)]
+/// pub struct BlockId(u64);
+///
+/// assert_eq!(BlockId(42).pack_to_vec(), 42u64.pack_to_vec());
+/// ```
+///
+/// ## Inlining
+///
+/// The `#[packable(inline)]` attribute marks the generated `pack` and `unpack` methods as `#[inline]`, so that they
+/// can be inlined into their callers across crates. This is meant for small types packed on hot paths, such as tags or
+/// headers made of a few fixed-size fields, as inlining the methods of large types mostly increases the code size.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::Packable;
+///
+/// #[derive(Packable)]
+/// #[packable(inline)]
+/// pub struct Header {
+///     kind: u8,
+///     len: u16,
+/// }
+/// ```
+///
+/// ## Presence bitfields
+///
+/// The `#[packable(presence_bitfield)]` attribute can be used on structs to pack all their `Option<_>` fields behind
+/// a single bitmask instead of a tag byte per field. The mask is packed first and bit `i` is set when the `i`-th
+/// optional field, in declaration order, is `Some`. Only the values of present fields are packed afterwards, in
+/// declaration order along with the other fields.
+///
+/// The mask is a `u8`, `u16`, `u32` or `u64` depending on the number of optional fields, which means a struct can
+/// have at most 64 of them. Unused bits of the mask are ignored while unpacking. Skipped fields and fields with a
+/// `length_prefix` attribute are not considered optional fields. This attribute cannot be combined with
+/// `packed_size`.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// #[derive(Packable, Debug, PartialEq)]
+/// #[packable(presence_bitfield)]
+/// pub struct Settings {
+///     timeout: Option<u16>,
+///     version: u8,
+///     retries: Option<u8>,
+/// }
+///
+/// let settings = Settings {
+///     timeout: None,
+///     version: 1,
+///     retries: Some(3),
+/// };
+/// let bytes = settings.pack_to_vec();
+///
+/// assert_eq!(bytes, [0b10, 1, 3]);
+/// assert_eq!(Settings::unpack_verified(&bytes, &()).unwrap(), settings);
+/// ```
+///
+/// ## Round-trip tests
+///
+/// The `#[packable(roundtrip_test)]` attribute generates a test, only compiled with `cfg(test)`, that packs the
+/// [`Default`] value of the type, unpacks it and checks that the unpacked value is packed into the same bytes. Bytes
+/// are compared instead of values so the type does not need to implement [`PartialEq`]. The type must implement
+/// [`Default`], must not be generic and must be declared at the module level.
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::Packable;
+///
+/// #[derive(Default, Packable)]
+/// #[packable(roundtrip_test)]
+/// pub struct Ping {
+///     nonce: u64,
+/// }
+/// ```
+///
+/// ## Borrowed structs
+///
+/// Structs with a lifetime parameter, like `struct Frame<'a> { body: &'a [u8] }`, cannot implement [`Packable`] as
+/// they borrow the bytes they are unpacked from. The derive macro implements [`PackableRef`] for them instead.
 pub trait Packable: Sized + 'static {
     /// The error type that can be returned if some semantic error occurs while unpacking.
     ///
@@ -196,6 +726,30 @@ pub trait Packable: Sized + 'static {
         unpacker: &mut U,
         visitor: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>>;
+
+    /// Unpacks this value from the given [`Unpacker`], reading at most `max_bytes` bytes from it.
+    ///
+    /// Unpacking fails with [`LimitedUnpackerError::SizeLimitExceeded`] as soon as the value would need more bytes,
+    /// regardless of how many bytes are left in the [`Unpacker`]. This can be used to bound a value nested in a larger
+    /// stream, like a length-delimited message.
+    #[inline]
+    fn unpack_bounded<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        max_bytes: usize,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, LimitedUnpackerError<U::Error>>> {
+        Self::unpack::<_, VERIFY>(&mut LimitedUnpacker::new(unpacker, max_bytes), visitor)
+    }
+}
+
+/// A [`Packable`] type whose values are always packed using the same number of bytes.
+///
+/// Unlike [`PackableExt::packed_len`], the size is known at compile time so it can be used to size arrays or in
+/// constant assertions. This trait can be implemented by the derive macro for structs with the
+/// `#[packable(packed_size)]` attribute, which requires every field that is not skipped to implement [`PackedSize`].
+pub trait PackedSize: Packable {
+    /// The number of bytes used to pack any value of this type.
+    const PACKED_SIZE: usize;
 }
 
 /// Extension trait for types that implement [`Packable`].
@@ -205,6 +759,7 @@ pub trait PackableExt: Packable {
     fn packed_len(&self) -> usize;
 
     /// Convenience method that packs this value into a [`Vec<u8>`].
+    #[cfg(feature = "alloc")]
     fn pack_to_vec(&self) -> Vec<u8>;
 
     /// Unpacks this value from a sequence of bytes doing syntactical checks.
@@ -217,6 +772,15 @@ pub trait PackableExt: Packable {
     fn unpack_unverified<T: AsRef<[u8]>>(
         bytes: T,
     ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnexpectedEOF>>;
+
+    /// Unpacks this value from a whole sequence of bytes doing syntactical checks, using the default
+    /// [`UnpackVisitor`](Packable::UnpackVisitor).
+    ///
+    /// Unlike [`unpack_verified`](PackableExt::unpack_verified), unpacking fails with a [`TrailingBytesError`] if bytes
+    /// are left after the value, which makes it the safe default to parse complete messages.
+    fn unpack_exact<T: AsRef<[u8]>>(
+        bytes: T,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, ExactUnpackError>>;
 }
 
 impl<P: Packable> PackableExt for P {
@@ -231,6 +795,7 @@ impl<P: Packable> PackableExt for P {
     }
 
     #[inline]
+    #[cfg(feature = "alloc")]
     fn pack_to_vec(&self) -> Vec<u8> {
         let mut packer = Vec::with_capacity(self.packed_len());
 
@@ -259,4 +824,22 @@ impl<P: Packable> PackableExt for P {
             &<P as Packable>::UnpackVisitor::default(),
         )
     }
+
+    #[inline]
+    fn unpack_exact<T: AsRef<[u8]>>(
+        bytes: T,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, ExactUnpackError>> {
+        let mut unpacker = SliceUnpacker::new(bytes.as_ref());
+        let value = Self::unpack::<_, true>(&mut unpacker, &<P as Packable>::UnpackVisitor::default()).map_err(
+            |err| match err {
+                UnpackError::Packable(err) => UnpackError::Packable(err),
+                UnpackError::Unpacker(err) => UnpackError::Unpacker(err.into()),
+            },
+        )?;
+
+        match unpacker.remaining_bytes() {
+            Some(0) | None => Ok(value),
+            Some(remaining) => Err(UnpackError::Unpacker(TrailingBytesError { remaining }.into())),
+        }
+    }
 }
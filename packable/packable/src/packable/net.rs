@@ -0,0 +1,123 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{
+    convert::Infallible,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6},
+};
+
+use crate::{
+    Packable, PackedSize,
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+};
+
+/// Addresses are packed as their octets, in network byte order.
+impl Packable for Ipv4Addr {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes(self.octets())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        <[u8; 4]>::unpack::<_, VERIFY>(unpacker, visitor)
+            .coerce()
+            .map(Self::from)
+    }
+}
+
+impl PackedSize for Ipv4Addr {
+    const PACKED_SIZE: usize = 4;
+}
+
+/// Addresses are packed as their octets, in network byte order.
+impl Packable for Ipv6Addr {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes(self.octets())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        <[u8; 16]>::unpack::<_, VERIFY>(unpacker, visitor)
+            .coerce()
+            .map(Self::from)
+    }
+}
+
+impl PackedSize for Ipv6Addr {
+    const PACKED_SIZE: usize = 16;
+}
+
+/// Socket addresses are packed as their address followed by their port.
+impl Packable for SocketAddrV4 {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.ip().pack(packer)?;
+        self.port().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let ip = Ipv4Addr::unpack::<_, VERIFY>(unpacker, visitor)?;
+        let port = u16::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+
+        Ok(Self::new(ip, port))
+    }
+}
+
+impl PackedSize for SocketAddrV4 {
+    const PACKED_SIZE: usize = Ipv4Addr::PACKED_SIZE + u16::PACKED_SIZE;
+}
+
+/// Socket addresses are packed as their address followed by their port, flow information and scope ID, so that
+/// link-local addresses keep the interface they are scoped to.
+impl Packable for SocketAddrV6 {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.ip().pack(packer)?;
+        self.port().pack(packer)?;
+        self.flowinfo().pack(packer)?;
+        self.scope_id().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let ip = Ipv6Addr::unpack::<_, VERIFY>(unpacker, visitor)?;
+        let port = u16::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let flowinfo = u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let scope_id = u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+
+        Ok(Self::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+impl PackedSize for SocketAddrV6 {
+    const PACKED_SIZE: usize = Ipv6Addr::PACKED_SIZE + u16::PACKED_SIZE + 2 * u32::PACKED_SIZE;
+}
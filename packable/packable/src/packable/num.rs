@@ -3,7 +3,7 @@
 
 use core::convert::Infallible;
 
-use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable, PackedSize};
 
 macro_rules! impl_packable_for_num {
     ($ty:ty) => {
@@ -26,6 +26,10 @@ macro_rules! impl_packable_for_num {
                 Ok(Self::from_le_bytes(bytes))
             }
         }
+
+        impl PackedSize for $ty {
+            const PACKED_SIZE: usize = core::mem::size_of::<Self>();
+        }
     };
 }
 
@@ -72,6 +76,11 @@ impl Packable for usize {
     }
 }
 
+#[cfg(feature = "usize")]
+impl PackedSize for usize {
+    const PACKED_SIZE: usize = core::mem::size_of::<u64>();
+}
+
 #[cfg(feature = "usize")]
 impl Packable for isize {
     type UnpackError = core::num::TryFromIntError;
@@ -91,3 +100,8 @@ impl Packable for isize {
         Self::try_from(i64::unpack::<_, VERIFY>(unpacker, visitor).coerce()?).map_err(UnpackError::Packable)
     }
 }
+
+#[cfg(feature = "usize")]
+impl PackedSize for isize {
+    const PACKED_SIZE: usize = core::mem::size_of::<u64>();
+}
@@ -3,13 +3,18 @@
 
 //! Types and utilities related to packing and unpacking [`Option`] values.
 
-use core::{convert::Infallible, fmt};
+use core::{
+    convert::Infallible,
+    fmt,
+    num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64},
+    ops::Deref,
+};
 
 use crate::{
     error::{UnpackError, UnpackErrorExt},
     packer::Packer,
     unpacker::Unpacker,
-    Packable,
+    Packable, PackedSize,
 };
 
 /// Error type raised when a semantic error occurs while unpacking an option.
@@ -67,3 +72,75 @@ impl<T: Packable> Packable for Option<T> {
         }
     }
 }
+
+/// Wrapper type for an optional non-zero integer that is packed as the integer itself, `0` standing for `None`.
+///
+/// Unlike [`Option`], no tag is packed, so that `None` and `Some(NonZeroU32::new(5).unwrap())` are respectively packed
+/// as the bytes of `0u32` and `5u32`. Every packed value is valid, so unpacking never fails with a semantic error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct OptionalNonZero<T>(pub Option<T>);
+
+impl<T> OptionalNonZero<T> {
+    /// Consumes the wrapper and returns the inner option.
+    #[inline(always)]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> From<Option<T>> for OptionalNonZero<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<OptionalNonZero<T>> for Option<T> {
+    fn from(value: OptionalNonZero<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> Deref for OptionalNonZero<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_packable_for_optional_non_zero {
+    ($ty:ty, $int:ty) => {
+        impl Packable for OptionalNonZero<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.0.map_or(0, <$ty>::get).pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                <$int>::unpack::<_, VERIFY>(unpacker, visitor).map(|value| Self(<$ty>::new(value)))
+            }
+        }
+
+        impl PackedSize for OptionalNonZero<$ty> {
+            const PACKED_SIZE: usize = <$int>::PACKED_SIZE;
+        }
+    };
+}
+
+impl_packable_for_optional_non_zero!(NonZeroU8, u8);
+impl_packable_for_optional_non_zero!(NonZeroU16, u16);
+impl_packable_for_optional_non_zero!(NonZeroU32, u32);
+impl_packable_for_optional_non_zero!(NonZeroU64, u64);
+impl_packable_for_optional_non_zero!(NonZeroI8, i8);
+impl_packable_for_optional_non_zero!(NonZeroI16, i16);
+impl_packable_for_optional_non_zero!(NonZeroI32, i32);
+impl_packable_for_optional_non_zero!(NonZeroI64, i64);
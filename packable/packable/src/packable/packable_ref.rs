@@ -0,0 +1,103 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{convert::Infallible, fmt::Debug, num::TryFromIntError};
+
+use crate::{
+    error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    prefix::UnpackPrefixError,
+    unpacker::SliceUnpacker,
+    Packable,
+};
+
+/// A type that can be packed, and unpacked borrowing data from the bytes it is unpacked from.
+///
+/// [`Packable`] types own their data, so they cannot hold references to the unpacked bytes. Types with a lifetime
+/// implement `PackableRef<'a>` instead, and are unpacked from a [`SliceUnpacker<'a>`] whose bytes they can borrow,
+/// like a `&'a [u8]` field, without copying them.
+///
+/// Every [`Packable`] type without an [`UnpackVisitor`](Packable::UnpackVisitor) implements `PackableRef<'a>` for any
+/// lifetime and is packed the same way, so borrowed types can hold owned fields. A `&'a [u8]` is packed like a
+/// `Vec<u8>`.
+///
+/// This trait is implemented by the [`Packable`](packable_derive::Packable) derive macro for structs with a lifetime
+/// parameter, as long as every field that is not skipped implements `PackableRef<'a>`:
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableRef};
+///
+/// #[derive(Packable)]
+/// pub struct Frame<'a> {
+///     body: &'a [u8],
+///     kind: u8,
+/// }
+///
+/// let bytes = [2, 0, 0, 0, 0, 0, 0, 0, 3, 4, 1];
+/// let frame = Frame::unpack_ref_verified(&bytes).unwrap();
+///
+/// assert_eq!(frame.kind, 1);
+/// assert_eq!(frame.body, &[3, 4]);
+/// ```
+/// Borrowed structs only support the `unpack_error`, `verify_with`, `skip` and `unpack_error_with` attributes, and
+/// cannot be generic over more than one lifetime.
+pub trait PackableRef<'a>: Sized {
+    /// The error type that can be returned if some semantic error occurs while unpacking.
+    type UnpackError: Debug + From<Infallible>;
+
+    /// Packs this value into the given [`Packer`].
+    fn pack_ref<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error>;
+
+    /// Unpacks this value from the given [`SliceUnpacker`], borrowing from its bytes. The `VERIFY` generic parameter
+    /// can be used to skip additional syntactic checks.
+    fn unpack_ref<const VERIFY: bool>(
+        unpacker: &mut SliceUnpacker<'a>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>>;
+
+    /// Unpacks this value from a sequence of bytes doing syntactical checks.
+    fn unpack_ref_verified(bytes: &'a [u8]) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        Self::unpack_ref::<true>(&mut SliceUnpacker::new(bytes))
+    }
+
+    /// Unpacks this value from a sequence of bytes without doing syntactical checks.
+    fn unpack_ref_unverified(bytes: &'a [u8]) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        Self::unpack_ref::<false>(&mut SliceUnpacker::new(bytes))
+    }
+}
+
+impl<'a, T: Packable<UnpackVisitor = ()>> PackableRef<'a> for T {
+    type UnpackError = T::UnpackError;
+
+    #[inline]
+    fn pack_ref<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.pack(packer)
+    }
+
+    #[inline]
+    fn unpack_ref<const VERIFY: bool>(
+        unpacker: &mut SliceUnpacker<'a>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        T::unpack::<_, VERIFY>(unpacker, &())
+    }
+}
+
+impl<'a> PackableRef<'a> for &'a [u8] {
+    type UnpackError = UnpackPrefixError<Infallible, TryFromIntError>;
+
+    #[inline]
+    fn pack_ref<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_prefixed_bytes(self)
+    }
+
+    #[inline]
+    fn unpack_ref<const VERIFY: bool>(
+        unpacker: &mut SliceUnpacker<'a>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        let len = u64::unpack::<_, VERIFY>(unpacker, &())
+            .coerce()?
+            .try_into()
+            .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
+
+        Ok(unpacker.unpack_borrowed(len)?)
+    }
+}
@@ -3,16 +3,26 @@
 
 //! Types and utilities used to pack and unpack dynamic sequences of values with restricted length prefixes.
 
+#[cfg(feature = "alloc")]
 mod boxed;
+#[cfg(feature = "alloc")]
 mod btreeset;
+#[cfg(feature = "alloc")]
 mod string;
+#[cfg(feature = "alloc")]
 mod vec;
 
 use core::{convert::Infallible, fmt};
 
+use crate::error::ArrayUnpackError;
+
+#[cfg(feature = "alloc")]
 pub use boxed::BoxedSlicePrefix;
+#[cfg(feature = "alloc")]
 pub use btreeset::BTreeSetPrefix;
+#[cfg(feature = "alloc")]
 pub use string::StringPrefix;
+#[cfg(feature = "alloc")]
 pub use vec::VecPrefix;
 
 /// Semantic error raised while unpacking dynamically-sized sequences.
@@ -52,6 +62,16 @@ impl<E> UnpackPrefixError<Infallible, E> {
     }
 }
 
+impl<E> UnpackPrefixError<ArrayUnpackError<Infallible>, E> {
+    /// Projects the value to the [`Prefix`](UnpackPrefixError::Prefix) variant.
+    pub fn into_prefix_err(self) -> E {
+        match self {
+            Self::Item(err) => match err.inner {},
+            Self::Prefix(err) => err,
+        }
+    }
+}
+
 impl<T> UnpackPrefixError<T, Infallible> {
     /// Projects the value to the [`Item`](UnpackPrefixError::Item) variant.
     pub fn into_item_err(self) -> T {
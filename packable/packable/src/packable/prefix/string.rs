@@ -12,7 +12,7 @@ use core::{
 
 use crate::{
     bounded::Bounded,
-    error::{UnpackError, UnpackErrorExt},
+    error::{PackPrefixedBytesError, UnpackError, UnpackErrorExt},
     packable::Packable,
     packer::Packer,
     prefix::UnpackPrefixError,
@@ -91,33 +91,25 @@ where
 
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-        // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
+        // The length of any dynamically-sized sequence must be prefixed. It is within the bounds of the prefix since
         // the length of the inner `String` has been validated while creating this `StringPrefix`.
-        B::try_from(self.len()).unwrap().pack(packer)?;
-
-        packer.pack_bytes(self.inner.as_bytes())?;
-
-        Ok(())
+        packer
+            .pack_prefixed_bytes_with::<B>(self.inner.as_bytes())
+            .map_err(|err| match err {
+                PackPrefixedBytesError::Prefix(err) => unreachable!("{:?}", err),
+                PackPrefixedBytesError::Packer(err) => err,
+            })
     }
 
     #[inline]
     fn unpack<U: Unpacker, const VERIFY: bool>(
         unpacker: &mut U,
-        visitor: &Self::UnpackVisitor,
+        _: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
         // The length of any dynamically-sized sequence must be prefixed.
-        let len = B::unpack::<_, VERIFY>(unpacker, visitor)
-            .map_packable_err(UnpackPrefixError::Prefix)?
-            .into();
-
-        // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
-        let len = len
-            .try_into()
-            .ok()
-            .expect("the length prefix exceeds the pointer length of this platform");
-
-        let mut bytes = alloc::vec![0u8; len];
-        unpacker.unpack_bytes(&mut bytes)?;
+        let bytes = unpacker
+            .unpack_prefixed_bytes_with::<B, VERIFY>()
+            .map_packable_err(|err| UnpackPrefixError::Prefix(err.into_prefix_err()))?;
 
         let inner = String::from_utf8(bytes).map_err(|e| UnpackError::Packable(UnpackPrefixError::Item(e)))?;
 
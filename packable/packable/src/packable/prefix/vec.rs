@@ -12,7 +12,7 @@ use core::{
 };
 
 use crate::{
-    error::{UnpackError, UnpackErrorExt},
+    error::{PackPrefixedBytesError, UnpackError, UnpackErrorExt},
     packable::bounded::Bounded,
     packer::Packer,
     prefix::UnpackPrefixError,
@@ -93,14 +93,20 @@ where
 
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-        // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
-        // the length of the inner `Vec` has been validated while creating this `VecPrefix`.
-        B::try_from(self.len()).unwrap().pack(packer)?;
         if TypeId::of::<T>() == TypeId::of::<u8>() {
             // Safety: `Self` is identical to `VecPrefix<u8, B>`.
             let bytes = unsafe { core::mem::transmute::<&Self, &VecPrefix<u8, B>>(self) };
-            packer.pack_bytes(bytes.deref())?;
+            // The length of any dynamically-sized sequence must be prefixed. It is within the bounds of the prefix
+            // since the length of the inner `Vec` has been validated while creating this `VecPrefix`.
+            packer.pack_prefixed_bytes_with::<B>(bytes).map_err(|err| match err {
+                PackPrefixedBytesError::Prefix(err) => unreachable!("{:?}", err),
+                PackPrefixedBytesError::Packer(err) => err,
+            })?;
         } else {
+            // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
+            // the length of the inner `Vec` has been validated while creating this `VecPrefix`.
+            B::try_from(self.len()).unwrap().pack(packer)?;
+
             for item in self.iter() {
                 item.pack(packer)?;
             }
@@ -114,24 +120,20 @@ where
         unpacker: &mut U,
         visitor: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        // The length of any dynamically-sized sequence must be prefixed.
-        let len = B::unpack::<_, VERIFY>(unpacker, &())
-            .map_packable_err(UnpackPrefixError::Prefix)?
-            .into();
-
         if TypeId::of::<T>() == TypeId::of::<u8>() {
-            // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
-            let len = len
-                .try_into()
-                .ok()
-                .expect("the length prefix exceeds the pointer length of this platform");
-
-            let mut bytes = alloc::vec![0u8; len];
-            unpacker.unpack_bytes(&mut bytes)?;
+            // The length of any dynamically-sized sequence must be prefixed.
+            let bytes = unpacker
+                .unpack_prefixed_bytes_with::<B, VERIFY>()
+                .map_packable_err(|err| UnpackPrefixError::Prefix(err.into_prefix_err()))?;
             // Safety: `Self` is identical to `VecPrefix<u8, B>` which has the same layout as
             // `Vec<u8>` thanks to `#[repr(transparent)]`.
             Ok(unsafe { core::mem::transmute::<Vec<u8>, Self>(bytes) })
         } else {
+            // The length of any dynamically-sized sequence must be prefixed.
+            let len = B::unpack::<_, VERIFY>(unpacker, &())
+                .map_packable_err(UnpackPrefixError::Prefix)?
+                .into();
+
             // If `len` fits in a `usize`, we use it as the capacity of the inner `Vec` to avoid extra
             // allocations.
             //
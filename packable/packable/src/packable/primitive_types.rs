@@ -5,7 +5,12 @@ use core::convert::Infallible;
 
 use primitive_types::U256;
 
-use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable, PackedSize,
+};
 
 impl Packable for U256 {
     type UnpackError = Infallible;
@@ -21,6 +26,10 @@ impl Packable for U256 {
         unpacker: &mut U,
         visitor: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        <[u64; 4]>::unpack::<_, VERIFY>(unpacker, visitor).map(Self)
+        <[u64; 4]>::unpack::<_, VERIFY>(unpacker, visitor).coerce().map(Self)
     }
 }
+
+impl PackedSize for U256 {
+    const PACKED_SIZE: usize = <[u64; 4]>::PACKED_SIZE;
+}
@@ -3,6 +3,7 @@
 
 //! Types and implementations for packing and unpacking set values.
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 use core::{convert::Infallible, fmt};
@@ -98,7 +99,7 @@ impl<T, I: fmt::Display, P: fmt::Display> fmt::Display for UnpackOrderedSetError
     }
 }
 
-#[cfg(feature = "usize")]
+#[cfg(all(feature = "usize", feature = "alloc"))]
 mod btreeset {
     use alloc::collections::BTreeSet;
 
@@ -3,10 +3,7 @@
 
 extern crate alloc;
 
-use alloc::{
-    string::{FromUtf8Error, String},
-    vec::Vec,
-};
+use alloc::string::{FromUtf8Error, String};
 
 use crate::{
     error::{UnpackError, UnpackErrorExt},
@@ -22,21 +19,16 @@ impl Packable for String {
 
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-        let bytes = self.as_bytes();
-        // This cast is fine because we know `usize` is not larger than `64` bits.
-        (bytes.len() as u64).pack(packer)?;
-
-        packer.pack_bytes(bytes)?;
-
-        Ok(())
+        packer.pack_prefixed_bytes(self.as_bytes())
     }
 
     #[inline]
     fn unpack<U: Unpacker, const VERIFY: bool>(
         unpacker: &mut U,
-        visitor: &Self::UnpackVisitor,
+        _: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        let bytes = Vec::<u8>::unpack::<_, VERIFY>(unpacker, visitor)
+        let bytes = unpacker
+            .unpack_prefixed_bytes::<VERIFY>()
             .map_packable_err(|err| UnpackPrefixError::Prefix(err.into_prefix_err()))?;
 
         String::from_utf8(bytes).map_err(|e| UnpackError::Packable(Self::UnpackError::Item(e)))
@@ -1,15 +1,38 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use core::borrow::Borrow;
+use core::{borrow::Borrow, convert::Infallible};
 
 use crate::{
     error::{UnpackError, UnpackErrorExt},
     packer::Packer,
     unpacker::Unpacker,
-    Packable,
+    Packable, PackedSize,
 };
 
+impl Packable for () {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    /// The unit type is packed as no bytes at all.
+    #[inline]
+    fn pack<P: Packer>(&self, _packer: &mut P) -> Result<(), P::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        _unpacker: &mut U,
+        _visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(())
+    }
+}
+
+impl PackedSize for () {
+    const PACKED_SIZE: usize = 0;
+}
+
 macro_rules! tuple_impls {
     ($($Tuple:ident { ($first_idx:tt) -> $FirstT:ident $(($idx:tt) -> $T:ident)* })+) => {
         $(
@@ -3,34 +3,54 @@
 
 extern crate alloc;
 
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 use core::any::TypeId;
 
 use crate::{
-    error::{UnpackError, UnpackErrorExt},
+    error::{ArrayUnpackError, UnpackError, UnpackErrorExt},
     packer::Packer,
     prefix::UnpackPrefixError,
     unpacker::Unpacker,
-    Packable,
+    Packable, PackedSize,
 };
 
+/// Returns the packed size of `T` if it is a primitive type whose values are always packed using the same number of
+/// bytes.
+///
+/// `Vec<T>` does not require `T` to implement [`PackedSize`], so the size can only be known for these types.
+fn fixed_packed_size<T: 'static>() -> Option<usize> {
+    macro_rules! packed_size_of {
+        ($($ty:ty),*) => {
+            $(
+                if TypeId::of::<T>() == TypeId::of::<$ty>() {
+                    return Some(<$ty as PackedSize>::PACKED_SIZE);
+                }
+            )*
+        };
+    }
+
+    packed_size_of!(bool, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+    None
+}
+
 impl<T> Packable for Vec<T>
 where
     T: Packable,
 {
-    type UnpackError = UnpackPrefixError<T::UnpackError, <usize as Packable>::UnpackError>;
+    type UnpackError = UnpackPrefixError<ArrayUnpackError<T::UnpackError>, <usize as Packable>::UnpackError>;
     type UnpackVisitor = T::UnpackVisitor;
 
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-        // This cast is fine because we know `usize` is not larger than `64` bits.
-        (self.len() as u64).pack(packer)?;
-
         if TypeId::of::<T>() == TypeId::of::<u8>() {
             // Safety: `Self` is identical to `Vec<u8>`.
             let bytes = unsafe { core::mem::transmute::<&Self, &Vec<u8>>(self) };
-            packer.pack_bytes(bytes)?;
+            packer.pack_prefixed_bytes(bytes)?;
         } else {
+            // This cast is fine because we know `usize` is not larger than `64` bits.
+            (self.len() as u64).pack(packer)?;
+
             for item in self.iter() {
                 item.pack(packer)?;
             }
@@ -44,21 +64,34 @@ where
         unpacker: &mut U,
         visitor: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        let len = u64::unpack::<_, VERIFY>(unpacker, &())
-            .coerce()?
-            .try_into()
-            .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
-
         if TypeId::of::<T>() == TypeId::of::<u8>() {
-            let mut bytes = vec![0u8; len];
-            unpacker.unpack_bytes(&mut bytes)?;
+            let bytes = unpacker
+                .unpack_prefixed_bytes::<VERIFY>()
+                .map_packable_err(|err| UnpackPrefixError::Prefix(err.into_prefix_err()))?;
             // Safety: `Self` is identical to `Vec<u8>`.
             Ok(unsafe { core::mem::transmute::<Vec<u8>, Self>(bytes) })
         } else {
-            let mut vec = Vec::with_capacity(len);
+            let len = u64::unpack::<_, VERIFY>(unpacker, &())
+                .coerce()?
+                .try_into()
+                .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
+
+            let mut vec = Vec::new();
+
+            if let Some(size) = fixed_packed_size::<T>().and_then(|size| size.checked_mul(len)) {
+                unpacker.ensure_bytes(size)?;
+
+                // `ensure_bytes` always succeeds for unpackers that do not know how many bytes they have left, e.g.
+                // stream unpackers. The whole vector is therefore only allocated upfront if the remaining bytes are
+                // known to hold its elements, otherwise it grows as its elements are unpacked.
+                if unpacker.remaining_bytes().is_some_and(|remaining| size <= remaining) {
+                    vec.reserve_exact(len);
+                }
+            }
 
-            for _ in 0..len {
-                let item = T::unpack::<_, VERIFY>(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
+            for index in 0..len {
+                let item = T::unpack::<_, VERIFY>(unpacker, visitor)
+                    .map_packable_err(|inner| Self::UnpackError::Item(ArrayUnpackError { index, inner }))?;
                 vec.push(item);
             }
 
@@ -0,0 +1,54 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::{collections::VecDeque, vec::Vec};
+use core::any::TypeId;
+
+use crate::{
+    Packable,
+    error::{ArrayUnpackError, UnpackError},
+    packer::Packer,
+    prefix::UnpackPrefixError,
+    unpacker::Unpacker,
+};
+
+/// A [`VecDeque<T>`] is packed like a [`Vec<T>`], with its elements in front-to-back order.
+impl<T> Packable for VecDeque<T>
+where
+    T: Packable,
+{
+    type UnpackError = UnpackPrefixError<ArrayUnpackError<T::UnpackError>, <usize as Packable>::UnpackError>;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (self.len() as u64).pack(packer)?;
+
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            // Safety: `Self` is identical to `VecDeque<u8>`.
+            let bytes = unsafe { core::mem::transmute::<&Self, &VecDeque<u8>>(self) };
+            let (front, back) = bytes.as_slices();
+            packer.pack_bytes(front)?;
+            packer.pack_bytes(back)?;
+        } else {
+            for item in self.iter() {
+                item.pack(packer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        // Unpacking a `Vec<T>` only allocates upfront the elements known to fit in the unpacker, and converting it does
+        // not reallocate.
+        Vec::<T>::unpack::<_, VERIFY>(unpacker, visitor).map(Self::from)
+    }
+}
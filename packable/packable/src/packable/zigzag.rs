@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities used to pack and unpack signed integers using zigzag encoding.
+
+use core::convert::Infallible;
+
+use crate::{Packable, PackedSize, error::UnpackError, packer::Packer, unpacker::Unpacker};
+
+/// Wrapper type for a signed integer that is packed as the unsigned integer of the same width given by zigzag encoding.
+///
+/// Zigzag encoding maps signed integers to unsigned ones so that values close to zero, whether positive or negative,
+/// get small unsigned representations: `0`, `-1`, `1`, `-2`, `2`... are mapped to `0`, `1`, `2`, `3`, `4`... The
+/// value is still packed using the full width of its type, so this wrapper is mostly useful as a first stage before a
+/// variable-length encoding of the unsigned value, or before a general-purpose compression of the packed bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct ZigZag<T>(pub T);
+
+impl<T> ZigZag<T> {
+    /// Consumes the wrapper and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for ZigZag<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> core::ops::Deref for ZigZag<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_packable_for_zigzag {
+    ($ty:ty, $unsigned:ty) => {
+        impl ZigZag<$ty> {
+            /// Returns the unsigned integer the value is mapped to.
+            #[inline]
+            pub const fn encode(self) -> $unsigned {
+                ((self.0 << 1) ^ (self.0 >> (<$ty>::BITS - 1))) as $unsigned
+            }
+
+            /// Returns the value mapped to an unsigned integer.
+            #[inline]
+            pub const fn decode(value: $unsigned) -> Self {
+                Self(((value >> 1) as $ty) ^ -((value & 1) as $ty))
+            }
+        }
+
+        impl Packable for ZigZag<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.encode().pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker, const VERIFY: bool>(
+                unpacker: &mut U,
+                visitor: &Self::UnpackVisitor,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                <$unsigned>::unpack::<_, VERIFY>(unpacker, visitor).map(Self::decode)
+            }
+        }
+
+        impl PackedSize for ZigZag<$ty> {
+            const PACKED_SIZE: usize = <$unsigned>::PACKED_SIZE;
+        }
+    };
+}
+
+impl_packable_for_zigzag!(i8, u8);
+impl_packable_for_zigzag!(i16, u16);
+impl_packable_for_zigzag!(i32, u32);
+impl_packable_for_zigzag!(i64, u64);
+#[cfg(has_i128)]
+impl_packable_for_zigzag!(i128, u128);
@@ -0,0 +1,126 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use base64::engine::{Engine, GeneralPurpose, general_purpose::STANDARD};
+
+use crate::{endian::ByteOrder, packer::Packer};
+
+// Number of complete input groups encoded at once when packing large byte sequences.
+const CHUNK_GROUPS: usize = 16;
+
+/// A [`Packer`] that encodes the packed bytes as base64 before writing them into an inner [`Packer`].
+///
+/// Bytes are encoded in groups of three, so up to two bytes may be pending until [`Base64Packer::finish`] is called to
+/// write them along with the required padding. Dropping the packer without finishing it discards those bytes.
+pub struct Base64Packer<P: Packer> {
+    inner: P,
+    engine: GeneralPurpose,
+    pending: [u8; 2],
+    pending_len: usize,
+    written_bytes: usize,
+}
+
+impl<P: Packer> Base64Packer<P> {
+    /// Creates a new [`Base64Packer`] using the standard base64 alphabet with padding.
+    pub fn new(inner: P) -> Self {
+        Self::with_engine(inner, STANDARD)
+    }
+
+    /// Creates a new [`Base64Packer`] using the given base64 engine, e.g. a URL-safe one.
+    pub fn with_engine(inner: P, engine: GeneralPurpose) -> Self {
+        Self {
+            inner,
+            engine,
+            pending: [0; 2],
+            pending_len: 0,
+            written_bytes: 0,
+        }
+    }
+
+    /// Writes any pending bytes into the inner [`Packer`] and returns it.
+    pub fn finish(mut self) -> Result<P, P::Error> {
+        if self.pending_len > 0 {
+            let mut encoded = [0u8; 4];
+            let len = self.encode(&self.pending[..self.pending_len], &mut encoded);
+            self.inner.pack_bytes(&encoded[..len])?;
+        }
+
+        Ok(self.inner)
+    }
+
+    fn encode(&self, input: &[u8], output: &mut [u8]) -> usize {
+        self.engine
+            .encode_slice(input, output)
+            .expect("output buffer is large enough for the encoded input")
+    }
+}
+
+impl Base64Packer<Vec<u8>> {
+    /// Finishes the packer and returns the encoded bytes as a base64 [`String`].
+    pub fn into_string(self) -> String {
+        let bytes = match self.finish() {
+            Ok(bytes) => bytes,
+            Err(err) => match err {},
+        };
+
+        // Base64 alphabets only contain ASCII characters.
+        String::from_utf8(bytes).expect("base64 output is valid UTF-8")
+    }
+}
+
+impl<P: Packer> Packer for Base64Packer<P> {
+    type Error = P::Error;
+
+    const BYTE_ORDER: ByteOrder = P::BYTE_ORDER;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        let mut bytes = bytes.as_ref();
+
+        self.written_bytes += bytes.len();
+
+        // Complete the pending group first.
+        if self.pending_len > 0 {
+            let missing = 3 - self.pending_len;
+
+            if bytes.len() < missing {
+                self.pending[self.pending_len..self.pending_len + bytes.len()].copy_from_slice(bytes);
+                self.pending_len += bytes.len();
+                return Ok(());
+            }
+
+            let mut group = [0u8; 3];
+            group[..self.pending_len].copy_from_slice(&self.pending[..self.pending_len]);
+            group[self.pending_len..].copy_from_slice(&bytes[..missing]);
+            bytes = &bytes[missing..];
+            self.pending_len = 0;
+
+            let mut encoded = [0u8; 4];
+            let len = self.encode(&group, &mut encoded);
+            self.inner.pack_bytes(&encoded[..len])?;
+        }
+
+        let remainder = bytes.len() % 3;
+        let (groups, rest) = bytes.split_at(bytes.len() - remainder);
+
+        let mut encoded = [0u8; CHUNK_GROUPS * 4];
+
+        for chunk in groups.chunks(CHUNK_GROUPS * 3) {
+            let len = self.encode(chunk, &mut encoded);
+            self.inner.pack_bytes(&encoded[..len])?;
+        }
+
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.written_bytes)
+    }
+}
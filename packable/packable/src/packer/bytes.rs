@@ -0,0 +1,47 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{convert::Infallible, ops::Deref};
+
+use bytes::BytesMut;
+
+use crate::packer::Packer;
+
+/// A [`Packer`] backed by a [`BytesMut`] buffer.
+#[derive(Default)]
+pub struct BytesMutPacker(BytesMut);
+
+impl BytesMutPacker {
+    /// Creates a new [`BytesMutPacker`] from a [`BytesMut`].
+    pub fn new(bytes: BytesMut) -> Self {
+        Self(bytes)
+    }
+
+    /// Consumes the value to return the inner [`BytesMut`].
+    pub fn into_inner(self) -> BytesMut {
+        self.0
+    }
+}
+
+impl Deref for BytesMutPacker {
+    type Target = BytesMut;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Packer for BytesMutPacker {
+    type Error = Infallible;
+
+    #[inline]
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.0.extend_from_slice(bytes.as_ref());
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
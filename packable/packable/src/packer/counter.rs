@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::packer::Packer;
+use crate::{endian::ByteOrder, packer::Packer};
 
 /// A [`Packer`] able to keep count of the number of written bytes.
 pub struct CounterPacker<P: Packer> {
@@ -35,6 +35,8 @@ impl<P: Packer> CounterPacker<P> {
 impl<P: Packer> Packer for CounterPacker<P> {
     type Error = P::Error;
 
+    const BYTE_ORDER: ByteOrder = P::BYTE_ORDER;
+
     fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
         let bytes = bytes.as_ref();
         let len = bytes.len();
@@ -0,0 +1,59 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::{convert::Infallible, ops::Deref};
+
+use crate::packer::Packer;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// A [`Packer`] that renders the packed bytes as a lowercase hexadecimal [`String`].
+///
+/// This is mostly useful to produce human-readable fixtures when testing or debugging binary formats.
+#[derive(Default)]
+pub struct HexPacker(String);
+
+impl HexPacker {
+    /// Creates a new, empty [`HexPacker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes the value to return the hexadecimal [`String`].
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for HexPacker {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Packer for HexPacker {
+    type Error = Infallible;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_ref();
+
+        self.0.reserve(bytes.len() * 2);
+
+        for byte in bytes {
+            self.0.push(HEX_CHARS[(byte >> 4) as usize] as char);
+            self.0.push(HEX_CHARS[(byte & 0x0f) as usize] as char);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.0.len() / 2)
+    }
+}
@@ -11,17 +11,20 @@ use std::{
 use crate::packer::Packer;
 
 /// A [`Packer`] backed by [`Write`].
-pub struct IoPacker<W: Write>(W);
+pub struct IoPacker<W: Write> {
+    writer: W,
+    written: usize,
+}
 
 impl<W: Write> IoPacker<W> {
     /// Creates a new [`Packer`] from a value that implements [`Write`].
     pub fn new(writer: W) -> Self {
-        Self(writer)
+        Self { writer, written: 0 }
     }
 
     /// Consumes the value to return the inner value that implements [`Write`].
     pub fn into_inner(self) -> W {
-        self.0
+        self.writer
     }
 }
 
@@ -29,7 +32,7 @@ impl<W: Write> Deref for IoPacker<W> {
     type Target = W;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.writer
     }
 }
 
@@ -38,6 +41,16 @@ impl<W: Write> Packer for IoPacker<W> {
 
     #[inline]
     fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
-        self.0.write_all(bytes.as_ref())
+        let bytes = bytes.as_ref();
+
+        self.writer.write_all(bytes)?;
+        self.written += bytes.len();
+
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.written)
     }
 }
@@ -3,8 +3,9 @@
 
 use crate::packer::Packer;
 
+/// A [`Packer`] that only counts the bytes written into it.
 #[repr(transparent)]
-pub(crate) struct LenPacker(pub(crate) usize);
+pub struct LenPacker(pub usize);
 
 impl Packer for LenPacker {
     type Error = core::convert::Infallible;
@@ -15,4 +16,9 @@ impl Packer for LenPacker {
 
         Ok(())
     }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.0)
+    }
 }
@@ -0,0 +1,65 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{endian::ByteOrder, error::LimitedPackerError, packer::Packer};
+
+/// A [`Packer`] that fails once the total number of written bytes would exceed a given limit.
+///
+/// The limit is checked before writing into the inner [`Packer`], so the bytes that would cross it are never written.
+pub struct LimitedPacker<P: Packer> {
+    inner: P,
+    limit: usize,
+    written: usize,
+}
+
+impl<P: Packer> LimitedPacker<P> {
+    /// Creates a new [`LimitedPacker`] that can write at most `limit` bytes into `packer`.
+    #[inline]
+    pub fn new(packer: P, limit: usize) -> Self {
+        Self {
+            inner: packer,
+            limit,
+            written: 0,
+        }
+    }
+
+    /// Returns the maximum number of bytes that can be written.
+    #[inline]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Consumes the value to return the inner [`Packer`].
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: Packer> Packer for LimitedPacker<P> {
+    type Error = LimitedPackerError<P::Error>;
+
+    const BYTE_ORDER: ByteOrder = P::BYTE_ORDER;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_ref();
+        let required = self.written.saturating_add(bytes.len());
+
+        if required > self.limit {
+            return Err(LimitedPackerError::SizeLimitExceeded {
+                limit: self.limit,
+                required,
+            });
+        }
+
+        self.inner.pack_bytes(bytes).map_err(LimitedPackerError::Packer)?;
+        self.written = required;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.written)
+    }
+}
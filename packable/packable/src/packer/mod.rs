@@ -6,38 +6,105 @@
 //! The [`Packer`] trait represents types that can be used to write bytes into it. It can be thought as a `no_std`
 //! friendly alternative to the [`Write`](std::io::Write) trait.
 
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "bytes")]
+mod bytes;
 mod counter;
+#[cfg(feature = "alloc")]
+mod hex;
 #[cfg(feature = "io")]
 mod io;
-mod len;
+pub(crate) mod len;
+mod limited;
 mod slice;
+#[cfg(feature = "tokio")]
+mod tokio;
+#[cfg(feature = "alloc")]
 mod vec;
 
+#[cfg(feature = "base64")]
+pub use self::base64::Base64Packer;
+#[cfg(feature = "bytes")]
+pub use self::bytes::BytesMutPacker;
 pub use counter::CounterPacker;
+#[cfg(feature = "alloc")]
+pub use hex::HexPacker;
 #[cfg(feature = "io")]
 pub use io::IoPacker;
 pub(crate) use len::LenPacker;
+pub use limited::LimitedPacker;
 pub use slice::SlicePacker;
+#[cfg(feature = "tokio")]
+pub use self::tokio::AsyncPacker;
+
+use crate::{endian::ByteOrder, error::PackPrefixedBytesError, packable::bounded::Bounded, Packable};
 
 /// A type that can pack any value that implements [`Packable`](crate::Packable).
 pub trait Packer {
     /// An error type representing any error related to writing bytes.
     type Error;
 
+    /// The byte order of the numeric values written into the [`Packer`].
+    ///
+    /// The [`Packable`] implementations of this crate always pack numeric values in little-endian order, unless they
+    /// are wrapped in [`Be`](crate::endian::Be). Hand-written implementations can branch on this constant to follow
+    /// the byte order of packers that write another format.
+    const BYTE_ORDER: ByteOrder = ByteOrder::Little;
+
     /// Writes a sequence of bytes into the [`Packer`]. The totality of `bytes` must be written into the packer.
     /// This method **must** fail if the packer does not have enough space to fulfill the request.
     fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error>;
 
-    /// Returns the exact number of written bytes if possible.
+    /// Returns the exact number of bytes written into the [`Packer`] so far if possible.
+    ///
+    /// The packers of this crate keep track of it, e.g. to enforce a maximum message size while packing. Other packers
+    /// may return `None`, in which case they can be wrapped in a [`CounterPacker`] to count their written bytes.
     #[inline]
     fn written_bytes(&self) -> Option<usize> {
         None
     }
+
+    /// Writes a sequence of bytes prefixed by its length as a [`u64`], the same way a `Vec<u8>` is packed.
+    #[inline]
+    fn pack_prefixed_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (bytes.len() as u64).pack(self)?;
+
+        self.pack_bytes(bytes)
+    }
+
+    /// Writes a sequence of bytes prefixed by its length as a `B`, the same way a
+    /// [`VecPrefix<u8, B>`](crate::prefix::VecPrefix) is packed.
+    ///
+    /// Nothing is written if the length of `bytes` is out of the bounds of `B`, and a
+    /// [`PackPrefixedBytesError::Prefix`] is returned instead.
+    #[inline]
+    fn pack_prefixed_bytes_with<B>(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(), PackPrefixedBytesError<<B as TryFrom<usize>>::Error, Self::Error>>
+    where
+        Self: Sized,
+        B: Bounded + Packable,
+    {
+        B::try_from(bytes.len())
+            .map_err(PackPrefixedBytesError::Prefix)?
+            .pack(self)
+            .map_err(PackPrefixedBytesError::Packer)?;
+
+        self.pack_bytes(bytes).map_err(PackPrefixedBytesError::Packer)
+    }
 }
 
 impl<P: Packer + ?Sized> Packer for &mut P {
     type Error = P::Error;
 
+    const BYTE_ORDER: ByteOrder = P::BYTE_ORDER;
+
     #[inline]
     fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
         P::pack_bytes(*self, bytes)
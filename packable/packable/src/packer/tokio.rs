@@ -0,0 +1,78 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate std;
+
+use std::{
+    io::{self, ErrorKind},
+    ops::Deref,
+    vec::Vec,
+};
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::Packable;
+
+/// Packs values into a writer implementing [`AsyncWrite`].
+///
+/// As [`Packable`] is synchronous, this is not a [`Packer`](crate::packer::Packer): every value is packed into an
+/// internal buffer first, which is then written with a single call, so that no write blocks the executor.
+pub struct AsyncPacker<W: AsyncWrite + Unpin> {
+    writer: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncPacker<W> {
+    /// Creates a new [`AsyncPacker`] from a value that implements [`AsyncWrite`].
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Consumes the value to return the inner value that implements [`AsyncWrite`].
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Packs a value and writes its bytes.
+    pub async fn pack<P: Packable>(&mut self, value: &P) -> io::Result<()> {
+        self.buffer.clear();
+        // Packing to a `Vec<u8>` cannot fail.
+        value.pack(&mut self.buffer).unwrap();
+
+        self.writer.write_all(&self.buffer).await
+    }
+
+    /// Packs a value and writes its bytes as a frame, prefixed by their length as a [`u32`].
+    ///
+    /// Frames are read back with [`AsyncUnpacker::unpack_frame`](crate::unpacker::AsyncUnpacker::unpack_frame). An
+    /// error of kind [`ErrorKind::InvalidInput`] is returned if the packed value is longer than [`u32::MAX`] bytes.
+    pub async fn pack_frame<P: Packable>(&mut self, value: &P) -> io::Result<()> {
+        self.buffer.clear();
+        // The length is packed once the value has been, to know it.
+        self.buffer.extend_from_slice(&[0; 4]);
+        // Packing to a `Vec<u8>` cannot fail.
+        value.pack(&mut self.buffer).unwrap();
+
+        let len = u32::try_from(self.buffer.len() - 4)
+            .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "the packed value is too long for a frame"))?;
+        self.buffer[..4].copy_from_slice(&len.to_le_bytes());
+
+        self.writer.write_all(&self.buffer).await
+    }
+
+    /// Flushes the inner writer.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Deref for AsyncPacker<W> {
+    type Target = W;
+
+    fn deref(&self) -> &Self::Target {
+        &self.writer
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A module that re-exports the most commonly used items of this crate.
+//!
+//! ```
+//! # use packable as packable_crate;
+//! use packable::prelude::*;
+//!
+//! #[derive(Packable)]
+//! pub struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! let bytes = Point { x: 1, y: -1 }.pack_to_vec();
+//! let point = Point::unpack_verified(bytes, &()).unwrap();
+//! ```
+
+pub use crate::{
+    Packable, PackableExt, PackedSize,
+    error::{UnexpectedEOF, UnknownTagError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+};
@@ -0,0 +1,77 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers to test [`Packable`] implementations.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{
+    Packable, PackableExt,
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::{SliceUnpacker, Unpacker},
+};
+
+/// Packs a value into a [`Vec<u8>`], unpacks it back doing syntactical checks and asserts that the unpacked value is
+/// equal to the original one and that every packed byte was consumed. The packed bytes are returned so they can be
+/// checked further.
+///
+/// ```
+/// use packable::test_util::assert_packable_roundtrip;
+///
+/// assert_eq!(assert_packable_roundtrip(&Some(42u16)), [1, 42, 0]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if any of the assertions fails or if the value cannot be unpacked.
+#[track_caller]
+pub fn assert_packable_roundtrip<T>(value: &T) -> Vec<u8>
+where
+    T: Packable + PartialEq + Debug,
+    T::UnpackError: Debug,
+{
+    let bytes = value.pack_to_vec();
+
+    assert_eq!(
+        value.packed_len(),
+        bytes.len(),
+        "`packed_len` does not match the number of packed bytes"
+    );
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    let unpacked = T::unpack::<_, true>(&mut unpacker, &T::UnpackVisitor::default()).unwrap();
+
+    assert_eq!(value, &unpacked);
+    assert_eq!(
+        unpacker.peek_u8().unwrap(),
+        None,
+        "the packed bytes were not consumed completely"
+    );
+
+    bytes
+}
+
+/// Unpacks a value from `bytes` doing syntactical checks, packs it again and returns whether the packed bytes are
+/// identical to `bytes`, i.e. whether `bytes` is the canonical encoding of the value.
+///
+/// Bytes left after the unpacked value make the encoding non-canonical. Types with several encodings for the same
+/// value, like a `bool` unpacked from any non-zero byte, must pack deterministically for this check to be meaningful.
+///
+/// ```
+/// use packable::test_util::repack_stable;
+///
+/// assert!(repack_stable::<bool>(&[1]).unwrap());
+/// assert!(!repack_stable::<bool>(&[2]).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// This function fails if the value cannot be unpacked from `bytes`.
+pub fn repack_stable<T: Packable>(bytes: &[u8]) -> Result<bool, UnpackError<T::UnpackError, UnexpectedEOF>> {
+    let value = T::unpack_verified(bytes, &T::UnpackVisitor::default())?;
+
+    Ok(value.pack_to_vec() == bytes)
+}
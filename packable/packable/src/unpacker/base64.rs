@@ -0,0 +1,121 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use base64::{
+    DecodeSliceError,
+    engine::{Engine, GeneralPurpose, general_purpose::STANDARD},
+};
+
+use crate::{
+    endian::ByteOrder,
+    error::{Base64Error, UnexpectedEOF},
+    unpacker::Unpacker,
+};
+
+/// An [`Unpacker`] that decodes base64 read from an inner [`Unpacker`].
+///
+/// The input is decoded in groups of four characters. A padded group marks the end of the encoded data and any
+/// characters after it are left in the inner unpacker. Without padding, the encoded data ends with the inner unpacker,
+/// and its last group may be shorter than four characters.
+pub struct Base64Unpacker<U: Unpacker> {
+    inner: U,
+    engine: GeneralPurpose,
+    decoded: [u8; 3],
+    start: usize,
+    end: usize,
+    finished: bool,
+}
+
+impl<U: Unpacker> Base64Unpacker<U> {
+    /// Creates a new [`Base64Unpacker`] using the standard base64 alphabet with padding.
+    pub fn new(inner: U) -> Self {
+        Self::with_engine(inner, STANDARD)
+    }
+
+    /// Creates a new [`Base64Unpacker`] using the given base64 engine, e.g. a URL-safe one.
+    pub fn with_engine(inner: U, engine: GeneralPurpose) -> Self {
+        Self {
+            inner,
+            engine,
+            decoded: [0; 3],
+            start: 0,
+            end: 0,
+            finished: false,
+        }
+    }
+
+    /// Consumes the value to return the inner [`Unpacker`].
+    pub fn into_inner(self) -> U {
+        self.inner
+    }
+
+    // Decodes the next group of characters if the buffer is empty. Returns `false` if the input is exhausted.
+    fn fill(&mut self) -> Result<bool, Base64Error<U::Error>> {
+        if self.start < self.end {
+            return Ok(true);
+        }
+
+        if self.finished || self.inner.peek_u8().map_err(Base64Error::Unpacker)?.is_none() {
+            self.finished = true;
+            return Ok(false);
+        }
+
+        let mut group = [0u8; 4];
+        let mut len = 0;
+
+        // The last group of an input without padding is shorter than four characters.
+        while len < group.len() && (len == 0 || self.inner.peek_u8().map_err(Base64Error::Unpacker)?.is_some()) {
+            self.inner
+                .unpack_bytes(&mut group[len..len + 1])
+                .map_err(Base64Error::Unpacker)?;
+            len += 1;
+        }
+
+        self.start = 0;
+        self.end = self
+            .engine
+            .decode_slice(&group[..len], &mut self.decoded)
+            .map_err(|err| match err {
+                DecodeSliceError::DecodeError(err) => Base64Error::Decode(err),
+                DecodeSliceError::OutputSliceTooSmall => unreachable!("a group never decodes to more than 3 bytes"),
+            })?;
+        self.finished = len < group.len() || group[3] == b'=';
+
+        Ok(self.start < self.end)
+    }
+}
+
+impl<U: Unpacker> Unpacker for Base64Unpacker<U> {
+    type Error = Base64Error<U::Error>;
+
+    const BYTE_ORDER: ByteOrder = U::BYTE_ORDER;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        let mut written = 0;
+
+        while written < bytes.len() {
+            if !self.fill()? {
+                return Err(Base64Error::UnexpectedEOF(UnexpectedEOF {
+                    required: bytes.len(),
+                    had: written,
+                }));
+            }
+
+            let len = (self.end - self.start).min(bytes.len() - written);
+            bytes[written..written + len].copy_from_slice(&self.decoded[self.start..self.start + len]);
+            self.start += len;
+            written += len;
+        }
+
+        Ok(())
+    }
+
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        Ok(if self.fill()? {
+            Some(self.decoded[self.start])
+        } else {
+            None
+        })
+    }
+}
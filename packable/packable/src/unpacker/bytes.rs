@@ -0,0 +1,75 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bytes::{Buf, Bytes};
+
+use crate::{error::UnexpectedEOF, unpacker::Unpacker};
+
+/// An [`Unpacker`] backed by a [`Bytes`] buffer.
+///
+/// Besides copying bytes out of the buffer, this unpacker can hand out [`Bytes`] slices that share the same
+/// underlying memory by using [`BytesUnpacker::unpack_shared`].
+pub struct BytesUnpacker {
+    bytes: Bytes,
+}
+
+impl BytesUnpacker {
+    /// Creates a new [`BytesUnpacker`] from a [`Bytes`] buffer.
+    pub fn new(bytes: Bytes) -> Self {
+        Self { bytes }
+    }
+
+    /// Reads the next `len` bytes as a [`Bytes`] value without copying them.
+    pub fn unpack_shared(&mut self, len: usize) -> Result<Bytes, UnexpectedEOF> {
+        self.ensure_bytes(len)?;
+        Ok(self.bytes.split_to(len))
+    }
+
+    /// Consumes the value to return the bytes that have not been read yet.
+    pub fn into_inner(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl Unpacker for BytesUnpacker {
+    type Error = UnexpectedEOF;
+
+    #[inline]
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+
+        self.ensure_bytes(bytes.len())?;
+        self.bytes.copy_to_slice(bytes);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        Ok(self.bytes.first().copied())
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.ensure_bytes(len)?;
+        self.bytes.advance(len);
+        Ok(())
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        if self.bytes.len() < len {
+            Err(UnexpectedEOF {
+                required: len,
+                had: self.bytes.len(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        Some(self.bytes.len())
+    }
+}
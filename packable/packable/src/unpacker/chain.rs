@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{error::UnexpectedEOF, unpacker::Unpacker};
+
+/// An [`Unpacker`] that reads from a sequence of non-contiguous `&[u8]` chunks as if they were a single slice.
+///
+/// Reads crossing the boundary between chunks are handled transparently, so the chunks never need to be
+/// concatenated.
+pub struct ChainUnpacker<'a, I: Iterator<Item = &'a [u8]>> {
+    current: &'a [u8],
+    chunks: I,
+}
+
+impl<'a, I: Iterator<Item = &'a [u8]>> ChainUnpacker<'a, I> {
+    /// Creates a new [`ChainUnpacker`] from a sequence of chunks.
+    pub fn new<C: IntoIterator<IntoIter = I>>(chunks: C) -> Self {
+        Self {
+            current: &[],
+            chunks: chunks.into_iter(),
+        }
+    }
+
+    // Moves to the next non-empty chunk if the current one is exhausted. Returns `false` if there are no bytes left.
+    fn advance(&mut self) -> bool {
+        while self.current.is_empty() {
+            match self.chunks.next() {
+                Some(chunk) => self.current = chunk,
+                None => return false,
+            }
+        }
+
+        true
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a [u8]>> Unpacker for ChainUnpacker<'a, I> {
+    type Error = UnexpectedEOF;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        let mut written = 0;
+
+        while written < bytes.len() {
+            if !self.advance() {
+                return Err(UnexpectedEOF {
+                    required: bytes.len(),
+                    had: written,
+                });
+            }
+
+            let len = self.current.len().min(bytes.len() - written);
+            let (head, tail) = self.current.split_at(len);
+            bytes[written..written + len].copy_from_slice(head);
+            self.current = tail;
+            written += len;
+        }
+
+        Ok(())
+    }
+
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        Ok(if self.advance() {
+            self.current.first().copied()
+        } else {
+            None
+        })
+    }
+
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        let mut skipped = 0;
+
+        while skipped < len {
+            if !self.advance() {
+                return Err(UnexpectedEOF {
+                    required: len,
+                    had: skipped,
+                });
+            }
+
+            let chunk_len = self.current.len().min(len - skipped);
+            self.current = &self.current[chunk_len..];
+            skipped += chunk_len;
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::unpacker::Unpacker;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+use crate::{endian::ByteOrder, unpacker::Unpacker};
 
 /// An [`Unpacker`] able to keep count of the number of read bytes.
 pub struct CounterUnpacker<U: Unpacker> {
@@ -35,6 +41,8 @@ impl<U: Unpacker> CounterUnpacker<U> {
 impl<U: Unpacker> Unpacker for CounterUnpacker<U> {
     type Error = U::Error;
 
+    const BYTE_ORDER: ByteOrder = U::BYTE_ORDER;
+
     fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
         let bytes = bytes.as_mut();
         let len = bytes.len();
@@ -45,6 +53,26 @@ impl<U: Unpacker> Unpacker for CounterUnpacker<U> {
         Ok(())
     }
 
+    #[inline]
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        self.inner.peek_u8()
+    }
+
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.skip_bytes(len)?;
+        self.counter += len;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn unpack_slice(&mut self, len: usize) -> Result<Cow<'_, [u8]>, Self::Error> {
+        let bytes = self.inner.unpack_slice(len)?;
+        self.counter += len;
+
+        Ok(bytes)
+    }
+
     #[inline]
     fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
         self.inner.ensure_bytes(len)
@@ -54,4 +82,9 @@ impl<U: Unpacker> Unpacker for CounterUnpacker<U> {
     fn read_bytes(&self) -> Option<usize> {
         Some(self.counter)
     }
+
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        self.inner.remaining_bytes()
+    }
 }
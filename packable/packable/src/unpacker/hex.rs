@@ -0,0 +1,92 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    error::{HexError, UnexpectedEOF},
+    unpacker::Unpacker,
+};
+
+/// An [`Unpacker`] backed by a hexadecimal `&str`.
+///
+/// Both lowercase and uppercase digits are accepted. Unpacking fails if the string has an odd length or if it
+/// contains non-hexadecimal characters.
+pub struct HexUnpacker<'a> {
+    hex: &'a str,
+    offset: usize,
+}
+
+impl<'a> HexUnpacker<'a> {
+    /// Creates a new [`HexUnpacker`] from a `&str`.
+    pub fn new(hex: &'a str) -> Self {
+        Self { hex, offset: 0 }
+    }
+
+    fn remaining(&self) -> Result<&'a [u8], HexError> {
+        let remaining = &self.hex.as_bytes()[self.offset..];
+
+        if remaining.len() % 2 == 1 {
+            return Err(HexError::OddLength);
+        }
+
+        Ok(remaining)
+    }
+
+    fn decode_digit(&self, index: usize) -> Result<u8, HexError> {
+        let index = self.offset + index;
+
+        match self.hex.as_bytes()[index] {
+            digit @ b'0'..=b'9' => Ok(digit - b'0'),
+            digit @ b'a'..=b'f' => Ok(digit - b'a' + 10),
+            digit @ b'A'..=b'F' => Ok(digit - b'A' + 10),
+            _ => Err(HexError::InvalidCharacter {
+                // The index might not be at a char boundary if the string contains multi-byte characters.
+                c: self.hex.get(index..).and_then(|s| s.chars().next()).unwrap_or(char::REPLACEMENT_CHARACTER),
+                index,
+            }),
+        }
+    }
+
+    fn decode_byte(&self, index: usize) -> Result<u8, HexError> {
+        Ok(self.decode_digit(2 * index)? << 4 | self.decode_digit(2 * index + 1)?)
+    }
+}
+
+impl<'u> Unpacker for HexUnpacker<'u> {
+    type Error = HexError;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+
+        self.ensure_bytes(bytes.len())?;
+
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = self.decode_byte(index)?;
+        }
+
+        self.offset += 2 * bytes.len();
+
+        Ok(())
+    }
+
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        if self.remaining()?.is_empty() {
+            Ok(None)
+        } else {
+            self.decode_byte(0).map(Some)
+        }
+    }
+
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        let had = self.remaining()?.len() / 2;
+
+        if had < len {
+            Err(HexError::UnexpectedEOF(UnexpectedEOF { required: len, had }))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn remaining_bytes(&self) -> Option<usize> {
+        Some((self.hex.len() - self.offset) / 2)
+    }
+}
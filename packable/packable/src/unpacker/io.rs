@@ -4,24 +4,30 @@
 extern crate std;
 
 use std::{
-    io::{self, Read},
+    io::{self, ErrorKind, Read},
     ops::Deref,
 };
 
 use crate::unpacker::Unpacker;
 
 /// An [`Unpacker`] backed by [`Read`].
-pub struct IoUnpacker<R: Read>(R);
+pub struct IoUnpacker<R: Read> {
+    reader: R,
+    // A byte read from `reader` by `peek_u8` that has not been unpacked yet.
+    peeked: Option<u8>,
+}
 
 impl<R: Read> IoUnpacker<R> {
     /// Creates a new [`Unpacker`] from a value that implements [`Read`].
     pub fn new(reader: R) -> Self {
-        Self(reader)
+        Self { reader, peeked: None }
     }
 
     /// Consumes the value to return the inner value that implements [`Read`].
+    ///
+    /// A byte that has been peeked but not unpacked yet is lost.
     pub fn into_inner(self) -> R {
-        self.0
+        self.reader
     }
 }
 
@@ -29,7 +35,7 @@ impl<R: Read> Deref for IoUnpacker<R> {
     type Target = R;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.reader
     }
 }
 
@@ -38,6 +44,34 @@ impl<R: Read> Unpacker for IoUnpacker<R> {
 
     #[inline]
     fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
-        self.0.read_exact(bytes.as_mut())
+        let bytes = bytes.as_mut();
+
+        match (self.peeked, bytes.split_first_mut()) {
+            (Some(peeked), Some((first, tail))) => {
+                *first = peeked;
+                self.peeked = None;
+                self.reader.read_exact(tail)
+            }
+            _ => self.reader.read_exact(bytes),
+        }
+    }
+
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        if self.peeked.is_none() {
+            let mut byte = [0u8; 1];
+
+            loop {
+                match self.reader.read(&mut byte) {
+                    Ok(0) => return Ok(None),
+                    Ok(_) => break,
+                    Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            self.peeked = Some(byte[0]);
+        }
+
+        Ok(self.peeked)
     }
 }
@@ -0,0 +1,129 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+use crate::{endian::ByteOrder, error::LimitedUnpackerError, unpacker::Unpacker};
+
+/// An [`Unpacker`] that fails once the total number of read bytes would exceed a given limit.
+///
+/// The limit is checked before reading from the inner [`Unpacker`], so the bytes that would cross it are never
+/// consumed.
+pub struct LimitedUnpacker<U: Unpacker> {
+    inner: U,
+    limit: usize,
+    read: usize,
+}
+
+impl<U: Unpacker> LimitedUnpacker<U> {
+    /// Creates a new [`LimitedUnpacker`] that can read at most `limit` bytes from `unpacker`.
+    #[inline]
+    pub fn new(unpacker: U, limit: usize) -> Self {
+        Self {
+            inner: unpacker,
+            limit,
+            read: 0,
+        }
+    }
+
+    /// Returns the maximum number of bytes that can be read.
+    #[inline]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the number of bytes that can still be read before reaching the limit.
+    ///
+    /// Unlike [`Unpacker::remaining_bytes`], this is only an upper bound: the inner [`Unpacker`] may hold fewer bytes.
+    #[inline]
+    pub fn remaining_budget(&self) -> usize {
+        self.limit - self.read
+    }
+
+    /// Consumes the value to return the inner [`Unpacker`].
+    #[inline]
+    pub fn into_inner(self) -> U {
+        self.inner
+    }
+
+    fn check_limit(&self, len: usize) -> Result<usize, LimitedUnpackerError<U::Error>> {
+        let required = self.read.saturating_add(len);
+
+        if required > self.limit {
+            Err(LimitedUnpackerError::SizeLimitExceeded {
+                limit: self.limit,
+                required,
+            })
+        } else {
+            Ok(required)
+        }
+    }
+}
+
+impl<U: Unpacker> Unpacker for LimitedUnpacker<U> {
+    type Error = LimitedUnpackerError<U::Error>;
+
+    const BYTE_ORDER: ByteOrder = U::BYTE_ORDER;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        let required = self.check_limit(bytes.len())?;
+
+        self.inner.unpack_bytes(bytes).map_err(LimitedUnpackerError::Unpacker)?;
+        self.read = required;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        if self.remaining_budget() == 0 {
+            return Ok(None);
+        }
+
+        self.inner.peek_u8().map_err(LimitedUnpackerError::Unpacker)
+    }
+
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        let required = self.check_limit(len)?;
+
+        self.inner.skip_bytes(len).map_err(LimitedUnpackerError::Unpacker)?;
+        self.read = required;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn unpack_slice(&mut self, len: usize) -> Result<Cow<'_, [u8]>, Self::Error> {
+        let required = self.check_limit(len)?;
+
+        let bytes = self.inner.unpack_slice(len).map_err(LimitedUnpackerError::Unpacker)?;
+        self.read = required;
+
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        self.check_limit(len)?;
+        self.inner.ensure_bytes(len).map_err(LimitedUnpackerError::Unpacker)
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.read)
+    }
+
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        // The budget is only an upper bound of the bytes that can be read, so it is not returned when the inner
+        // unpacker does not know its remaining bytes.
+        self.inner
+            .remaining_bytes()
+            .map(|remaining| remaining.min(self.remaining_budget()))
+    }
+}
@@ -6,25 +6,140 @@
 //! The [`Unpacker`] trait represents types that can be used to read bytes from it. It can be thought as a `no_std`
 //! friendly alternative to the [`Read`](std::io::Read) trait.
 
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod chain;
 mod counter;
+mod hex;
 #[cfg(feature = "io")]
 mod io;
+mod limited;
 mod slice;
+#[cfg(feature = "tokio")]
+mod tokio;
 
+#[cfg(feature = "base64")]
+pub use self::base64::Base64Unpacker;
+#[cfg(feature = "bytes")]
+pub use self::bytes::BytesUnpacker;
+pub use chain::ChainUnpacker;
 pub use counter::CounterUnpacker;
+pub use hex::HexUnpacker;
 #[cfg(feature = "io")]
 pub use io::IoUnpacker;
+pub use limited::LimitedUnpacker;
 pub use slice::SliceUnpacker;
+#[cfg(feature = "tokio")]
+pub use self::tokio::AsyncUnpacker;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::{convert::Infallible, num::TryFromIntError};
+
+use crate::{endian::ByteOrder, error::ElementCountError, PackedSize};
+#[cfg(feature = "alloc")]
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packable::bounded::Bounded,
+    prefix::UnpackPrefixError,
+    Packable,
+};
+
+/// Error type raised when [`Unpacker::unpack_prefixed_bytes`] or [`Unpacker::unpack_prefixed_bytes_with`] fails,
+/// where `P` is the error raised while unpacking the prefix and `E` the error of the unpacker.
+#[cfg(feature = "alloc")]
+pub type UnpackPrefixedBytesError<P, E> = UnpackError<UnpackPrefixError<Infallible, P>, E>;
+
+/// The number of bytes read at once into a buffer whose length cannot be checked against the remaining bytes.
+#[cfg(feature = "alloc")]
+const UNCHECKED_CHUNK_LEN: usize = 4096;
+
+/// Reads `len` bytes from an [`Unpacker`] into an owned buffer.
+///
+/// The buffer is only allocated upfront if the remaining bytes of the unpacker are known to hold it. Otherwise, e.g.
+/// for stream unpackers, it grows as the bytes are read, so that a hostile length fails with the error of the unpacker
+/// instead of allocating more memory than there are bytes to read.
+#[cfg(feature = "alloc")]
+fn unpack_vec<U: Unpacker>(unpacker: &mut U, len: usize) -> Result<Vec<u8>, U::Error> {
+    unpacker.ensure_bytes(len)?;
+
+    if unpacker.remaining_bytes().is_some_and(|remaining| len <= remaining) {
+        let mut bytes = vec![0u8; len];
+        unpacker.unpack_bytes(&mut bytes)?;
+
+        return Ok(bytes);
+    }
+
+    let mut bytes = Vec::new();
+
+    while bytes.len() < len {
+        let start = bytes.len();
+        bytes.resize(start + (len - start).min(UNCHECKED_CHUNK_LEN), 0);
+        unpacker.unpack_bytes(&mut bytes[start..])?;
+    }
+
+    Ok(bytes)
+}
 
 /// A type that can unpack any value that implements [`Packable`](crate::Packable).
 pub trait Unpacker: Sized {
     /// An error type representing any error related to reading bytes.
     type Error;
 
+    /// The byte order of the numeric values read from the [`Unpacker`].
+    ///
+    /// The [`Packable`](crate::Packable) implementations of this crate always unpack numeric values in little-endian
+    /// order, unless they are wrapped in [`Be`](crate::endian::Be). Hand-written implementations can branch on this
+    /// constant to follow the byte order of unpackers that read another format.
+    const BYTE_ORDER: ByteOrder = ByteOrder::Little;
+
     /// Reads a sequence of bytes from the [`Unpacker`]. This sequence must be long enough to fill `bytes` completely.
     /// This method **must** fail if the unpacker does not have enough bytes to fulfill the request.
     fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error>;
 
+    /// Returns the next byte of the [`Unpacker`] without consuming it or `None` if there are no bytes left.
+    ///
+    /// Successive calls to this method without reading any bytes in between must return the same value. There is no
+    /// default implementation, as [`Unpacker::unpack_bytes`] cannot be used without consuming the byte.
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error>;
+
+    /// Consumes `len` bytes from the [`Unpacker`] without returning them.
+    ///
+    /// This method **must** fail if the unpacker does not have enough bytes to fulfill the request. The default
+    /// implementation reads the bytes into a temporary buffer, unpackers that can do better should override it.
+    fn skip_bytes(&mut self, mut len: usize) -> Result<(), Self::Error> {
+        let mut buffer = [0u8; 64];
+
+        while len > 0 {
+            let chunk = len.min(buffer.len());
+            self.unpack_bytes(&mut buffer[..chunk])?;
+            len -= chunk;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the next `len` bytes as a slice and advances the [`Unpacker`] past them.
+    ///
+    /// This method **must** fail if the unpacker does not have enough bytes to fulfill the request. Unpackers backed
+    /// by a slice, like [`SliceUnpacker`], return bytes borrowed from it without copying them. The default
+    /// implementation copies the bytes into an owned buffer, which is the best streaming unpackers can do.
+    ///
+    /// The bytes are returned as a [`Cow`] rather than a `&[u8]` because copied bytes have no buffer of the unpacker
+    /// to be borrowed from, which is also why this method requires the `alloc` feature. Borrowed bytes only live as
+    /// long as the borrow of the unpacker: values that keep borrowing the input bytes after unpacking them use
+    /// [`SliceUnpacker::unpack_borrowed`] instead, through [`PackableRef`](crate::PackableRef).
+    #[cfg(feature = "alloc")]
+    fn unpack_slice(&mut self, len: usize) -> Result<Cow<'_, [u8]>, Self::Error> {
+        unpack_vec(self, len).map(Cow::Owned)
+    }
+
     /// Tries to guarantee that the [`Unpacker`] has at least `len` bytes.
     ///
     /// This method **must** fail if and only if it is certain that there are not enough bytes and
@@ -39,16 +154,101 @@ pub trait Unpacker: Sized {
     fn read_bytes(&self) -> Option<usize> {
         None
     }
+
+    /// Returns the exact number of bytes left in the region backing the [`Unpacker`] if possible.
+    ///
+    /// Unpackers over a sized region, like a length-delimited frame, can use this to expose where the region ends.
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        None
+    }
+
+    /// Checks that `count` elements of type `T` fit in the bytes left in the region backing the [`Unpacker`].
+    ///
+    /// This can be used to validate a declared element count before allocating a sequence for it. The check always
+    /// succeeds if [`Unpacker::remaining_bytes`] is not known.
+    fn ensure_elements<T: PackedSize>(&self, count: usize) -> Result<(), ElementCountError> {
+        if let Some(remaining) = self.remaining_bytes() {
+            let fits = count
+                .checked_mul(T::PACKED_SIZE)
+                .is_some_and(|required| required <= remaining);
+
+            if !fits {
+                return Err(ElementCountError {
+                    count,
+                    element_size: T::PACKED_SIZE,
+                    remaining,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads a sequence of bytes prefixed by its length as a [`u64`], the same way a `Vec<u8>` is unpacked.
+    ///
+    /// The prefix is unpacked with the given `VERIFY` flag.
+    #[cfg(feature = "alloc")]
+    fn unpack_prefixed_bytes<const VERIFY: bool>(
+        &mut self,
+    ) -> Result<Vec<u8>, UnpackPrefixedBytesError<TryFromIntError, Self::Error>> {
+        let len = u64::unpack::<_, VERIFY>(self, &()).coerce()?;
+        let len = usize::try_from(len).map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
+
+        Ok(unpack_vec(self, len)?)
+    }
+
+    /// Reads a sequence of bytes prefixed by its length as a `B`, the same way a
+    /// [`VecPrefix<u8, B>`](crate::prefix::VecPrefix) is unpacked.
+    ///
+    /// The prefix is unpacked with the given `VERIFY` flag, which checks that it is in the bounds of `B`.
+    #[cfg(feature = "alloc")]
+    fn unpack_prefixed_bytes_with<B, const VERIFY: bool>(
+        &mut self,
+    ) -> Result<Vec<u8>, UnpackPrefixedBytesError<B::UnpackError, Self::Error>>
+    where
+        B: Bounded + Packable<UnpackVisitor = ()>,
+    {
+        let len = B::unpack::<_, VERIFY>(self, &())
+            .map_packable_err(UnpackPrefixError::Prefix)?
+            .into();
+
+        // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
+        let len = len
+            .try_into()
+            .ok()
+            .expect("the length prefix exceeds the pointer length of this platform");
+
+        Ok(unpack_vec(self, len)?)
+    }
 }
 
-impl<U: Unpacker + ?Sized> Unpacker for &mut U {
+impl<U: Unpacker> Unpacker for &mut U {
     type Error = U::Error;
 
+    const BYTE_ORDER: ByteOrder = U::BYTE_ORDER;
+
     #[inline]
     fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
         U::unpack_bytes(*self, bytes)
     }
 
+    #[inline]
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        U::peek_u8(*self)
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        U::skip_bytes(*self, len)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn unpack_slice(&mut self, len: usize) -> Result<Cow<'_, [u8]>, Self::Error> {
+        U::unpack_slice(*self, len)
+    }
+
     #[inline]
     fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
         U::ensure_bytes(*self, len)
@@ -58,4 +258,9 @@ impl<U: Unpacker + ?Sized> Unpacker for &mut U {
     fn read_bytes(&self) -> Option<usize> {
         U::read_bytes(*self)
     }
+
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        U::remaining_bytes(*self)
+    }
 }
@@ -1,6 +1,12 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
 use crate::{error::UnexpectedEOF, unpacker::Unpacker};
 
 /// A [`Unpacker`] backed by a `&mut [u8]`.
@@ -14,6 +20,15 @@ impl<'a> SliceUnpacker<'a> {
     pub fn new(slice: &'a [u8]) -> Self {
         Self { slice }
     }
+
+    /// Reads the next `len` bytes without copying them, returning a slice borrowed from the underlying `&[u8]`.
+    #[inline]
+    pub fn unpack_borrowed(&mut self, len: usize) -> Result<&'a [u8], UnexpectedEOF> {
+        self.ensure_bytes(len)?;
+        let (head, tail) = self.slice.split_at(len);
+        self.slice = tail;
+        Ok(head)
+    }
 }
 
 impl<'u> Unpacker for SliceUnpacker<'u> {
@@ -37,6 +52,24 @@ impl<'u> Unpacker for SliceUnpacker<'u> {
         }
     }
 
+    #[inline]
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        Ok(self.slice.first().copied())
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.ensure_bytes(len)?;
+        self.slice = &self.slice[len..];
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn unpack_slice(&mut self, len: usize) -> Result<Cow<'_, [u8]>, Self::Error> {
+        self.unpack_borrowed(len).map(Cow::Borrowed)
+    }
+
     #[inline]
     fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
         if self.slice.len() < len {
@@ -48,4 +81,9 @@ impl<'u> Unpacker for SliceUnpacker<'u> {
             Ok(())
         }
     }
+
+    #[inline]
+    fn remaining_bytes(&self) -> Option<usize> {
+        Some(self.slice.len())
+    }
 }
@@ -0,0 +1,94 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate std;
+
+use std::{io, ops::Deref, vec::Vec};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{
+    Packable, PackedSize,
+    error::{UnexpectedEOF, UnpackError},
+    frame::{check_frame_len, eof_error, unpack_frame},
+};
+
+/// Unpacks values from a reader implementing [`AsyncRead`].
+///
+/// As [`Packable`] is synchronous, this is not an [`Unpacker`](crate::unpacker::Unpacker): the bytes of every value
+/// are read into an internal buffer first, from which the value is then unpacked, so that no read blocks the executor.
+/// This requires knowing how many bytes to read beforehand, so values are either read as length-prefixed frames or
+/// have a fixed [`PackedSize`].
+///
+/// Values are always unpacked doing syntactical checks, and the errors of their bytes are reported as [`io::Error`]s,
+/// like those of the reader: an [`UnexpectedEOF`] is of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof), and a
+/// [`TrailingBytesError`](crate::error::TrailingBytesError) of kind [`InvalidData`](io::ErrorKind::InvalidData).
+pub struct AsyncUnpacker<R: AsyncRead + Unpin> {
+    reader: R,
+    buffer: Vec<u8>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncUnpacker<R> {
+    /// Creates a new [`AsyncUnpacker`] from a value that implements [`AsyncRead`].
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Consumes the value to return the inner value that implements [`AsyncRead`].
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the [`PackedSize::PACKED_SIZE`] bytes of a value and unpacks it.
+    pub async fn unpack_sized<P: Packable + PackedSize>(
+        &mut self,
+        visitor: &P::UnpackVisitor,
+    ) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+        self.buffer.resize(P::PACKED_SIZE, 0);
+        self.reader.read_exact(&mut self.buffer).await?;
+
+        unpack_frame(&self.buffer, visitor)
+    }
+
+    /// Reads a frame, prefixed by its length as a [`u32`], and unpacks a value from it.
+    ///
+    /// Frames are written with [`AsyncPacker::pack_frame`](crate::packer::AsyncPacker::pack_frame). The value must
+    /// consume the whole frame. Like [`read_frame`](crate::read_frame), an error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) is returned without reading the frame if its length is larger than
+    /// `max_len`. Below that, the buffer grows as the bytes of the frame are read, so a length announcing more bytes
+    /// than the reader provides does not allocate them upfront.
+    pub async fn unpack_frame<P: Packable>(
+        &mut self,
+        max_len: usize,
+        visitor: &P::UnpackVisitor,
+    ) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+        let len = self.reader.read_u32_le().await? as usize;
+        check_frame_len(len, max_len)?;
+
+        self.buffer.clear();
+        (&mut self.reader)
+            .take(len as u64)
+            .read_to_end(&mut self.buffer)
+            .await?;
+
+        if self.buffer.len() < len {
+            return Err(UnpackError::Unpacker(eof_error(UnexpectedEOF {
+                required: len,
+                had: self.buffer.len(),
+            })));
+        }
+
+        unpack_frame(&self.buffer, visitor)
+    }
+}
+
+impl<R: AsyncRead + Unpin> Deref for AsyncUnpacker<R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.reader
+    }
+}
@@ -3,6 +3,12 @@
 
 mod common;
 
+use packable::{
+    error::{ArrayUnpackError, UnpackError},
+    option::UnpackOptionError,
+    PackableExt,
+};
+
 #[test]
 fn packable_array() {
     assert_eq!(
@@ -10,3 +16,16 @@ fn packable_array() {
         1024 * core::mem::size_of::<u8>()
     );
 }
+
+#[test]
+fn invalid_array_element_index() {
+    let bytes = [0u8, 1, 0, 0, 0, 0, 2];
+
+    assert!(matches!(
+        <[Option<u32>; 3]>::unpack_verified(bytes.as_slice(), &()),
+        Err(UnpackError::Packable(ArrayUnpackError {
+            index: 2,
+            inner: UnpackOptionError::UnknownTag(2)
+        }))
+    ));
+}
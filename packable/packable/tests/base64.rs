@@ -0,0 +1,76 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "base64")]
+
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+use packable::{
+    Packable,
+    error::{Base64Error, UnpackError},
+    packer::{Base64Packer, Packer},
+    unpacker::{Base64Unpacker, SliceUnpacker, Unpacker},
+};
+
+#[test]
+fn base64_round_trip() {
+    let value = (0x6F7BD423u32, [1u8, 2, 3, 4, 5, 6, 7]);
+
+    let mut packer = Base64Packer::new(Vec::new());
+    value.pack(&mut packer).unwrap();
+    let encoded = packer.into_string();
+    assert_eq!(encoded, "I9R7bwECAwQFBgc=");
+
+    let mut unpacker = Base64Unpacker::new(SliceUnpacker::new(encoded.as_bytes()));
+    let unpacked = <(u32, [u8; 7])>::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    assert_eq!(unpacked, value);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn base64_pack_without_padding() {
+    let mut packer = Base64Packer::new(Vec::new());
+    0x010203u32.pack(&mut packer).unwrap();
+    0x04u8.pack(&mut packer).unwrap();
+    0x05u8.pack(&mut packer).unwrap();
+
+    assert_eq!(packer.into_string(), "AwIBAAQF");
+}
+
+#[test]
+fn base64_unpack_invalid_character() {
+    let mut unpacker = Base64Unpacker::new(SliceUnpacker::new(b"AA*A"));
+
+    assert!(matches!(
+        u8::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(Base64Error::Decode(_)))
+    ));
+}
+
+#[test]
+fn base64_unpack_not_enough_bytes() {
+    let mut unpacker = Base64Unpacker::new(SliceUnpacker::new(b"AQI="));
+
+    assert!(matches!(
+        u32::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(Base64Error::UnexpectedEOF(_)))
+    ));
+}
+
+#[test]
+fn base64_round_trip_engines() {
+    for engine in [STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD] {
+        for len in 0..=6 {
+            let value = &[0xFB, 0xFF, 0xBF, 0xFE, 0xEF, 0xFA][..len];
+
+            let mut packer = Base64Packer::with_engine(Vec::new(), engine.clone());
+            packer.pack_bytes(value).unwrap();
+            let encoded = packer.into_string();
+
+            let mut unpacker = Base64Unpacker::with_engine(SliceUnpacker::new(encoded.as_bytes()), engine.clone());
+            let mut unpacked = vec![0u8; len];
+            unpacker.unpack_bytes(&mut unpacked).unwrap();
+            assert_eq!(unpacked, value, "{encoded}");
+            assert_eq!(unpacker.peek_u8().unwrap(), None);
+        }
+    }
+}
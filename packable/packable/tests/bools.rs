@@ -0,0 +1,49 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::io::ErrorKind;
+
+use packable::{
+    Packable, PackableExt,
+    bools::{PackedBools, UnpackPackedBoolsError},
+    error::UnpackError,
+    unpacker::IoUnpacker,
+};
+
+#[test]
+fn packable_packed_bools() {
+    let bools = PackedBools(vec![true, false, true, true, false, false, false, false, false, true]);
+    let (bytes, _) = common::generic_test(&bools);
+
+    assert_eq!(&bytes[..8], 10u64.to_le_bytes());
+    assert_eq!(&bytes[8..], [0b0000_1101, 0b0000_0010]);
+
+    let (bytes, _) = common::generic_test(&PackedBools::default());
+    assert_eq!(bytes, 0u64.to_le_bytes());
+}
+
+#[test]
+fn invalid_packed_bools_padding() {
+    let mut bytes = 3u64.to_le_bytes().to_vec();
+    bytes.push(0b0000_1001);
+
+    assert!(matches!(
+        PackedBools::unpack_verified(&bytes, &()),
+        Err(UnpackError::Packable(UnpackPackedBoolsError::NonZeroPadding))
+    ));
+    assert_eq!(*PackedBools::unpack_unverified(&bytes).unwrap(), [true, false, false]);
+}
+
+#[test]
+fn packed_bools_huge_len_stream_unpacker() {
+    // A stream unpacker cannot check the declared length, which must not be allocated before the bytes are read.
+    let mut bytes = (1u64 << 45).to_le_bytes().to_vec();
+    bytes.push(0b0000_0001);
+
+    match PackedBools::unpack::<_, true>(&mut IoUnpacker::new(bytes.as_slice()), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Bound;
+
+use packable::{
+    PackableExt,
+    bound::UnpackBoundError,
+    error::{UnknownTagError, UnpackError},
+};
+
+mod common;
+
+#[test]
+fn packable_bound() {
+    assert_eq!(
+        common::generic_test(&Bound::<u32>::Unbounded).0.len(),
+        core::mem::size_of::<u8>()
+    );
+    assert_eq!(common::generic_test(&Bound::<u32>::Included(42)).0, [1, 42, 0, 0, 0]);
+    assert_eq!(common::generic_test(&Bound::<u32>::Excluded(42)).0, [2, 42, 0, 0, 0]);
+}
+
+#[test]
+fn invalid_bound_tag() {
+    assert!(matches!(
+        Bound::<u32>::unpack_verified([3u8, 42, 0, 0, 0].as_slice(), &()),
+        Err(UnpackError::Packable(UnpackBoundError::UnknownTag(UnknownTagError(3))))
+    ));
+}
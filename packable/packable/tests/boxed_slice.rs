@@ -1,6 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::io::ErrorKind;
+
+use packable::{
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::IoUnpacker,
+    Packable, PackableExt,
+};
+
 mod common;
 
 #[test]
@@ -12,3 +20,39 @@ fn packable_boxed_slice() {
             + core::mem::size_of::<u8>()
     );
 }
+
+#[test]
+fn packable_boxed_bytes() {
+    let bytes = vec![1u8, 2, 3];
+    let (packed, _) = common::generic_test(&bytes.clone().into_boxed_slice());
+
+    assert_eq!(packed, bytes.pack_to_vec());
+}
+
+#[test]
+fn invalid_boxed_bytes_len() {
+    // The length is checked against the remaining bytes before allocating.
+    let bytes = u64::MAX.to_le_bytes();
+
+    assert!(matches!(
+        Box::<[u8]>::unpack_verified(bytes, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { .. }))
+    ));
+}
+
+#[test]
+fn boxed_slice_huge_len_stream_unpacker() {
+    // A stream unpacker cannot check the declared length, which must not be allocated before the elements are read.
+    let mut bytes = (1u64 << 40).to_le_bytes().to_vec();
+    bytes.extend([1, 0, 0, 0]);
+
+    match Box::<[u32]>::unpack::<_, true>(&mut IoUnpacker::new(bytes.as_slice()), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+
+    match Box::<[Option<u32>]>::unpack::<_, true>(&mut IoUnpacker::new(bytes.as_slice()), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    error::{UnexpectedEOF, UnpackError},
+    ByteBuf, PackableExt,
+};
+
+#[test]
+fn packable_byte_buf() {
+    let bytes = vec![1u8, 2, 3];
+    let (packed, unpacked) = common::generic_test(&ByteBuf::from(bytes.clone()));
+
+    assert_eq!(packed, bytes.pack_to_vec());
+    assert_eq!(Vec::from(unpacked), bytes);
+}
+
+#[test]
+fn invalid_byte_buf_len() {
+    let mut bytes = Vec::from(4u64.to_le_bytes());
+    bytes.extend([1, 2, 3]);
+
+    assert!(matches!(
+        ByteBuf::unpack_verified(bytes, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { required: 4, had: 3 }))
+    ));
+}
@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "bytes")]
+
+use bytes::{Bytes, BytesMut};
+use packable::{
+    Packable,
+    packer::BytesMutPacker,
+    unpacker::{BytesUnpacker, Unpacker},
+};
+
+#[test]
+fn bytes_round_trip() {
+    let value = (0x6F7BD423u32, [1u8, 2, 3]);
+
+    let mut packer = BytesMutPacker::new(BytesMut::new());
+    value.pack(&mut packer).unwrap();
+
+    let bytes = packer.into_inner().freeze();
+    assert_eq!(bytes.as_ref(), [0x23, 0xD4, 0x7B, 0x6F, 1, 2, 3]);
+
+    let mut unpacker = BytesUnpacker::new(bytes);
+    let unpacked = <(u32, [u8; 3])>::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    assert_eq!(unpacked, value);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn bytes_unpack_shared() {
+    let bytes = Bytes::from_static(&[3, 0, 0, 0, 10, 20, 30, 40]);
+    let mut unpacker = BytesUnpacker::new(bytes.clone());
+
+    let len = u32::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    let shared = unpacker.unpack_shared(len as usize).unwrap();
+
+    assert_eq!(shared.as_ref(), [10, 20, 30]);
+    assert_eq!(shared.as_ptr(), bytes[4..].as_ptr());
+    assert_eq!(unpacker.unpack_shared(2).unwrap_err().had, 1);
+}
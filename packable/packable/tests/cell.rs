@@ -0,0 +1,24 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::cell::{Cell, RefCell};
+
+use packable::{PackableExt, PackedSize};
+
+#[test]
+fn packable_cell() {
+    let (packed, _) = common::generic_test(&Cell::new(42u32));
+
+    assert_eq!(packed, 42u32.pack_to_vec());
+    assert_eq!(Cell::<u32>::PACKED_SIZE, u32::PACKED_SIZE);
+}
+
+#[test]
+fn packable_ref_cell() {
+    let (packed, unpacked) = common::generic_test(&RefCell::new(Some([1u8, 2, 3])));
+
+    assert_eq!(packed, Some([1u8, 2, 3]).pack_to_vec());
+    assert_eq!(unpacked.into_inner(), Some([1, 2, 3]));
+}
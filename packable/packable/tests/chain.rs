@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    Packable,
+    error::UnpackError,
+    unpacker::{ChainUnpacker, Unpacker},
+};
+
+#[test]
+fn chain_unpack_across_chunks() {
+    let chunks: [&[u8]; 4] = [&[0x23, 0xD4], &[], &[0x7B], &[0x6F, 0x2A]];
+    let mut unpacker = ChainUnpacker::new(chunks);
+
+    assert_eq!(u32::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0x6F7BD423);
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x2A));
+    assert_eq!(u8::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0x2A);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn chain_skip_across_chunks() {
+    let chunks = [vec![1u8, 2], vec![3, 4, 5], vec![6]];
+    let mut unpacker = ChainUnpacker::new(chunks.iter().map(Vec::as_slice));
+
+    unpacker.skip_bytes(4).unwrap();
+    assert_eq!(u16::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0x0605);
+    assert_eq!(unpacker.skip_bytes(1).unwrap_err().had, 0);
+}
+
+#[test]
+fn chain_unpack_not_enough_bytes() {
+    let chunks: [&[u8]; 2] = [&[1], &[2]];
+    let mut unpacker = ChainUnpacker::new(chunks);
+
+    assert!(matches!(
+        u32::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(err)) if err.required == 4 && err.had == 2
+    ));
+}
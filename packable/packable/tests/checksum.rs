@@ -0,0 +1,57 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    PackableExt,
+    bools::{PackedBools, UnpackPackedBoolsError},
+    checksum::{ChecksumError, ChecksummedVec, crc32},
+    error::{ArrayUnpackError, UnpackError},
+    prefix::UnpackPrefixError,
+};
+
+#[test]
+fn packable_checksummed_vec() {
+    let vec = ChecksummedVec(vec![1u16, 2, 3]);
+    let (bytes, _) = common::generic_test(&vec);
+
+    assert_eq!(&bytes[..8], 3u64.to_le_bytes());
+    assert_eq!(&bytes[8..10], 1u16.to_le_bytes());
+    assert_eq!(&bytes[10..14], crc32(&1u16.to_le_bytes()).to_le_bytes());
+    assert_eq!(bytes.len(), 8 + 3 * (2 + 4));
+
+    let (bytes, _) = common::generic_test(&ChecksummedVec::<u16>::default());
+    assert_eq!(bytes, 0u64.to_le_bytes());
+}
+
+#[test]
+fn checksummed_vec_corrupted_element() {
+    let mut bytes = ChecksummedVec(vec![1u32, 2, 3]).pack_to_vec();
+    // Flip a bit of the third element.
+    bytes[8 + 2 * 8] ^= 1;
+
+    assert!(matches!(
+        ChecksummedVec::<u32>::unpack_verified(&bytes, &()),
+        Err(UnpackError::Packable(UnpackPrefixError::Item(ArrayUnpackError {
+            index: 2,
+            inner: ChecksumError::Mismatch { expected, computed },
+        }))) if expected == crc32(&3u32.to_le_bytes()) && computed == crc32(&2u32.to_le_bytes())
+    ));
+    assert_eq!(*ChecksummedVec::<u32>::unpack_unverified(&bytes).unwrap(), [1, 2, 2]);
+}
+
+#[test]
+fn checksummed_vec_invalid_element() {
+    let mut bytes = ChecksummedVec(vec![PackedBools(vec![true]), PackedBools(vec![true, false, true])]).pack_to_vec();
+    // Set a padding bit of the second element.
+    bytes[8 + (8 + 1 + 4) + 8] |= 0b1000_0000;
+
+    assert!(matches!(
+        ChecksummedVec::<PackedBools>::unpack_verified(&bytes, &()),
+        Err(UnpackError::Packable(UnpackPrefixError::Item(ArrayUnpackError {
+            index: 1,
+            inner: ChecksumError::Item(UnpackPackedBoolsError::NonZeroPadding),
+        })))
+    ));
+}
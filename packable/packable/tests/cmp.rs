@@ -0,0 +1,29 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cmp::{Ordering, Reverse};
+
+use packable::{
+    PackableExt, PackedSize,
+    error::{UnknownTagError, UnpackError},
+};
+
+mod common;
+
+#[test]
+fn packable_reverse() {
+    assert_eq!(common::generic_test(&Reverse(42u32)).0, [42, 0, 0, 0]);
+    assert_eq!(Reverse::<u32>::PACKED_SIZE, 4);
+}
+
+#[test]
+fn packable_ordering() {
+    assert_eq!(common::generic_test(&Ordering::Less).0, [0]);
+    assert_eq!(common::generic_test(&Ordering::Equal).0, [1]);
+    assert_eq!(common::generic_test(&Ordering::Greater).0, [2]);
+
+    assert!(matches!(
+        Ordering::unpack_verified([3u8], &()),
+        Err(UnpackError::Packable(UnknownTagError(3)))
+    ));
+}
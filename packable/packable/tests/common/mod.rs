@@ -4,7 +4,7 @@
 use core::fmt::Debug;
 
 use packable::{
-    packer::{IoPacker, SlicePacker},
+    packer::{IoPacker, Packer, SlicePacker},
     unpacker::IoUnpacker,
     Packable, PackableExt,
 };
@@ -59,6 +59,7 @@ where
 
     let mut packer = IoPacker::new(Vec::new());
     packable.pack(&mut packer).unwrap();
+    assert_eq!(packer.written_bytes(), Some(vec.len()));
     let mut unpacker = IoUnpacker::new(packer.as_slice());
     let unpacked = P::unpack::<_, true>(&mut unpacker, &()).unwrap();
     assert_eq!(packable, &unpacked);
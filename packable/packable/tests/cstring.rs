@@ -0,0 +1,46 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::ffi::CString;
+
+use packable::{
+    PackableExt,
+    error::{UnexpectedEOF, UnpackError},
+};
+
+#[test]
+fn packable_cstring() {
+    let (packed, _) = common::generic_test(&CString::new("packable").unwrap());
+
+    assert_eq!(packed, b"packable\0");
+}
+
+#[test]
+fn packable_cstring_empty() {
+    let (packed, _) = common::generic_test(&CString::default());
+
+    assert_eq!(packed, [0]);
+}
+
+#[test]
+fn unpack_cstring_stops_at_nul() {
+    let mut unpacker = packable::unpacker::SliceUnpacker::new(b"abc\0def\0");
+
+    let first = <CString as packable::Packable>::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    let second = <CString as packable::Packable>::unpack::<_, true>(&mut unpacker, &()).unwrap();
+
+    assert_eq!(first.as_bytes(), b"abc");
+    assert_eq!(second.as_bytes(), b"def");
+}
+
+#[test]
+fn unpack_cstring_missing_nul() {
+    assert!(matches!(
+        CString::unpack_verified(b"abc", &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { .. }))
+    ));
+}
@@ -0,0 +1,47 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    Packable,
+    error::ElementCountError,
+    unpacker::{IoUnpacker, SliceUnpacker, Unpacker},
+};
+
+#[test]
+fn ensure_elements_fit() {
+    let bytes = [2u8, 0, 1, 0, 2, 0];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    let count = u16::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    unpacker.ensure_elements::<u16>(count.into()).unwrap();
+}
+
+#[test]
+fn ensure_elements_mismatch() {
+    let bytes = [3u8, 0, 1, 0, 2, 0];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    let count = u16::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    assert!(matches!(
+        unpacker.ensure_elements::<u16>(count.into()),
+        Err(ElementCountError {
+            count: 3,
+            element_size: 2,
+            remaining: 4
+        })
+    ));
+}
+
+#[test]
+fn ensure_elements_overflow() {
+    let unpacker = SliceUnpacker::new(&[]);
+
+    assert!(unpacker.ensure_elements::<u64>(usize::MAX).is_err());
+}
+
+#[test]
+fn ensure_elements_unknown_remaining() {
+    let unpacker = IoUnpacker::new([0u8; 2].as_slice());
+
+    unpacker.ensure_elements::<u64>(usize::MAX).unwrap();
+}
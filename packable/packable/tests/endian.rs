@@ -0,0 +1,131 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::convert::Infallible;
+
+use packable::{
+    endian::{Be, ByteOrder, Le},
+    error::UnpackError,
+    packer::{CounterPacker, Packer},
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+#[test]
+fn packable_le() {
+    let (bytes, _) = common::generic_test(&Le(1u16));
+    assert_eq!(bytes, [0x01, 0x00]);
+    assert_eq!(Le(0x6F7BD423u32).pack_to_vec(), 0x6F7BD423u32.pack_to_vec());
+}
+
+#[test]
+fn packable_be() {
+    let (bytes, _) = common::generic_test(&Be(1u16));
+    assert_eq!(bytes, [0x00, 0x01]);
+
+    let (bytes, _) = common::generic_test(&Be(-2i32));
+    assert_eq!(bytes, (-2i32).to_be_bytes());
+
+    let (bytes, _) = common::generic_test(&Be(core::f64::consts::PI));
+    assert_eq!(bytes, core::f64::consts::PI.to_be_bytes());
+}
+
+#[test]
+fn packable_mixed_endianness() {
+    let value = (Be(8080u16), 8080u16);
+    let (bytes, _) = common::generic_test(&value);
+    assert_eq!(bytes, [0x1F, 0x90, 0x90, 0x1F]);
+}
+
+// A packer writing numeric values in big-endian order.
+struct BigEndianPacker(Vec<u8>);
+
+impl Packer for BigEndianPacker {
+    type Error = Infallible;
+
+    const BYTE_ORDER: ByteOrder = ByteOrder::Big;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.0.pack_bytes(bytes)
+    }
+}
+
+// A value packed in the byte order of its packer.
+#[derive(Debug, PartialEq)]
+struct Native(u16);
+
+impl Packable for Native {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        match P::BYTE_ORDER {
+            ByteOrder::Little => Le(self.0).pack(packer),
+            ByteOrder::Big => Be(self.0).pack(packer),
+        }
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match U::BYTE_ORDER {
+            ByteOrder::Little => Le::<u16>::unpack::<_, VERIFY>(unpacker, visitor).map(|value| Self(value.0)),
+            ByteOrder::Big => Be::<u16>::unpack::<_, VERIFY>(unpacker, visitor).map(|value| Self(value.0)),
+        }
+    }
+}
+
+#[test]
+fn byte_order_defaults_to_little() {
+    assert_eq!(<Vec<u8> as Packer>::BYTE_ORDER, ByteOrder::Little);
+    assert_eq!(<SliceUnpacker<'_> as Unpacker>::BYTE_ORDER, ByteOrder::Little);
+    assert_eq!(Native(0x0102).pack_to_vec(), [0x02, 0x01]);
+    assert_eq!(Native::unpack_verified([0x02, 0x01], &()).unwrap(), Native(0x0102));
+}
+
+#[test]
+fn byte_order_forwarded_by_wrappers() {
+    let mut packer = CounterPacker::new(BigEndianPacker(Vec::new()));
+    Native(0x0102).pack(&mut &mut packer).unwrap();
+
+    assert_eq!(<CounterPacker<BigEndianPacker> as Packer>::BYTE_ORDER, ByteOrder::Big);
+    assert_eq!(packer.into_inner().0, [0x01, 0x02]);
+}
+
+// An unpacker reading numeric values in big-endian order.
+#[cfg(feature = "base64")]
+struct BigEndianUnpacker<'a>(SliceUnpacker<'a>);
+
+#[cfg(feature = "base64")]
+impl Unpacker for BigEndianUnpacker<'_> {
+    type Error = packable::error::UnexpectedEOF;
+
+    const BYTE_ORDER: ByteOrder = ByteOrder::Big;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.0.unpack_bytes(bytes)
+    }
+
+    fn peek_u8(&mut self) -> Result<Option<u8>, Self::Error> {
+        self.0.peek_u8()
+    }
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn byte_order_forwarded_by_base64() {
+    use packable::{packer::Base64Packer, unpacker::Base64Unpacker};
+
+    let mut packer = Base64Packer::new(BigEndianPacker(Vec::new()));
+    Native(0x0102).pack(&mut packer).unwrap();
+    let encoded = packer.finish().unwrap().0;
+
+    // `AQI=` is the base64 encoding of `[0x01, 0x02]`.
+    assert_eq!(encoded, b"AQI=");
+
+    let mut unpacker = Base64Unpacker::new(BigEndianUnpacker(SliceUnpacker::new(&encoded)));
+    assert_eq!(Native::unpack::<_, true>(&mut unpacker, &()).unwrap(), Native(0x0102));
+}
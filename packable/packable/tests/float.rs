@@ -0,0 +1,43 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    error::UnpackError,
+    float::{CanonicalNan, Finite, NonFiniteError},
+    PackableExt,
+};
+
+#[test]
+fn packable_canonical_nan() {
+    let (bytes, _) = common::generic_test(&CanonicalNan(1.5f32));
+    assert_eq!(bytes, 1.5f32.to_le_bytes());
+
+    let nan = f32::from_bits(0x7FC0_0001);
+    assert_ne!(nan.pack_to_vec(), f32::NAN.pack_to_vec());
+    assert_eq!(CanonicalNan(nan).pack_to_vec(), f32::NAN.to_le_bytes());
+    assert_eq!(CanonicalNan(-f64::NAN).pack_to_vec(), f64::NAN.to_le_bytes());
+}
+
+#[test]
+fn packable_finite() {
+    let (bytes, _) = common::generic_test(&Finite::<f64>::new(2.5).unwrap());
+    assert_eq!(bytes, 2.5f64.to_le_bytes());
+
+    assert_eq!(Finite::<f32>::new(f32::INFINITY), Err(NonFiniteError));
+    assert_eq!(Finite::try_from(f64::NAN), Err(NonFiniteError));
+}
+
+#[test]
+fn unpack_non_finite() {
+    assert!(matches!(
+        Finite::<f64>::unpack_verified(f64::NAN.to_le_bytes(), &()),
+        Err(UnpackError::Packable(NonFiniteError))
+    ));
+    assert!(matches!(
+        Finite::<f32>::unpack_verified(f32::NEG_INFINITY.to_le_bytes(), &()),
+        Err(UnpackError::Packable(NonFiniteError))
+    ));
+    assert!(Finite::<f64>::unpack_unverified(f64::NAN.to_le_bytes()).unwrap().is_nan());
+}
@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use packable::{
+    PackableExt,
+    error::UnpackError,
+    map::{DeterministicHashMap, UnpackMapError, UnpackOrderedMapError},
+};
+
+mod common;
+
+fn entries_bytes(entries: &[(u16, u8)]) -> Vec<u8> {
+    let mut bytes = Vec::from((entries.len() as u64).to_le_bytes());
+
+    for (key, value) in entries {
+        bytes.extend(key.to_le_bytes());
+        bytes.push(*value);
+    }
+
+    bytes
+}
+
+#[test]
+fn packable_hashmap() {
+    assert_eq!(
+        common::generic_test(&HashMap::from([(1u16, Some(0u32)), (2, None)]))
+            .0
+            .len(),
+        core::mem::size_of::<u64>()
+            + 2 * core::mem::size_of::<u16>()
+            + (core::mem::size_of::<u8>() + core::mem::size_of::<u32>())
+            + core::mem::size_of::<u8>()
+    );
+}
+
+#[test]
+fn packable_deterministic_hashmap() {
+    common::generic_test(&DeterministicHashMap(HashMap::from([(1u16, Some(0u32)), (2, None)])));
+}
+
+#[test]
+fn deterministic_order() {
+    let entries = [(0x0100u16, 1u8), (0x00ff, 2), (0x0001, 3), (0x0200, 4)];
+    let map = DeterministicHashMap(HashMap::<_, _>::from(entries));
+
+    // Keys are sorted by their packed little-endian bytes, not by their value.
+    assert_eq!(
+        map.pack_to_vec(),
+        entries_bytes(&[(0x0100, 1), (0x0200, 4), (0x0001, 3), (0x00ff, 2)])
+    );
+}
+
+#[test]
+fn invalid_duplicate() {
+    let bytes = entries_bytes(&[(1, 1), (2, 2), (1, 3)]);
+
+    let unpacked = HashMap::<u16, u8>::unpack_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackMapError::DuplicateKey(1))),
+    ));
+}
+
+#[test]
+fn invalid_unordered() {
+    let bytes = entries_bytes(&[(2, 1), (1, 2)]);
+
+    assert!(HashMap::<u16, u8>::unpack_verified(&bytes, &()).is_ok());
+    assert!(matches!(
+        DeterministicHashMap::<u16, u8>::unpack_verified(&bytes, &()),
+        Err(UnpackError::Packable(UnpackOrderedMapError::Unordered)),
+    ));
+}
+
+#[test]
+fn invalid_deterministic_duplicate() {
+    let bytes = entries_bytes(&[(1, 1), (1, 2)]);
+
+    assert!(matches!(
+        DeterministicHashMap::<u16, u8>::unpack_verified(bytes, &()),
+        Err(UnpackError::Packable(UnpackOrderedMapError::Map(
+            UnpackMapError::DuplicateKey(1)
+        ))),
+    ));
+}
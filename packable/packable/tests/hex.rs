@@ -0,0 +1,63 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    error::{HexError, UnpackError},
+    packer::HexPacker,
+    unpacker::{HexUnpacker, Unpacker},
+    Packable,
+};
+
+#[test]
+fn hex_round_trip() {
+    let value = (Some(0xABu8), 0x6F7BD423u32);
+
+    let mut packer = HexPacker::new();
+    value.pack(&mut packer).unwrap();
+    assert_eq!(&*packer, "01ab23d47b6f");
+
+    let hex = packer.into_inner();
+    let mut unpacker = HexUnpacker::new(&hex);
+    let unpacked = <(Option<u8>, u32)>::unpack::<_, true>(&mut unpacker, &()).unwrap();
+    assert_eq!(unpacked, value);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn hex_unpack_uppercase() {
+    let mut unpacker = HexUnpacker::new("2A00FF");
+
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x2A));
+    assert_eq!(u8::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0x2A);
+    assert_eq!(u16::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0xFF00);
+}
+
+#[test]
+fn hex_unpack_odd_length() {
+    let mut unpacker = HexUnpacker::new("2a0");
+
+    assert!(matches!(
+        u8::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(HexError::OddLength))
+    ));
+}
+
+#[test]
+fn hex_unpack_invalid_character() {
+    let mut unpacker = HexUnpacker::new("2axy");
+
+    assert!(matches!(
+        u16::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(HexError::InvalidCharacter { c: 'x', index: 2 }))
+    ));
+}
+
+#[test]
+fn hex_unpack_not_enough_bytes() {
+    let mut unpacker = HexUnpacker::new("2a");
+
+    assert!(matches!(
+        u32::unpack::<_, true>(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(HexError::UnexpectedEOF(err))) if err.required == 4 && err.had == 1
+    ));
+}
@@ -0,0 +1,60 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::ErrorKind;
+
+use packable::{error::UnpackError, from_reader, read_frame, to_writer, write_frame};
+
+#[test]
+fn writer_reader_roundtrip() {
+    let mut bytes = Vec::new();
+    to_writer(&(1u8, 2u32), &mut bytes).unwrap();
+    to_writer(&true, &mut bytes).unwrap();
+
+    assert_eq!(bytes, [1, 2, 0, 0, 0, 1]);
+
+    let mut reader = bytes.as_slice();
+    assert_eq!(from_reader::<(u8, u32), _>(&mut reader, &()).unwrap(), (1, 2));
+    assert!(from_reader::<bool, _>(&mut reader, &()).unwrap());
+}
+
+#[test]
+fn reader_unexpected_eof() {
+    match from_reader::<u64, _>([1u8, 2].as_slice(), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn frames_roundtrip() {
+    let mut bytes = Vec::new();
+    write_frame(&mut bytes, &(1u8, 2u16)).unwrap();
+    write_frame(&mut bytes, &7u32).unwrap();
+
+    assert_eq!(bytes, [3, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 7, 0, 0, 0]);
+
+    let mut reader = bytes.as_slice();
+    assert_eq!(read_frame::<(u8, u16), _>(&mut reader, 16, &()).unwrap(), (1, 2));
+    assert_eq!(read_frame::<u32, _>(&mut reader, 16, &()).unwrap(), 7);
+}
+
+#[test]
+fn frame_too_long() {
+    let bytes = [5, 0, 0, 0, 1, 2, 3, 4, 5];
+
+    match read_frame::<u32, _>(bytes.as_slice(), 4, &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn frame_trailing_bytes() {
+    let bytes = [3, 0, 0, 0, 1, 2, 3];
+
+    match read_frame::<u16, _>(bytes.as_slice(), 16, &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    Packable,
+    error::{LimitedPackerError, LimitedUnpackerError, UnexpectedEOF, UnpackError},
+    packer::{LimitedPacker, Packer},
+    unpacker::{IoUnpacker, LimitedUnpacker, SliceUnpacker, Unpacker},
+};
+
+#[test]
+fn limited_packer_within_limit() {
+    let mut packer = LimitedPacker::new(Vec::new(), 6);
+
+    (0x0102u16, 0x03040506u32).pack(&mut packer).unwrap();
+    assert_eq!(packer.written_bytes(), Some(6));
+    assert_eq!(packer.into_inner(), [0x02, 0x01, 0x06, 0x05, 0x04, 0x03]);
+}
+
+#[test]
+fn limited_packer_limit_exceeded() {
+    let mut packer = LimitedPacker::new(Vec::new(), 5);
+
+    assert!(matches!(
+        (0x0102u16, 0x03040506u32).pack(&mut packer),
+        Err(LimitedPackerError::SizeLimitExceeded { limit: 5, required: 6 })
+    ));
+    assert_eq!(packer.written_bytes(), Some(2));
+    assert_eq!(packer.into_inner(), [0x02, 0x01]);
+}
+
+#[test]
+fn limited_unpacker_within_limit() {
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&[0x02, 0x01, 0x06, 0x05, 0x04, 0x03, 0x07]), 6);
+
+    assert_eq!(
+        <(u16, u32)>::unpack::<_, true>(&mut unpacker, &()).unwrap(),
+        (0x0102, 0x03040506)
+    );
+    assert_eq!(unpacker.read_bytes(), Some(6));
+    assert_eq!(unpacker.remaining_bytes(), Some(0));
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn limited_unpacker_limit_exceeded() {
+    let mut unpacker = SliceUnpacker::new(&[0x02, 0x01, 0x06, 0x05, 0x04, 0x03]);
+
+    assert!(matches!(
+        <(u16, u32)>::unpack_bounded::<_, true>(&mut unpacker, 5, &()),
+        Err(UnpackError::Unpacker(LimitedUnpackerError::SizeLimitExceeded {
+            limit: 5,
+            required: 6
+        }))
+    ));
+    // The bytes that would cross the limit are left in the inner unpacker.
+    assert_eq!(unpacker.remaining_bytes(), Some(4));
+}
+
+#[test]
+fn limited_unpacker_inner_eof() {
+    let mut unpacker = SliceUnpacker::new(&[0x02, 0x01]);
+
+    assert!(matches!(
+        u32::unpack_bounded::<_, true>(&mut unpacker, 8, &()),
+        Err(UnpackError::Unpacker(LimitedUnpackerError::Unpacker(UnexpectedEOF {
+            required: 4,
+            had: 2
+        })))
+    ));
+}
+
+#[test]
+fn limited_unpacker_skip_and_peek() {
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&[0x01, 0x02, 0x03, 0x04, 0x05]), 4);
+
+    unpacker.skip_bytes(2).unwrap();
+    assert_eq!(unpacker.remaining_budget(), 2);
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x03));
+    assert_eq!(unpacker.remaining_budget(), 2);
+
+    assert!(matches!(
+        unpacker.skip_bytes(3),
+        Err(LimitedUnpackerError::SizeLimitExceeded { limit: 4, required: 5 })
+    ));
+    assert_eq!(u16::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0x0403);
+    assert_eq!(unpacker.remaining_budget(), 0);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+    assert_eq!(unpacker.into_inner().remaining_bytes(), Some(1));
+}
+
+#[test]
+fn limited_unpacker_stream_remaining_bytes() {
+    let mut unpacker = LimitedUnpacker::new(IoUnpacker::new([0x02, 0x01].as_slice()), 6);
+
+    // The budget is not the number of remaining bytes of a stream.
+    assert_eq!(unpacker.remaining_bytes(), None);
+    assert_eq!(unpacker.remaining_budget(), 6);
+    assert_eq!(u16::unpack::<_, true>(&mut unpacker, &()).unwrap(), 0x0102);
+    assert_eq!(unpacker.remaining_bytes(), None);
+    assert_eq!(unpacker.remaining_budget(), 4);
+}
@@ -0,0 +1,36 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+use packable::PackedSize;
+
+#[test]
+fn packable_ipv4_addr() {
+    let (packed, _) = common::generic_test(&Ipv4Addr::new(192, 168, 1, 10));
+
+    assert_eq!(packed, [192, 168, 1, 10]);
+    assert_eq!(Ipv4Addr::PACKED_SIZE, 4);
+}
+
+#[test]
+fn packable_socket_addr_v4() {
+    let (packed, _) = common::generic_test(&SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080));
+
+    assert_eq!(packed, [127, 0, 0, 1, 0x90, 0x1f]);
+    assert_eq!(SocketAddrV4::PACKED_SIZE, 6);
+}
+
+#[test]
+fn packable_socket_addr_v6_scope_id() {
+    let ip = Ipv6Addr::new(0xfe80, 0, 0, 0, 0x1, 0x2, 0x3, 0x4);
+    let (packed, unpacked) = common::generic_test(&SocketAddrV6::new(ip, 443, 0x0102_0304, 3));
+
+    assert_eq!(packed.len(), SocketAddrV6::PACKED_SIZE);
+    assert_eq!(&packed[..16], ip.octets());
+    assert_eq!(&packed[18..], [0x04, 0x03, 0x02, 0x01, 0x03, 0x00, 0x00, 0x00]);
+    assert_eq!(unpacked.flowinfo(), 0x0102_0304);
+    assert_eq!(unpacked.scope_id(), 3);
+}
@@ -1,6 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::num::{NonZeroI8, NonZeroU32};
+
+use packable::{PackableExt, PackedSize, option::OptionalNonZero};
+
 mod common;
 
 #[test]
@@ -14,3 +18,18 @@ fn packable_option() {
         core::mem::size_of::<u8>() + core::mem::size_of::<u64>()
     );
 }
+
+#[test]
+fn packable_optional_non_zero() {
+    let (bytes, _) = common::generic_test(&OptionalNonZero::<NonZeroU32>(None));
+    assert_eq!(bytes, [0, 0, 0, 0]);
+
+    let (bytes, _) = common::generic_test(&OptionalNonZero(NonZeroU32::new(5)));
+    assert_eq!(bytes, 5u32.to_le_bytes());
+
+    assert_eq!(
+        OptionalNonZero::<NonZeroI8>::unpack_verified([0xFF], &()).unwrap(),
+        OptionalNonZero(NonZeroI8::new(-1))
+    );
+    assert_eq!(OptionalNonZero::<NonZeroU32>::PACKED_SIZE, 4);
+}
@@ -0,0 +1,37 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    unpacker::{CounterUnpacker, IoUnpacker, SliceUnpacker, Unpacker},
+    Packable,
+};
+
+fn peek_then_unpack<U: Unpacker>(unpacker: &mut U)
+where
+    U::Error: core::fmt::Debug,
+{
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x01));
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x01));
+    assert_eq!(u16::unpack::<_, true>(unpacker, &()).unwrap(), 0x0201);
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x03));
+    assert_eq!(u8::unpack::<_, true>(unpacker, &()).unwrap(), 0x03);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn peek_slice_unpacker() {
+    peek_then_unpack(&mut SliceUnpacker::new(&[0x01, 0x02, 0x03]));
+}
+
+#[test]
+fn peek_io_unpacker() {
+    peek_then_unpack(&mut IoUnpacker::new([0x01, 0x02, 0x03].as_slice()));
+}
+
+#[test]
+fn peek_does_not_count_as_read() {
+    let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(&[0x2A]));
+
+    assert_eq!(unpacker.peek_u8().unwrap(), Some(0x2A));
+    assert_eq!(unpacker.counter(), 0);
+}
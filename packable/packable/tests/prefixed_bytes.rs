@@ -0,0 +1,54 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    PackableExt,
+    bounded::{BoundedU8, InvalidBoundedU8, TryIntoBoundedU8Error},
+    error::{PackPrefixedBytesError, UnpackError},
+    packer::Packer,
+    prefix::UnpackPrefixError,
+    unpacker::{SliceUnpacker, Unpacker},
+};
+
+#[test]
+fn prefixed_bytes_round_trip() {
+    let mut bytes = Vec::new();
+    bytes.pack_prefixed_bytes(&[1, 2, 3]).unwrap();
+
+    assert_eq!(bytes, vec![1u8, 2, 3].pack_to_vec());
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    assert_eq!(unpacker.unpack_prefixed_bytes::<true>().unwrap(), [1, 2, 3]);
+    assert_eq!(unpacker.peek_u8().unwrap(), None);
+}
+
+#[test]
+fn prefixed_bytes_with_bounded_prefix() {
+    let mut bytes = Vec::new();
+    bytes.pack_prefixed_bytes_with::<BoundedU8<0, 2>>(&[4, 5]).unwrap();
+
+    assert_eq!(bytes, [2, 4, 5]);
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    assert_eq!(
+        unpacker.unpack_prefixed_bytes_with::<BoundedU8<0, 2>, true>().unwrap(),
+        [4, 5]
+    );
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    assert!(matches!(
+        unpacker.unpack_prefixed_bytes_with::<BoundedU8<0, 1>, true>(),
+        Err(UnpackError::Packable(UnpackPrefixError::Prefix(InvalidBoundedU8(2))))
+    ));
+}
+
+#[test]
+fn prefixed_bytes_out_of_prefix_bounds() {
+    let mut bytes = Vec::new();
+
+    assert!(matches!(
+        bytes.pack_prefixed_bytes_with::<BoundedU8<0, 1>>(&[4, 5]),
+        Err(PackPrefixedBytesError::Prefix(TryIntoBoundedU8Error::Invalid(2)))
+    ));
+    assert!(bytes.is_empty());
+}
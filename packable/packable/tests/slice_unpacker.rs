@@ -0,0 +1,70 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use packable::{
+    PackableExt,
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::{ChainUnpacker, CounterUnpacker, SliceUnpacker, Unpacker},
+};
+
+type Message = (u8, u32, u16);
+
+const MESSAGE: Message = (1, 2, 3);
+
+#[test]
+fn truncated_message() {
+    let bytes = MESSAGE.pack_to_vec();
+
+    assert_eq!(bytes.len(), 7);
+    assert_eq!(Message::unpack_verified(&bytes, &()).unwrap(), MESSAGE);
+
+    // The `u32` field requires 4 bytes but only 2 remain after the `u8` field.
+    let err = Message::unpack_verified(&bytes[..3], &()).unwrap_err();
+
+    assert!(matches!(
+        err,
+        UnpackError::Unpacker(UnexpectedEOF { required: 4, had: 2 })
+    ));
+    assert_eq!(
+        err.to_string(),
+        "unpacker error while unpacking: not enough bytes, required 4 but had 2"
+    );
+
+    // The `u16` field requires 2 bytes but none remain.
+    assert_eq!(
+        Message::unpack_verified(&bytes[..5], &())
+            .unwrap_err()
+            .into_unpacker_err(),
+        UnexpectedEOF { required: 2, had: 0 }
+    );
+}
+
+#[test]
+fn unpack_slice_borrows() {
+    let bytes = [1, 2, 3, 4, 5];
+    let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(&bytes));
+
+    let slice = unpacker.unpack_slice(3).unwrap();
+    assert!(matches!(slice, Cow::Borrowed(slice) if core::ptr::eq(slice, &bytes[..3])));
+
+    assert_eq!(unpacker.counter(), 3);
+    assert_eq!(
+        unpacker.unpack_slice(3).unwrap_err(),
+        UnexpectedEOF { required: 3, had: 2 }
+    );
+    assert_eq!(*unpacker.unpack_slice(2).unwrap(), [4, 5]);
+    assert_eq!(unpacker.remaining_bytes(), Some(0));
+}
+
+#[test]
+fn unpack_slice_copies() {
+    let mut unpacker = ChainUnpacker::new([&[1u8, 2][..], &[3, 4]]);
+
+    let slice = unpacker.unpack_slice(3).unwrap();
+    assert!(matches!(slice, Cow::Owned(_)));
+    assert_eq!(*slice, [1, 2, 3]);
+
+    assert!(unpacker.unpack_slice(2).is_err());
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "test-util")]
+
+use core::convert::Infallible;
+
+use packable::{
+    Packable,
+    error::{UnexpectedEOF, UnpackError},
+    packer::Packer,
+    test_util::{assert_packable_roundtrip, repack_stable},
+    unpacker::Unpacker,
+};
+
+#[test]
+fn roundtrip_returns_packed_bytes() {
+    assert_eq!(assert_packable_roundtrip(&(7u8, 0x0102u16)), [7, 2, 1]);
+}
+
+// Packs two bytes but only unpacks the first one.
+#[derive(Debug, PartialEq)]
+struct Leftover;
+
+impl Packable for Leftover {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes([0, 0])
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        (): &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        unpacker.unpack_bytes([0])?;
+        Ok(Self)
+    }
+}
+
+#[test]
+#[should_panic(expected = "the packed bytes were not consumed completely")]
+fn roundtrip_rejects_leftover_bytes() {
+    assert_packable_roundtrip(&Leftover);
+}
+
+#[test]
+fn repack_stable_canonical() {
+    assert!(repack_stable::<(u8, u16)>(&[7, 2, 1]).unwrap());
+    assert!(repack_stable::<Option<u16>>(&[0]).unwrap());
+}
+
+#[test]
+fn repack_stable_non_canonical() {
+    // Any non-zero byte is unpacked as `true`, which is only packed as `1`.
+    assert!(!repack_stable::<bool>(&[2]).unwrap());
+    // Trailing bytes are not part of the encoding of the value.
+    assert!(!repack_stable::<u8>(&[7, 0]).unwrap());
+}
+
+#[test]
+fn repack_stable_unpack_error() {
+    assert!(matches!(
+        repack_stable::<u32>(&[1, 2]),
+        Err(UnpackError::Unpacker(UnexpectedEOF { required: 4, had: 2 }))
+    ));
+}
@@ -0,0 +1,70 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "tokio")]
+
+use std::io::ErrorKind;
+
+use packable::{error::UnpackError, packer::AsyncPacker, unpacker::AsyncUnpacker};
+
+#[tokio::test]
+async fn frames_roundtrip() {
+    let mut packer = AsyncPacker::new(Vec::new());
+    packer.pack_frame(&(1u8, 2u16)).await.unwrap();
+    packer.pack_frame(&7u32).await.unwrap();
+
+    let bytes = packer.into_inner();
+    assert_eq!(bytes, [3, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 7, 0, 0, 0]);
+
+    let mut unpacker = AsyncUnpacker::new(bytes.as_slice());
+    assert_eq!(unpacker.unpack_frame::<(u8, u16)>(16, &()).await.unwrap(), (1, 2));
+    assert_eq!(unpacker.unpack_frame::<u32>(16, &()).await.unwrap(), 7);
+}
+
+#[tokio::test]
+async fn sized_roundtrip() {
+    let mut packer = AsyncPacker::new(Vec::new());
+    packer.pack(&42u64).await.unwrap();
+    packer.pack(&true).await.unwrap();
+
+    let bytes = packer.into_inner();
+    let mut unpacker = AsyncUnpacker::new(bytes.as_slice());
+
+    assert_eq!(unpacker.unpack_sized::<u64>(&()).await.unwrap(), 42);
+    assert!(unpacker.unpack_sized::<bool>(&()).await.unwrap());
+}
+
+#[tokio::test]
+async fn truncated_frame() {
+    let bytes = [8, 0, 0, 0, 1, 2];
+    let mut unpacker = AsyncUnpacker::new(&bytes[..]);
+
+    match unpacker.unpack_frame::<u64>(16, &()).await {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn frame_trailing_bytes() {
+    let bytes = [3, 0, 0, 0, 1, 2, 3];
+    let mut unpacker = AsyncUnpacker::new(&bytes[..]);
+
+    match unpacker.unpack_frame::<u16>(16, &()).await {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn frame_too_long() {
+    // The length is rejected before reading the bytes of the frame.
+    let bytes = [0xFF, 0xFF, 0xFF, 0xFF, 1, 2];
+    let mut unpacker = AsyncUnpacker::new(&bytes[..]);
+
+    match unpacker.unpack_frame::<u16>(16, &()).await {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+        other => panic!("unexpected result {other:?}"),
+    }
+    assert_eq!(*unpacker.into_inner(), [1, 2]);
+}
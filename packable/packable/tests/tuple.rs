@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use packable::{PackableExt, PackedSize};
+
 mod common;
 
 macro_rules! repeat {
@@ -20,6 +22,19 @@ macro_rules! test_packable_tuple {
     };
 }
 
+#[test]
+fn packable_unit() {
+    assert!(common::generic_test(&()).0.is_empty());
+    assert_eq!(<()>::PACKED_SIZE, 0);
+    assert_eq!(((), 1u8, ()).pack_to_vec(), [1]);
+}
+
+#[test]
+fn packable_one_tuple() {
+    assert_eq!(common::generic_test(&(42u32,)).0, 42u32.pack_to_vec());
+    assert_eq!(<(u32,)>::unpack_verified([42, 0, 0, 0], &()).unwrap(), (42,));
+}
+
 #[test]
 fn packable_tuple() {
     repeat!(
@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    PackableExt,
+    error::{ExactUnpackError, TrailingBytesError, UnexpectedEOF, UnpackError},
+};
+
+type Message = (u8, u32);
+
+#[test]
+fn unpack_exact() {
+    let message: Message = (1, 2);
+    let mut bytes = message.pack_to_vec();
+
+    assert_eq!(Message::unpack_exact(&bytes).unwrap(), message);
+
+    bytes.extend_from_slice(&[3, 4]);
+
+    assert!(matches!(
+        Message::unpack_exact(&bytes),
+        Err(UnpackError::Unpacker(ExactUnpackError::TrailingBytes(
+            TrailingBytesError { remaining: 2 }
+        )))
+    ));
+    assert!(matches!(
+        Message::unpack_exact(&bytes[..3]),
+        Err(UnpackError::Unpacker(ExactUnpackError::UnexpectedEOF(UnexpectedEOF {
+            required: 4,
+            had: 2
+        })))
+    ));
+}
@@ -3,6 +3,16 @@
 
 mod common;
 
+use std::io::ErrorKind;
+
+use packable::{
+    error::{ArrayUnpackError, UnexpectedEOF, UnpackError},
+    option::UnpackOptionError,
+    prefix::UnpackPrefixError,
+    unpacker::IoUnpacker,
+    Packable, PackableExt,
+};
+
 #[test]
 fn packable_vec() {
     assert_eq!(
@@ -12,3 +22,47 @@ fn packable_vec() {
             + core::mem::size_of::<u8>()
     );
 }
+
+#[test]
+fn invalid_vec_element_index() {
+    let bytes = [2u8, 0, 0, 0, 0, 0, 0, 0, 0, 3];
+
+    assert!(matches!(
+        Vec::<Option<u32>>::unpack_verified(bytes.as_slice(), &()),
+        Err(UnpackError::Packable(UnpackPrefixError::Item(ArrayUnpackError {
+            index: 1,
+            inner: UnpackOptionError::UnknownTag(3)
+        })))
+    ));
+}
+
+#[test]
+fn vec_len_exceeds_remaining_bytes() {
+    // The declared length is checked against the remaining bytes before allocating the vector.
+    let bytes = [16u8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0];
+
+    assert!(matches!(
+        Vec::<u32>::unpack_verified(bytes.as_slice(), &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF {
+            required: 64,
+            had: 4
+        }))
+    ));
+}
+
+#[test]
+fn vec_huge_len_stream_unpacker() {
+    // A stream unpacker cannot check the declared length, which must not be allocated before the elements are read.
+    let mut bytes = (1u64 << 40).to_le_bytes().to_vec();
+    bytes.extend([1, 0, 0, 0]);
+
+    match Vec::<u32>::unpack::<_, true>(&mut IoUnpacker::new(bytes.as_slice()), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+
+    match Vec::<u8>::unpack::<_, true>(&mut IoUnpacker::new(bytes.as_slice()), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::VecDeque, io::ErrorKind};
+
+use packable::{
+    Packable, PackableExt,
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::IoUnpacker,
+};
+
+mod common;
+
+#[test]
+fn packable_vec_deque() {
+    let mut deque = VecDeque::from([1u32, 2, 3]);
+    deque.pop_front();
+    deque.push_back(4);
+    deque.push_front(0);
+
+    let (bytes, unpacked) = common::generic_test(&deque);
+
+    assert_eq!(bytes, vec![0u32, 2, 3, 4].pack_to_vec());
+    assert_eq!(unpacked.into_iter().collect::<Vec<_>>(), [0, 2, 3, 4]);
+}
+
+#[test]
+fn packable_vec_deque_bytes() {
+    // The bytes are split in two slices once the front of the deque wraps around its buffer.
+    let mut deque = VecDeque::with_capacity(4);
+    deque.extend([1u8, 2, 3, 4]);
+    deque.pop_front();
+    deque.pop_front();
+    deque.push_back(5);
+    deque.push_back(6);
+
+    assert!(!deque.as_slices().1.is_empty());
+    assert_eq!(common::generic_test(&deque).0, vec![3u8, 4, 5, 6].pack_to_vec());
+}
+
+#[test]
+fn vec_deque_len_exceeds_remaining_bytes() {
+    let bytes = [16u8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0];
+
+    assert!(matches!(
+        VecDeque::<u32>::unpack_verified(bytes.as_slice(), &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { required: 64, had: 4 }))
+    ));
+}
+
+#[test]
+fn vec_deque_huge_len_stream_unpacker() {
+    let mut bytes = (1u64 << 40).to_le_bytes().to_vec();
+    bytes.extend([1, 0, 0, 0]);
+
+    match VecDeque::<u32>::unpack::<_, true>(&mut IoUnpacker::new(bytes.as_slice()), &()) {
+        Err(UnpackError::Unpacker(err)) => assert_eq!(err.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{PackableExt, zigzag::ZigZag};
+
+#[test]
+fn packable_zigzag() {
+    let (bytes, _) = common::generic_test(&ZigZag(-1i32));
+    assert_eq!(bytes, 1u32.to_le_bytes());
+
+    common::generic_test(&ZigZag(i8::MIN));
+    common::generic_test(&ZigZag(i64::MAX));
+    common::generic_test(&ZigZag(-12345i128));
+}
+
+#[test]
+fn zigzag_mapping() {
+    for (value, encoded) in [
+        (0i16, 0u16),
+        (-1, 1),
+        (1, 2),
+        (-2, 3),
+        (2, 4),
+        (i16::MAX, u16::MAX - 1),
+        (i16::MIN, u16::MAX),
+    ] {
+        assert_eq!(ZigZag(value).encode(), encoded);
+        assert_eq!(ZigZag::<i16>::decode(encoded), ZigZag(value));
+        assert_eq!(ZigZag(value).pack_to_vec(), encoded.to_le_bytes());
+    }
+}
@@ -73,6 +73,7 @@ fn boxed_slice_encode() {
 }
 
 #[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
 fn boxed_slice_reference_encode() {
     assert_eq!(
         prefix_hex::encode(&vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef].into_boxed_slice()),
@@ -13,11 +13,16 @@ pub fn from_unix_timestamp(timestamp: i64) -> time::OffsetDateTime {
     time::OffsetDateTime::from_unix_timestamp(timestamp).expect("timestamp out of range")
 }
 
+/// Creates a new time from a unix timestamp, at UTC, or returns `None` if the timestamp is out of range.
+pub fn try_from_unix_timestamp(timestamp: i64) -> Option<time::OffsetDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(timestamp).ok()
+}
+
 /// Produces a formatted `String` from a timestamp, displayed as local time.
 pub fn format(time: &time::OffsetDateTime) -> String {
     // This format string is correct, so unwrapping is fine.
     let format_description =
-        time::format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second] (UTC)").unwrap();
+        time::format_description::parse_borrowed::<1>("[year]-[month]-[day] [hour]:[minute]:[second] (UTC)").unwrap();
 
     // We know this is correct.
     time.format(&format_description).unwrap()
@@ -21,6 +21,8 @@ pub enum LogLayerErrorKind {
     Io(io::Error),
     /// Error setting the default logger/subscriber.
     SetLogger(log::SetLoggerError),
+    /// An output references an unknown target group.
+    TargetGroup(fern_logger::Error),
 }
 
 impl fmt::Display for LogLayerErrorKind {
@@ -28,6 +30,7 @@ impl fmt::Display for LogLayerErrorKind {
         match &self {
             Self::Io(err) => write!(f, "{}", err),
             Self::SetLogger(err) => write!(f, "{}", err),
+            Self::TargetGroup(err) => write!(f, "{}", err),
         }
     }
 }
@@ -44,7 +47,14 @@ impl From<log::SetLoggerError> for LogLayerErrorKind {
     }
 }
 
+impl From<fern_logger::Error> for LogLayerErrorKind {
+    fn from(err: fern_logger::Error) -> Self {
+        Self::TargetGroup(err)
+    }
+}
+
 /// An error originating from the [`FlamegraphLayer`](crate::subscriber::layer::FlamegraphLayer).
+#[allow(dead_code)]
 #[derive(Debug)]
 pub enum FlamegraphLayerErrorKind {
     /// Encountered an [`io::Error`].
@@ -125,6 +135,7 @@ impl error::Error for Error {
             Self::Flamegrapher(FlamegrapherErrorKind::Io(err)) => Some(err),
             Self::LogLayer(LogLayerErrorKind::Io(err)) => Some(err),
             Self::LogLayer(LogLayerErrorKind::SetLogger(err)) => Some(err),
+            Self::LogLayer(LogLayerErrorKind::TargetGroup(err)) => Some(err),
             _ => None,
         }
     }
@@ -0,0 +1,51 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+    /// The log context of the current thread.
+    static THREAD_CONTEXT: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "tokio")]
+tokio::task_local! {
+    /// The log context of the current task.
+    static TASK_CONTEXT: Arc<str>;
+}
+
+/// Sets the log context of the current thread, replacing the previous one.
+///
+/// The context is prepended to the message of every record logged from this thread, until it is cleared. Since
+/// asynchronous tasks can move between threads at `.await` points, tasks should use `scope` instead, with the `tokio`
+/// feature.
+pub fn set(context: impl Into<Arc<str>>) {
+    THREAD_CONTEXT.with(|current| *current.borrow_mut() = Some(context.into()));
+}
+
+/// Clears the log context of the current thread.
+pub fn clear() {
+    THREAD_CONTEXT.with(|current| current.borrow_mut().take());
+}
+
+/// Runs a future with the given log context, which is prepended to the message of every record logged while the
+/// future is polled, across `.await` points.
+///
+/// This context takes precedence over the context of the thread polling the future.
+#[cfg(feature = "tokio")]
+pub async fn scope<F: std::future::Future>(context: impl Into<Arc<str>>, future: F) -> F::Output {
+    TASK_CONTEXT.scope(context.into(), future).await
+}
+
+/// Returns the current log context, if any.
+///
+/// The context of the current task, set by `scope`, is returned first, and the context of the current thread, set
+/// by [`set`], otherwise.
+pub fn current() -> Option<Arc<str>> {
+    #[cfg(feature = "tokio")]
+    if let Ok(context) = TASK_CONTEXT.try_with(Arc::clone) {
+        return Some(context);
+    }
+
+    THREAD_CONTEXT.with(|current| current.borrow().clone())
+}
@@ -33,9 +33,9 @@ thread_local! {
 /// This layer registers an interest in the following [`Span`](tracing::Span) targets:
 ///  - `trace_tools::observe`: Generated by implementers of the [`Observe`](crate::Observe) trait.
 ///  - `tokio::task`: Used internally by `tokio` to instrument tasks, when compiling with
-/// `--cfg tokio_unstable` flags.
+///    `--cfg tokio_unstable` flags.
 ///  - [`Span`](tracing::Span)s with the name `runtime.spawn`: used internally by newer versions of `tokio`
-/// to instrument tasks, when compiling with `--cfg tokio_unstable` flags.
+///    to instrument tasks, when compiling with `--cfg tokio_unstable` flags.
 pub struct FlamegraphLayer {
     callsites: RwLock<HashSet<callsite::Identifier>>,
     span_locations: RwLock<HashMap<span::Id, Option<SpanLocation>>>,
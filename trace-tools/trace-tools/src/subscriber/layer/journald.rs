@@ -0,0 +1,105 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{io, os::unix::net::UnixDatagram};
+
+use serde_json::{Map, Value};
+use tracing::Level;
+
+/// Sends records to the systemd journal, using its native protocol.
+///
+/// Every record is sent as a single datagram made of journal fields: `PRIORITY`, `MESSAGE`, `TARGET`, the
+/// `CODE_FILE` and `CODE_LINE` of the event when they are known, and the fields of the event.
+pub(crate) struct Journald {
+    socket: UnixDatagram,
+}
+
+impl Journald {
+    /// The path of the socket on which the journal receives records.
+    const SOCKET_PATH: &'static str = "/run/systemd/journal/socket";
+
+    /// Connects to the socket of the journal.
+    pub(crate) fn connect() -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(Self::SOCKET_PATH)?;
+
+        Ok(Self { socket })
+    }
+
+    /// Sends a record to the journal.
+    ///
+    /// Records larger than the maximum datagram size of the socket are not sent, and an error is returned instead.
+    pub(crate) fn send(
+        &self,
+        level: Level,
+        target: &str,
+        location: Option<(&str, u32)>,
+        message: &str,
+        fields: &Map<String, Value>,
+    ) -> io::Result<()> {
+        let mut payload = Vec::new();
+
+        put_field(&mut payload, "PRIORITY", priority(level));
+        put_field(&mut payload, "MESSAGE", message);
+        put_field(&mut payload, "TARGET", target);
+
+        if let Some((file, line)) = location {
+            put_field(&mut payload, "CODE_FILE", file);
+            put_field(&mut payload, "CODE_LINE", &line.to_string());
+        }
+
+        for (name, value) in fields {
+            let name = field_name(name);
+
+            match value {
+                Value::String(value) => put_field(&mut payload, &name, value),
+                value => put_field(&mut payload, &name, &value.to_string()),
+            }
+        }
+
+        self.socket.send(&payload).map(|_| ())
+    }
+}
+
+/// Maps a [`Level`] to the corresponding syslog priority.
+fn priority(level: Level) -> &'static str {
+    match level {
+        Level::ERROR => "3",
+        Level::WARN => "4",
+        Level::INFO => "6",
+        Level::DEBUG | Level::TRACE => "7",
+    }
+}
+
+/// Converts the name of an event field into a valid journal field name.
+///
+/// Journal field names only contain uppercase letters, digits and underscores, and cannot start with an underscore
+/// or a digit, which are respectively reserved for trusted fields and invalid.
+fn field_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect::<String>();
+
+    match name.chars().next() {
+        Some(c) if c.is_ascii_uppercase() => name,
+        _ => format!("F{}", name),
+    }
+}
+
+/// Appends a field to a payload of the native journal protocol.
+///
+/// Values containing a newline are written in the binary form of the protocol, prefixed by their length.
+fn put_field(payload: &mut Vec<u8>, name: &str, value: &str) {
+    payload.extend_from_slice(name.as_bytes());
+
+    if value.contains('\n') {
+        payload.push(b'\n');
+        payload.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    } else {
+        payload.push(b'=');
+    }
+
+    payload.extend_from_slice(value.as_bytes());
+    payload.push(b'\n');
+}
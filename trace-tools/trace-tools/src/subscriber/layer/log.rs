@@ -2,32 +2,74 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    fs::{File, OpenOptions},
-    io::{self, Stdout, StdoutLock},
+    backtrace::{Backtrace, BacktraceStatus},
+    collections::{HashMap, VecDeque},
+    fs::File,
+    io::{self, StdoutLock},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
 };
 
 use colored::{ColoredString, Colorize};
-use fern_logger::{LoggerConfig, LoggerOutputConfig};
-use parking_lot::{Mutex, MutexGuard};
-use tracing::{metadata::LevelFilter, Event, Level, Metadata, Subscriber};
-use tracing_log::{AsTrace, NormalizeEvent};
+use fern_logger::{ColorScheme, HighlightStyle, LoggerConfig, LoggerOutputConfig, TargetRateLimiter, WriteErrorPolicy};
+use parking_lot::{Mutex, MutexGuard, RwLock};
+use serde_json::{Map, Value};
+use tracing::{metadata::LevelFilter, span, Event, Level, Metadata, Subscriber};
+use tracing_log::{AsLog, AsTrace, NormalizeEvent};
 use tracing_subscriber::{
-    filter::{self, Targets},
-    fmt::MakeWriter,
+    filter::{self, Filtered, Targets},
+    fmt::{self, format::DefaultFields, writer::BoxMakeWriter, MakeWriter},
     layer::{Context, Filter, Layer},
     registry::LookupSpan,
 };
 
-use crate::{subscriber::visitors::MessageVisitor, Error};
+#[cfg(all(feature = "journald", target_os = "linux"))]
+use super::journald::Journald;
+#[cfg(all(feature = "winlog", windows))]
+use super::winlog::EventLog;
+use crate::{
+    subscriber::{
+        context,
+        visitors::{EventFields, JsonVisitor},
+    },
+    Error,
+};
+
+/// Describes the format of the records written by a [`LogLayer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human readable lines, with the time, target, level and message of the event.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    ///
+    /// The object contains the `timestamp`, `level`, `target` and `message` of the event, followed by its other
+    /// fields, and a `spans` array describing every active span, from the root, with its `name` and `fields`.
+    /// Event fields whose name collides with one of these keys are prefixed with `event.`.
+    Json,
+    /// Human readable records spanning multiple lines, meant for the console during local development.
+    ///
+    /// The first line contains the time, level, target and message of the event. It is followed by one indented line
+    /// per field of the event, and by the source location of the event when it is known. Colors are used if they are
+    /// enabled for the output.
+    Pretty,
+}
 
 /// Describes the output target of a [`log`] event.
 ///
 /// Variants wrap a locked writer to the output target.
 enum LogOutput<'a> {
-    /// Log to standard output, with optional color.
-    Stdout(StdoutLock<'a>, bool),
+    /// Log to standard output, or to the console replacing it, with the color scheme of its levels if colors are
+    /// enabled.
+    Stdout(ConsoleWriter<'a>, Option<ColorScheme>),
     /// Log to a file.
     File(MutexGuard<'a, File>),
+    /// Log to an in-memory ring buffer.
+    RingBuffer(MutexGuard<'a, RingBuffer>),
 }
 
 impl<'a> io::Write for LogOutput<'a> {
@@ -35,6 +77,10 @@ impl<'a> io::Write for LogOutput<'a> {
         match self {
             Self::Stdout(lock, _) => lock.write(buf),
             Self::File(lock) => lock.write(buf),
+            Self::RingBuffer(lock) => {
+                lock.push(buf);
+                Ok(buf.len())
+            }
         }
     }
 
@@ -42,7 +88,137 @@ impl<'a> io::Write for LogOutput<'a> {
         match self {
             Self::Stdout(lock, _) => lock.flush(),
             Self::File(lock) => lock.flush(),
+            Self::RingBuffer(_) => Ok(()),
+        }
+    }
+}
+
+/// Writer to the standard output, or to the console replacing it in a [`LogLayer`].
+enum ConsoleWriter<'a> {
+    /// Write to the locked standard output.
+    Stdout(StdoutLock<'a>),
+    /// Write to a writer made by the console of the layer.
+    Console(Box<dyn io::Write + 'a>),
+}
+
+impl<'a> io::Write for ConsoleWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Stdout(lock) => lock.write(buf),
+            Self::Console(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Stdout(lock) => lock.flush(),
+            Self::Console(writer) => writer.flush(),
+        }
+    }
+}
+
+/// Retains the most recent records written to a ring buffer output, until they are dumped.
+///
+/// Every record is written at once, so each write is retained as a record.
+struct RingBuffer {
+    records: VecDeque<Vec<u8>>,
+    capacity: usize,
+}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Retains a record, discarding the oldest one if the buffer is full.
+    fn push(&mut self, record: &[u8]) {
+        if self.capacity == 0 {
+            return;
         }
+
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+
+        self.records.push_back(record.to_vec());
+    }
+
+    /// Writes the retained records, from the oldest to the most recent, then discards them.
+    fn dump_to<W: io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        for record in &self.records {
+            writer.write_all(record)?;
+        }
+
+        self.records.clear();
+
+        writer.flush()
+    }
+}
+
+/// Counters of the records written by a [`LogLayer`], or by one of its outputs.
+///
+/// A record is dropped when it could not be formatted or written to its output, or when the output could not be
+/// locked in time while logging a panic. Records dropped because their target exceeded the rate limit of the output
+/// are counted separately. The counters are updated without locking.
+#[derive(Debug, Default)]
+pub struct LogStats {
+    emitted: AtomicU64,
+    dropped: AtomicU64,
+    throttled: AtomicU64,
+}
+
+impl LogStats {
+    /// Returns the number of records that have been written.
+    pub fn emitted(&self) -> u64 {
+        self.emitted.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of records that have been dropped.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of records that have been dropped because their target exceeded its rate limit.
+    pub fn throttled(&self) -> u64 {
+        self.throttled.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, emitted: bool) {
+        let counter = if emitted { &self.emitted } else { &self.dropped };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Maximum level of the records written by a [`LogLayer`], shared by its clones and updated without locking.
+struct MaxLevel(AtomicUsize);
+
+impl MaxLevel {
+    /// The levels, ordered by their verbosity, whose index is stored in the atomic.
+    const LEVELS: [LevelFilter; 6] = [
+        LevelFilter::OFF,
+        LevelFilter::ERROR,
+        LevelFilter::WARN,
+        LevelFilter::INFO,
+        LevelFilter::DEBUG,
+        LevelFilter::TRACE,
+    ];
+
+    fn new(level: LevelFilter) -> Self {
+        let max_level = Self(AtomicUsize::new(0));
+        max_level.set(level);
+        max_level
+    }
+
+    fn get(&self) -> LevelFilter {
+        Self::LEVELS[self.0.load(Ordering::Relaxed)]
+    }
+
+    fn set(&self, level: LevelFilter) {
+        let index = Self::LEVELS.iter().position(|l| *l == level).unwrap_or_default();
+        self.0.store(index, Ordering::Relaxed);
     }
 }
 
@@ -50,10 +226,18 @@ impl<'a> io::Write for LogOutput<'a> {
 ///
 /// Locks obtained from these targets are used to create writers to the appropriate [`LogOutput`].
 enum LogDest {
-    /// Log to standard output, with optional color.
-    Stdout(bool),
+    /// Log to standard output, with the color scheme of its levels if colors are enabled.
+    Stdout(Option<ColorScheme>),
     /// Log to a file.
     File(Mutex<File>),
+    /// Log to the systemd journal, with structured fields.
+    #[cfg(all(feature = "journald", target_os = "linux"))]
+    Journald(Journald),
+    /// Log to the Windows Event Log, from the given event source.
+    #[cfg(all(feature = "winlog", windows))]
+    WinEventLog { source: EventLog },
+    /// Retain the most recent records in memory, until they are dumped.
+    RingBuffer(Mutex<RingBuffer>),
 }
 
 /// Describes a target destination of a [`log`] event, combined with filters that only permit
@@ -65,20 +249,179 @@ struct LogTarget {
     dest: LogDest,
 }
 
-/// [`MakeWriter`] implementation for the [`LogLayer`].
+/// Builds the [`Targets`] filter of a [`LogLayer`] output, from its level, target filters and target exclusions.
+///
+/// If the output has target filters, only the targets they match are enabled, at the level of the output. Otherwise,
+/// every target is enabled at this level. Targets matched by exclusions are disabled in both cases. Filters and
+/// exclusions are lowercased. References to target groups are matched as they are, so they should be resolved first
+/// with [`LoggerOutputConfig::resolve_target_groups`].
+///
+/// This can be used by other layers to filter events exactly like an output of a [`LogLayer`].
+pub fn build_targets(output_config: &LoggerOutputConfig) -> Targets {
+    let level = output_config.level_filter().as_trace();
+
+    let mut targets = if output_config.target_filters().is_empty() {
+        filter::Targets::default().with_default(level)
+    } else {
+        let mut targets = filter::Targets::default().with_default(LevelFilter::OFF);
+
+        for filter in output_config.target_filters() {
+            targets = targets.with_target(filter.clone().to_lowercase(), level);
+        }
+
+        targets
+    };
+
+    for exclusion in output_config.target_exclusions() {
+        targets = targets.with_target(exclusion.clone().to_lowercase(), LevelFilter::OFF);
+    }
+
+    targets
+}
+
+/// A [`fmt::Layer`] writing the records of a [`LoggerOutputConfig`], filtered by its targets, as built by
+/// [`fmt_layer`].
+pub type FmtLayer<S> = Filtered<fmt::Layer<S, DefaultFields, fmt::format::Format, BoxMakeWriter>, Targets, S>;
+
+/// Builds a [`fmt::Layer`] of [`tracing_subscriber`] from the configuration of a [`LogLayer`] output, so that the same
+/// configuration can be used with either layer, e.g. to migrate from one to the other or to compare them.
+///
+/// The layer is filtered by the [`build_targets`] of the output and writes to the standard output, with colors if they
+/// are enabled, or to the log file of the output. Its records use the default format of [`fmt::Layer`], so the
+/// widths, line ending, escaping and rate limit of the output are not applied, and [`log`] records are written with
+/// the `log` target and their `log.*` fields.
 ///
-/// Constructs a writer for a specific [`LogTarget`].
+/// # Errors
+/// This function can fail in the following ways:
+///  - An [`io::Error`] was encountered when opening the log file of the output.
+///  - The output is a `journald`, `winlog` or `ring_buffer` output, which a [`fmt::Layer`] cannot write to. The
+///    [`io::Error`] is of kind [`Unsupported`](io::ErrorKind::Unsupported).
+///  - A target filter or exclusion of the output references a target group. The output is used on its own, so its
+///    references must be resolved beforehand with [`LoggerOutputConfig::resolve_target_groups`].
+pub fn fmt_layer<S>(output_config: &LoggerOutputConfig) -> Result<FmtLayer<S>, Error>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let output_config = output_config
+        .resolve_target_groups(&HashMap::new())
+        .map_err(|err| Error::LogLayer(err.into()))?;
+
+    let unsupported = |name: &str| {
+        let err = io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("a fmt layer cannot write to a `{}` output", name),
+        );
+        Error::LogLayer(err.into())
+    };
+
+    let (writer, ansi) = match output_config.name() {
+        LogTargetMakeWriter::STDOUT_NAME => (BoxMakeWriter::new(io::stdout), output_config.color_enabled()),
+        #[cfg(all(feature = "journald", target_os = "linux"))]
+        name @ LogTargetMakeWriter::JOURNALD_NAME => return Err(unsupported(name)),
+        #[cfg(all(feature = "winlog", windows))]
+        name @ LogTargetMakeWriter::WINLOG_NAME => return Err(unsupported(name)),
+        name @ LogTargetMakeWriter::RING_BUFFER_NAME => return Err(unsupported(name)),
+        name => {
+            let file =
+                fern_logger::open_log_file(name, output_config.append()).map_err(|err| Error::LogLayer(err.into()))?;
+            (BoxMakeWriter::new(std::sync::Mutex::new(file)), false)
+        }
+    };
+
+    let layer = fmt::layer().with_writer(writer).with_ansi(ansi);
+
+    Ok(layer.with_filter(build_targets(&output_config)))
+}
+
+/// Constructs writers for a specific [`LogTarget`] of the [`LogLayer`].
 struct LogTargetMakeWriter {
-    stdout: Stdout,
+    /// The name of the output, as given in its [`LoggerOutputConfig`].
+    name: String,
+    /// The separator written after each record.
+    line_ending: &'static str,
+    /// Counters of the records written to this output.
+    stats: Arc<LogStats>,
+    /// Width of the target section of the records, if it overrides the one of the layer.
+    target_width: Option<usize>,
+    /// Width of the level section of the records, if it overrides the one of the layer.
+    level_width: Option<usize>,
+    /// Length of the longest target written to this output so far.
+    max_target_len: AtomicUsize,
+    /// Rate limiter of the records of each target, if the output has a rate limit.
+    rate_limiter: Option<TargetRateLimiter>,
+    /// Format of the records, if it overrides the one of the layer.
+    format: Option<LogFormat>,
+    /// Whether the messages of text records are quoted and escaped.
+    escape_message: bool,
+    /// Whether text records have a target section.
+    show_target: bool,
+    /// Name of the event field marking the records to highlight.
+    highlight_field: String,
+    /// Emphasis of the highlighted records.
+    highlight_style: HighlightStyle,
+    /// Behavior of the output when a record cannot be written.
+    write_error_policy: WriteErrorPolicy,
+    /// Whether a write error of the output has already been reported.
+    write_error_reported: AtomicBool,
+    /// Factory of the writers replacing the standard output, if the layer has a console.
+    console: Option<Arc<BoxMakeWriter>>,
     target: LogTarget,
 }
 
 impl LogTargetMakeWriter {
-    fn new(target: LogTarget) -> Self {
-        Self {
-            stdout: io::stdout(),
-            target,
-        }
+    /// The name that specifies the standard output as a log target (instead of a file).
+    const STDOUT_NAME: &'static str = "stdout";
+    /// The name that specifies the systemd journal as a log target (instead of a file).
+    #[cfg(all(feature = "journald", target_os = "linux"))]
+    const JOURNALD_NAME: &'static str = "journald";
+    /// The name that specifies the Windows Event Log as a log target (instead of a file).
+    #[cfg(all(feature = "winlog", windows))]
+    const WINLOG_NAME: &'static str = "winlog";
+    /// The name that specifies an in-memory ring buffer as a log target (instead of a file).
+    const RING_BUFFER_NAME: &'static str = "ring_buffer";
+
+    fn new(output_config: &LoggerOutputConfig, console: Option<Arc<BoxMakeWriter>>) -> Result<Self, io::Error> {
+        let targets = build_targets(output_config);
+
+        let dest = match output_config.name() {
+            Self::STDOUT_NAME => LogDest::Stdout(
+                output_config
+                    .color_enabled()
+                    .then(|| output_config.color_scheme()),
+            ),
+            #[cfg(all(feature = "journald", target_os = "linux"))]
+            Self::JOURNALD_NAME => LogDest::Journald(Journald::connect()?),
+            #[cfg(all(feature = "winlog", windows))]
+            Self::WINLOG_NAME => LogDest::WinEventLog {
+                source: EventLog::register(&event_log_source(output_config))?,
+            },
+            Self::RING_BUFFER_NAME => LogDest::RingBuffer(Mutex::new(RingBuffer::new(
+                output_config.ring_buffer_capacity(),
+            ))),
+            name => {
+                let file = fern_logger::open_log_file(name, output_config.append())?;
+                LogDest::File(Mutex::new(file))
+            }
+        };
+
+        Ok(Self {
+            name: output_config.name().to_owned(),
+            line_ending: output_config.line_ending().as_str(),
+            stats: Arc::default(),
+            target_width: output_config.target_width(),
+            level_width: output_config.level_width(),
+            max_target_len: AtomicUsize::new(0),
+            rate_limiter: output_config.rate_limit().map(TargetRateLimiter::new),
+            format: None,
+            escape_message: output_config.escape_message(),
+            show_target: output_config.show_target(),
+            highlight_field: output_config.highlight_field().to_owned(),
+            highlight_style: output_config.highlight_style(),
+            write_error_policy: output_config.write_error_policy(),
+            write_error_reported: AtomicBool::new(false),
+            console,
+            target: LogTarget { filter: targets, dest },
+        })
     }
 
     fn enabled<S>(&self, meta: &Metadata<'_>, ctx: &Context<'_, S>) -> bool
@@ -87,15 +430,101 @@ impl LogTargetMakeWriter {
     {
         Filter::enabled(&self.target.filter, meta, ctx)
     }
-}
 
-impl<'a> MakeWriter<'a> for &'a LogTargetMakeWriter {
-    type Writer = LogOutput<'a>;
+    /// Returns the formatter of a record of this output with the given target, from the formatter of its layer.
+    fn formatter<'a>(&'a self, layer_formatter: LogFormatter<'a>, target: &str) -> LogFormatter<'a> {
+        let mut formatter = LogFormatter {
+            target_width: self.target_width.unwrap_or(layer_formatter.target_width),
+            level_width: self.level_width.unwrap_or(layer_formatter.level_width),
+            format: self.format.unwrap_or(layer_formatter.format),
+            escape_message: self.escape_message,
+            show_target: self.show_target,
+            ..layer_formatter
+        };
+
+        if formatter.auto_target_width {
+            let max_target_len = self
+                .max_target_len
+                .fetch_max(target.len(), Ordering::Relaxed)
+                .max(target.len());
+            formatter.target_width = formatter.target_width.max(max_target_len);
+        }
+
+        formatter
+    }
+
+    /// Returns the highlight field and style of an event, if its record is highlighted in this output.
+    ///
+    /// Records are highlighted if their event has the highlight field set to `true` and they are written to the
+    /// standard output with colors enabled.
+    fn highlight(&self, fields: &EventFields) -> Option<(&str, HighlightStyle)> {
+        let highlighted = matches!(self.target.dest, LogDest::Stdout(Some(_)))
+            && fields.fields().get(&self.highlight_field) == Some(&Value::Bool(true));
+
+        highlighted.then_some((self.highlight_field.as_str(), self.highlight_style))
+    }
+
+    /// Counts a record in the statistics of this output and of its layer.
+    fn record(&self, layer_stats: &LogStats, emitted: bool) {
+        self.stats.record(emitted);
+        layer_stats.record(emitted);
+    }
+
+    /// Takes a token from the rate limit budget of a target, or counts the record as throttled in the statistics of
+    /// this output and of its layer if the budget is used up.
+    fn within_rate_limit(&self, layer_stats: &LogStats, target: &str) -> bool {
+        match &self.rate_limiter {
+            Some(rate_limiter) if !rate_limiter.check(target) => {
+                self.stats.throttled.fetch_add(1, Ordering::Relaxed);
+                layer_stats.throttled.fetch_add(1, Ordering::Relaxed);
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Writes a formatted record to a writer of the output target, applying the [`WriteErrorPolicy`] of the output if
+    /// it fails.
+    ///
+    /// Returns whether the record has been written, either to the output or to the standard error.
+    fn write_record(&self, writer: &mut LogOutput<'_>, record: &[u8]) -> bool {
+        let err = match io::Write::write_all(writer, record) {
+            Ok(()) => return true,
+            Err(err) => err,
+        };
+
+        match self.write_error_policy {
+            WriteErrorPolicy::Ignore => false,
+            WriteErrorPolicy::Retry => io::Write::write_all(writer, record).is_ok(),
+            WriteErrorPolicy::Stderr => io::Write::write_all(&mut io::stderr().lock(), record).is_ok(),
+            WriteErrorPolicy::Report => {
+                if !self.write_error_reported.swap(true, Ordering::Relaxed) {
+                    eprintln!("failed to write a record to the log output `{}`: {}", self.name, err);
+                }
+
+                false
+            }
+        }
+    }
+
+    /// Constructs a writer to the console of the layer, or to the standard output if the layer has none.
+    fn console_writer(&self) -> ConsoleWriter<'_> {
+        match &self.console {
+            Some(console) => ConsoleWriter::Console(console.make_writer()),
+            None => ConsoleWriter::Stdout(io::stdout().lock()),
+        }
+    }
 
-    fn make_writer(&self) -> Self::Writer {
+    /// Constructs a writer to the output target, or returns `None` if the target does not receive formatted records.
+    fn make_writer(&self) -> Option<LogOutput<'_>> {
         match &self.target.dest {
-            LogDest::Stdout(color) => LogOutput::Stdout(self.stdout.lock(), *color),
-            LogDest::File(file) => LogOutput::File(file.lock()),
+            LogDest::Stdout(color) => Some(LogOutput::Stdout(self.console_writer(), *color)),
+            LogDest::File(file) => Some(LogOutput::File(file.lock())),
+            LogDest::RingBuffer(buffer) => Some(LogOutput::RingBuffer(buffer.lock())),
+            #[cfg(all(feature = "journald", target_os = "linux"))]
+            LogDest::Journald(_) => None,
+            #[cfg(all(feature = "winlog", windows))]
+            LogDest::WinEventLog { .. } => None,
         }
     }
 }
@@ -111,28 +540,113 @@ impl<'a> MakeWriter<'a> for &'a LogTargetMakeWriter {
 /// generated by [`tracing_log`]. These are only created when
 /// [`collect_logs`](crate::subscriber::collect_logs) is called, or a [`LogTracer`](tracing_log::LogTracer)
 /// is initialised.
+///
+/// Outputs can be added and removed at runtime with [`add_output`](LogLayer::add_output) and
+/// [`remove_output`](LogLayer::remove_output). Clones of a layer share the same outputs, so a clone can be kept
+/// around to manage the outputs of a layer that has been moved into a subscriber.
+#[derive(Clone)]
 pub struct LogLayer {
-    make_writers: Vec<LogTargetMakeWriter>,
-    fmt_events: LogFormatter,
+    make_writers: Arc<RwLock<Vec<LogTargetMakeWriter>>>,
+    fmt_events: LogFormatter<'static>,
+    stats: Arc<LogStats>,
+    max_level: Arc<MaxLevel>,
+    timestamp_field: Option<Arc<str>>,
+    console: Option<Arc<BoxMakeWriter>>,
+    target_groups: Arc<HashMap<String, Vec<String>>>,
 }
 
 impl<S> Layer<S> for LogLayer
 where
     S: Subscriber + for<'a> LookupSpan<'a>,
 {
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        // Span fields are only needed to give context to JSON records, or to look up the timestamp field.
+        let json = self
+            .make_writers
+            .read()
+            .iter()
+            .any(|make_writer| make_writer.format.unwrap_or(self.fmt_events.format) == LogFormat::Json);
+
+        if !json && self.timestamp_field.is_none() {
+            return;
+        }
+
+        if let Some(span) = ctx.span(id) {
+            let mut extensions = span.extensions_mut();
+
+            if extensions.get_mut::<JsonVisitor>().is_none() {
+                let mut visitor = JsonVisitor::default();
+                attrs.record(&mut visitor);
+                extensions.insert(visitor);
+            }
+        }
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(visitor) = span.extensions_mut().get_mut::<JsonVisitor>() {
+                values.record(visitor);
+            }
+        }
+    }
+
     fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
         // If the event is originally issued by the `log` crate, generate the appropriate `tracing` metadata.
         if let Some(metadata) = event.normalized_metadata() {
+            if *metadata.level() > self.max_level.get() {
+                return;
+            }
+
             let mut buf = String::new();
+            // The fields and the time are recorded once, by the first output that writes the event, and shared by
+            // the others.
+            let mut fields = None;
+            let mut time = None;
 
-            for make_writer in &self.make_writers {
+            for make_writer in self.make_writers.read().iter() {
                 // Only write to an output if the event target is enabled by filters.
-                if make_writer.enabled(&metadata, &ctx) {
-                    let mut writer = make_writer.make_writer();
+                if make_writer.enabled(&metadata, &ctx)
+                    && make_writer.within_rate_limit(&self.stats, metadata.target())
+                {
+                    let fields = fields.get_or_insert_with(|| EventFields::from_event(event));
 
-                    if self.fmt_events.format_event(&mut buf, &writer, event).is_ok() {
-                        let _ = io::Write::write(&mut writer, buf.as_bytes());
-                    }
+                    let emitted = match make_writer.make_writer() {
+                        Some(mut writer) => {
+                            let formatter = LogFormatter {
+                                highlight: make_writer.highlight(fields),
+                                ..make_writer.formatter(self.fmt_events, metadata.target())
+                            };
+                            let time = time.get_or_insert_with(|| self.event_time(event, fields, &ctx));
+
+                            formatter.format_event(&mut buf, &writer, time, event, fields, &ctx).is_ok() && {
+                                buf.push_str(make_writer.line_ending);
+                                make_writer.write_record(&mut writer, buf.as_bytes())
+                            }
+                        }
+                        None => match &make_writer.target.dest {
+                            #[cfg(all(feature = "journald", target_os = "linux"))]
+                            LogDest::Journald(journald) => {
+                                let location = metadata.file().zip(metadata.line());
+
+                                journald
+                                    .send(
+                                        *metadata.level(),
+                                        metadata.target(),
+                                        location,
+                                        fields.message(),
+                                        fields.fields(),
+                                    )
+                                    .is_ok()
+                            }
+                            #[cfg(all(feature = "winlog", windows))]
+                            LogDest::WinEventLog { source } => source
+                                .send(*metadata.level(), metadata.target(), fields.message(), fields.fields())
+                                .is_ok(),
+                            _ => false,
+                        },
+                    };
+
+                    make_writer.record(&self.stats, emitted);
 
                     buf.clear();
                 }
@@ -142,127 +656,627 @@ where
 }
 
 impl LogLayer {
-    /// The name that specifies the standard output as a log target (instead of a file).
-    const STDOUT_NAME: &'static str = "stdout";
+    /// Returns a [`PanicLogger`] that writes to the same targets as this layer.
+    pub(crate) fn panic_logger(&self) -> PanicLogger {
+        PanicLogger {
+            make_writers: self.make_writers.clone(),
+            fmt_events: self.fmt_events,
+            stats: self.stats.clone(),
+            max_level: self.max_level.clone(),
+        }
+    }
+
+    /// Returns the maximum level of the records written by the layer.
+    pub fn max_level(&self) -> LevelFilter {
+        self.max_level.get()
+    }
+
+    /// Sets the maximum level of the records written by the layer, across all its outputs and targets, without
+    /// rebuilding its configuration. Clones of the layer share the same maximum level.
+    ///
+    /// This level combines with the level filters of every output, so a record is only written if both permit it.
+    /// The default maximum level, [`LevelFilter::TRACE`], leaves the filters of the outputs unchanged, and e.g. setting
+    /// [`LevelFilter::DEBUG`] only enables debug records for outputs whose filters already permit them.
+    pub fn set_max_level(&self, level: LevelFilter) {
+        self.max_level.set(level);
+    }
+
+    /// Sets the level of the records with the given target, or a target it is a prefix of, written to every output of
+    /// the layer, without rebuilding its configuration. The target is lowercased, like the filters of the outputs.
+    ///
+    /// This overrides the level filter, target filters and exclusions of the outputs for this target, e.g.
+    /// [`LevelFilter::OFF`] silences it and [`LevelFilter::DEBUG`] enables its debug records even if it is not part of
+    /// the target filters of an output. Records remain limited by the maximum level of the layer. Outputs added
+    /// afterwards use their own configuration.
+    pub fn set_target_level(&self, target: &str, level: LevelFilter) {
+        for make_writer in self.make_writers.write().iter_mut() {
+            let filter = make_writer.target.filter.clone();
+            make_writer.target.filter = filter.with_target(target.to_lowercase(), level);
+        }
+    }
+
+    /// Returns the counters of the records written by the layer, across all its outputs.
+    pub fn stats(&self) -> &LogStats {
+        &self.stats
+    }
+
+    /// Returns the counters of the records written to the output with the given name, which is either a file name,
+    /// `stdout`, `journald`, `winlog` or `ring_buffer`.
+    ///
+    /// The counters keep being updated for as long as the output is part of the layer.
+    pub fn output_stats(&self, name: &str) -> Option<Arc<LogStats>> {
+        self.make_writers
+            .read()
+            .iter()
+            .find(|make_writer| make_writer.name == name)
+            .map(|make_writer| make_writer.stats.clone())
+    }
+
+    pub(crate) fn new(config: LoggerConfig, console: Option<BoxMakeWriter>) -> Result<Self, Error> {
+        let console = console.map(Arc::new);
 
-    pub(crate) fn new(config: LoggerConfig) -> Result<Self, Error> {
         let fmt_events = LogFormatter {
             target_width: config.target_width(),
             level_width: config.level_width(),
+            auto_target_width: config.auto_target_width(),
+            format: LogFormat::default(),
+            escape_message: false,
+            show_target: true,
+            highlight: None,
         };
 
         let make_writers = config
             .outputs()
             .iter()
-            .map(|output_config: &LoggerOutputConfig| {
-                let level = output_config.level_filter().as_trace();
+            .map(|output_config| {
+                let output_config = output_config
+                    .resolve_target_groups(config.target_groups())
+                    .map_err(|err| Error::LogLayer(err.into()))?;
 
-                let mut targets = if output_config.target_filters().is_empty() {
-                    filter::Targets::default().with_default(level)
-                } else {
-                    let mut targets = filter::Targets::default().with_default(LevelFilter::OFF);
+                LogTargetMakeWriter::new(&output_config, console.clone()).map_err(|err| Error::LogLayer(err.into()))
+            })
+            .collect::<Result<_, Error>>()?;
 
-                    for filter in output_config.target_filters() {
-                        targets = targets.with_target(filter.clone().to_lowercase(), level);
-                    }
+        Ok(Self {
+            make_writers: Arc::new(RwLock::new(make_writers)),
+            fmt_events,
+            stats: Arc::default(),
+            max_level: Arc::new(MaxLevel::new(LevelFilter::TRACE)),
+            timestamp_field: None,
+            console,
+            target_groups: Arc::new(config.target_groups().clone()),
+        })
+    }
 
-                    targets
-                };
+    /// Sets the name of a field holding the time at which records should be stamped, instead of the current time.
+    ///
+    /// The field is looked up in the fields of the event, then in the fields of its spans, from the closest one to the
+    /// root. Integer values are read as unix timestamps, in seconds, and string values are written as they are.
+    /// Records are stamped with the current time if none of them has a valid value for this field. This can be used to
+    /// replay historical data with the time it was originally recorded at.
+    #[must_use]
+    pub fn with_timestamp_field(mut self, name: impl Into<String>) -> Self {
+        self.timestamp_field = Some(name.into().into());
+        self
+    }
 
-                for exclusion in output_config.target_exclusions() {
-                    targets = targets.with_target(exclusion.clone().to_lowercase(), LevelFilter::OFF);
-                }
+    /// Returns the formatted time of a record of the given event, from its timestamp field if the layer has one.
+    fn event_time<S>(&self, event: &Event<'_>, fields: &EventFields, ctx: &Context<'_, S>) -> String
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let value = self.timestamp_field.as_deref().and_then(|name| {
+            fields.fields().get(name).cloned().or_else(|| {
+                ctx.event_scope(event)
+                    .into_iter()
+                    .flatten()
+                    .find_map(|span| span.extensions().get::<JsonVisitor>()?.0.get(name).cloned())
+            })
+        });
+
+        let time = value.and_then(|value| match value {
+            Value::Number(timestamp) => timestamp
+                .as_i64()
+                .and_then(time_helper::try_from_unix_timestamp)
+                .map(|time| time_helper::format(&time)),
+            Value::String(time) => Some(time),
+            _ => None,
+        });
+
+        time.unwrap_or_else(now)
+    }
+
+    /// Writes the records retained by the `ring_buffer` outputs of the layer to the given writer, from the oldest to
+    /// the most recent, then discards them.
+    ///
+    /// Ring buffer outputs keep the last records in memory, up to their
+    /// [`ring_buffer_capacity`](LoggerOutputConfig::ring_buffer_capacity), so that normal operation is quiet but the
+    /// context of a failure can be dumped when it happens. They are also dumped to the standard error when a panic is
+    /// logged by [`install_panic_logging`](super::install_panic_logging).
+    ///
+    /// # Errors
+    /// This method can fail in the following ways:
+    ///  - An [`io::Error`] was encountered when writing the records, which are then retained.
+    pub fn dump_to<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        for make_writer in self.make_writers.read().iter() {
+            if let LogDest::RingBuffer(buffer) = &make_writer.target.dest {
+                buffer.lock().dump_to(&mut writer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the format of the records written by the layer.
+    #[must_use]
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.fmt_events.format = format;
+        self
+    }
+
+    /// Sets the format of the records written to every output of the layer with the given name, which is either a
+    /// file name, `stdout`, `journald`, `winlog` or `ring_buffer`, overriding the format of the layer.
+    ///
+    /// Returns `true` if at least one output has been updated.
+    pub fn set_output_format(&self, name: &str, format: LogFormat) -> bool {
+        let mut updated = false;
+
+        for make_writer in self.make_writers.write().iter_mut() {
+            if make_writer.name == name {
+                make_writer.format = Some(format);
+                updated = true;
+            }
+        }
+
+        updated
+    }
+
+    /// Adds an output to the layer. Events emitted after this call are routed to the new output.
+    ///
+    /// # Errors
+    /// This method can fail in the following ways:
+    ///  - An [`io::Error`] was encountered when creating the log file required by the config.
+    ///  - A target filter or exclusion of the config references a target group that is not part of the
+    ///    [`LoggerConfig`] the layer was built from.
+    pub fn add_output(&self, output_config: &LoggerOutputConfig) -> Result<(), Error> {
+        let output_config = output_config
+            .resolve_target_groups(&self.target_groups)
+            .map_err(|err| Error::LogLayer(err.into()))?;
+        let make_writer = LogTargetMakeWriter::new(&output_config, self.console.clone())
+            .map_err(|err| Error::LogLayer(err.into()))?;
+
+        self.make_writers.write().push(make_writer);
 
-                let dest = match output_config.name() {
-                    Self::STDOUT_NAME => LogDest::Stdout(output_config.color_enabled()),
-                    name => {
-                        let file = OpenOptions::new().write(true).create(true).append(true).open(name)?;
-                        LogDest::File(Mutex::new(file))
+        Ok(())
+    }
+
+    /// Removes every output of the layer with the given name, which is either a file name, `stdout`, `journald`,
+    /// `winlog` or `ring_buffer`.
+    ///
+    /// Returns `true` if at least one output has been removed.
+    pub fn remove_output(&self, name: &str) -> bool {
+        let mut make_writers = self.make_writers.write();
+        let len = make_writers.len();
+
+        make_writers.retain(|make_writer| make_writer.name != name);
+
+        make_writers.len() != len
+    }
+}
+
+/// Writes panic information to the targets of a [`LogLayer`], from within a panic hook.
+pub(crate) struct PanicLogger {
+    make_writers: Arc<RwLock<Vec<LogTargetMakeWriter>>>,
+    fmt_events: LogFormatter<'static>,
+    stats: Arc<LogStats>,
+    max_level: Arc<MaxLevel>,
+}
+
+impl PanicLogger {
+    /// The target used to filter and format panic records.
+    const TARGET: &'static str = "panic";
+
+    /// The maximum time to wait for the outputs or a file target to be released by another thread.
+    ///
+    /// The panicking thread may itself hold the locks, so this must not block indefinitely.
+    const LOCK_TIMEOUT: Duration = Duration::from_millis(100);
+
+    /// Logs a panic message, and a backtrace if enabled, at the `ERROR` level, then flushes all targets.
+    pub(crate) fn log(&self, message: &str, location: Option<String>) {
+        if Level::ERROR > self.max_level.get() {
+            return;
+        }
+
+        let mut record = match thread::current().name() {
+            Some(name) => format!("thread '{}' panicked", name),
+            None => String::from("thread '<unnamed>' panicked"),
+        };
+
+        if let Some(location) = location {
+            record.push_str(" at ");
+            record.push_str(&location);
+        }
+
+        record.push_str(": ");
+        record.push_str(message);
+
+        let backtrace = Backtrace::capture();
+
+        if backtrace.status() == BacktraceStatus::Captured {
+            record.push_str(&format!("\nstack backtrace:\n{}", backtrace));
+        }
+
+        let make_writers = match self.make_writers.try_read_for(Self::LOCK_TIMEOUT) {
+            Some(make_writers) => make_writers,
+            None => {
+                self.stats.record(false);
+                return;
+            }
+        };
+
+        let mut buf = String::new();
+
+        for make_writer in make_writers.iter() {
+            let target = &make_writer.target;
+
+            if !target.filter.would_enable(Self::TARGET, &Level::ERROR) {
+                continue;
+            }
+
+            let mut writer = match &target.dest {
+                LogDest::Stdout(color) => LogOutput::Stdout(make_writer.console_writer(), *color),
+                LogDest::File(file) => match file.try_lock_for(Self::LOCK_TIMEOUT) {
+                    Some(lock) => LogOutput::File(lock),
+                    None => {
+                        make_writer.record(&self.stats, false);
+                        continue;
+                    }
+                },
+                LogDest::RingBuffer(buffer) => match buffer.try_lock_for(Self::LOCK_TIMEOUT) {
+                    Some(lock) => LogOutput::RingBuffer(lock),
+                    None => {
+                        make_writer.record(&self.stats, false);
+                        continue;
                     }
+                },
+                #[cfg(all(feature = "journald", target_os = "linux"))]
+                LogDest::Journald(journald) => {
+                    let emitted = journald
+                        .send(Level::ERROR, Self::TARGET, None, &record, &Map::new())
+                        .is_ok();
+                    make_writer.record(&self.stats, emitted);
+                    continue;
+                }
+                #[cfg(all(feature = "winlog", windows))]
+                LogDest::WinEventLog { source } => {
+                    let emitted = source.send(Level::ERROR, Self::TARGET, &record, &Map::new()).is_ok();
+                    make_writer.record(&self.stats, emitted);
+                    continue;
+                }
+            };
+
+            let emitted = make_writer
+                .formatter(self.fmt_events, Self::TARGET)
+                .format_record(&mut buf, &writer, &now(), Self::TARGET, Level::ERROR, &record)
+                .is_ok()
+                && {
+                    buf.push_str(make_writer.line_ending);
+                    make_writer.write_record(&mut writer, buf.as_bytes())
                 };
 
-                Ok(LogTargetMakeWriter::new(LogTarget { filter: targets, dest }))
-            })
-            .collect::<Result<_, io::Error>>()
-            .map_err(|err| Error::LogLayer(err.into()))?;
+            make_writer.record(&self.stats, emitted);
 
-        Ok(Self {
-            make_writers,
-            fmt_events,
-        })
+            let _ = io::Write::flush(&mut writer);
+
+            buf.clear();
+        }
+
+        // Ring buffers are dumped once they hold the panic record, so that it comes with the records leading to it.
+        for make_writer in make_writers.iter() {
+            if let LogDest::RingBuffer(buffer) = &make_writer.target.dest {
+                if let Some(mut buffer) = buffer.try_lock_for(Self::LOCK_TIMEOUT) {
+                    let _ = buffer.dump_to(&mut io::stderr().lock());
+                }
+            }
+        }
+    }
+}
+
+/// Returns the event source of a `winlog` output, which is the file stem of the current executable unless the output
+/// sets one.
+#[cfg(all(feature = "winlog", windows))]
+fn event_log_source(output_config: &LoggerOutputConfig) -> String {
+    match output_config.event_log_source() {
+        Some(source) => source.to_owned(),
+        None => std::env::current_exe()
+            .ok()
+            .and_then(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| env!("CARGO_PKG_NAME").to_owned()),
     }
 }
 
+/// Returns the current time, formatted for a record.
+fn now() -> String {
+    time_helper::format(&time_helper::now_utc())
+}
+
+/// Formats a [`Level`] into a [`ColoredString`], using the colors of the records of a [`LogLayer`].
+///
+/// The level is left uncolored if `enabled` is `false`, and uses the default [`ColorScheme`] otherwise. This can be
+/// used by other layers and tools to render levels consistently with the records of a [`LogLayer`].
+pub fn color_level(level: Level, enabled: bool) -> ColoredString {
+    level.color(enabled.then_some(ColorScheme::Default))
+}
+
 /// Trait that allows a type to be formatted into a [`ColoredString`].
 ///
 /// Using a trait here allows this functionality to be implemented for the external [`Level`] type.
 trait ColorFormat {
-    /// Formats `self` into a [`ColoredString`].
-    fn color(self, enabled: bool) -> ColoredString;
+    /// Formats `self` into a [`ColoredString`], uncolored if no [`ColorScheme`] is given.
+    fn color(self, scheme: Option<ColorScheme>) -> ColoredString;
 }
 
 impl ColorFormat for Level {
-    fn color(self, enabled: bool) -> ColoredString {
+    fn color(self, scheme: Option<ColorScheme>) -> ColoredString {
         let text = self.to_string();
 
-        if !enabled {
-            return text.as_str().into();
-        }
-
-        match self {
-            Level::TRACE => text.bright_magenta(),
-            Level::DEBUG => text.bright_blue(),
-            Level::INFO => text.bright_green(),
-            Level::WARN => text.bright_yellow(),
-            Level::ERROR => text.bright_red(),
+        match scheme {
+            Some(scheme) => text.color(scheme.level_color(self.as_log())),
+            None => text.as_str().into(),
         }
     }
 }
 
 /// Helper struct for formatting [`log`] records into a [`String`] and writing to a [`Write`](std::fmt::Write)
 /// implementer.
-struct LogFormatter {
+#[derive(Clone, Copy)]
+struct LogFormatter<'a> {
     target_width: usize,
     level_width: usize,
+    auto_target_width: bool,
+    format: LogFormat,
+    escape_message: bool,
+    /// Whether text records have a target section, which JSON records always have.
+    show_target: bool,
+    /// Field marking the event as highlighted and emphasis of its message, if the record is highlighted.
+    highlight: Option<(&'a str, HighlightStyle)>,
 }
 
-impl LogFormatter {
+impl LogFormatter<'_> {
     /// Formats a [`log`] record (converted into a [`tracing::Event`] by [`tracing_log`]) into a [`String`].
     ///
-    /// This string is then written to a [`Write`](std::fmt::Write) implementer.
+    /// This string is then written to a [`Write`](std::fmt::Write) implementer. It is not terminated by a line
+    /// ending, which depends on the output.
     ///
     /// Formatting can change depending on the output target of the writer, and so this must also be
     /// provided. An output that writes to `stdout` can potentially be formatted with text colors.
-    fn format_event<W>(&self, writer: &mut W, output: &LogOutput, event: &Event<'_>) -> std::fmt::Result
+    ///
+    /// The fields and the time of the event are recorded beforehand, and the context is used to describe the active
+    /// spans in JSON records.
+    fn format_event<W, S>(
+        &self,
+        writer: &mut W,
+        output: &LogOutput,
+        time: &str,
+        event: &Event<'_>,
+        fields: &EventFields,
+        ctx: &Context<'_, S>,
+    ) -> std::fmt::Result
     where
         W: std::fmt::Write,
+        S: Subscriber + for<'a> LookupSpan<'a>,
     {
         if let Some(metadata) = event.normalized_metadata() {
             let level = *metadata.level();
             let target = metadata.target();
 
-            let mut visitor = MessageVisitor::default();
-            event.record(&mut visitor);
+            match self.format {
+                LogFormat::Text => return self.format_record(writer, output, time, target, level, fields.message()),
+                LogFormat::Pretty => {
+                    self.format_record(writer, output, time, target, level, fields.message())?;
+                    return self.format_pretty_fields(writer, output, fields.fields(), &metadata);
+                }
+                LogFormat::Json => {}
+            }
+
+            let spans = ctx
+                .event_scope(event)
+                .into_iter()
+                .flat_map(|scope| scope.from_root())
+                .map(|span| {
+                    let fields = span
+                        .extensions()
+                        .get::<JsonVisitor>()
+                        .map(|visitor| visitor.0.clone())
+                        .unwrap_or_default();
+
+                    let mut object = Map::new();
+                    object.insert("name".to_string(), span.name().into());
+                    object.insert("fields".to_string(), fields.into());
+
+                    Value::Object(object)
+                })
+                .collect();
+
+            Self::format_json(writer, time, target, level, fields.message(), fields.fields(), spans)?;
+        }
+
+        Ok(())
+    }
 
-            let time = time_helper::format(&time_helper::now_utc());
+    /// Formats a single JSON record into a [`String`], written to a [`Write`](std::fmt::Write) implementer.
+    fn format_json<W>(
+        writer: &mut W,
+        time: &str,
+        target: &str,
+        level: Level,
+        message: &str,
+        fields: &Map<String, Value>,
+        spans: Vec<Value>,
+    ) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        /// Keys of a JSON record that cannot be used by event fields.
+        const RESERVED_KEYS: [&str; 6] = ["timestamp", "level", "target", "message", "context", "spans"];
+
+        write!(
+            writer,
+            "{{\"timestamp\":{},\"level\":{},\"target\":{},\"message\":{}",
+            Value::from(time),
+            Value::from(level.as_str()),
+            Value::from(target),
+            Value::from(message),
+        )?;
+
+        if let Some(context) = context::current() {
+            write!(writer, ",\"context\":{}", Value::from(&*context))?;
+        }
 
-            let level = match *output {
-                LogOutput::File(_) => ColoredString::from(level.to_string().as_str()),
-                LogOutput::Stdout(_, color_enabled) => level.color(color_enabled),
+        for (name, value) in fields {
+            let name = if RESERVED_KEYS.contains(&name.as_str()) {
+                format!("event.{}", name)
+            } else {
+                name.clone()
             };
 
-            write!(
+            write!(writer, ",{}:{}", Value::from(name), value)?;
+        }
+
+        write!(writer, ",\"spans\":{}}}", Value::Array(spans))
+    }
+
+    /// Formats a single record, given its time, target, level and message, into a [`String`].
+    ///
+    /// This string is then written to a [`Write`](std::fmt::Write) implementer.
+    fn format_record<W>(
+        &self,
+        writer: &mut W,
+        output: &LogOutput,
+        time: &str,
+        target: &str,
+        level: Level,
+        message: &str,
+    ) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        if self.format == LogFormat::Json {
+            return Self::format_json(writer, time, target, level, message, &Map::new(), Vec::new());
+        }
+
+        let level = match *output {
+            LogOutput::File(_) | LogOutput::RingBuffer(_) => ColoredString::from(level.to_string().as_str()),
+            LogOutput::Stdout(_, scheme) => level.color(scheme),
+        };
+
+        let context = context::current()
+            .map(|context| format!("[{}] ", context))
+            .unwrap_or_default();
+
+        if self.format == LogFormat::Pretty && self.show_target {
+            return write!(
                 writer,
-                "{} {:target_width$} {:level_width$} {}",
+                "{} {:level_width$} {}: {}{}",
                 time,
+                level,
                 target,
+                context,
+                self.emphasize(message),
+                level_width = self.level_width,
+            );
+        }
+
+        // Pretty records span multiple lines, so their messages are not escaped.
+        let escaped;
+        let message = if self.escape_message && self.format == LogFormat::Text {
+            escaped = fern_logger::escape_message(message);
+            &escaped
+        } else {
+            message
+        };
+        let message = self.emphasize(message);
+
+        if !self.show_target {
+            return write!(
+                writer,
+                "{} {:level_width$} {}{}",
+                time,
                 level,
-                visitor.0,
-                target_width = self.target_width,
+                context,
+                message,
                 level_width = self.level_width,
-            )?;
+            );
+        }
+
+        write!(
+            writer,
+            "{} {:target_width$} {:level_width$} {}{}",
+            time,
+            target,
+            level,
+            context,
+            message,
+            target_width = self.target_width,
+            level_width = self.level_width,
+        )
+    }
+
+    /// Emphasizes the message of a record with the style of its highlight, if it is highlighted.
+    fn emphasize(&self, message: &str) -> ColoredString {
+        match self.highlight {
+            Some((_, HighlightStyle::Bold)) => message.bold(),
+            Some((_, HighlightStyle::Reverse)) => message.reversed(),
+            None => message.into(),
+        }
+    }
+
+    /// Formats the fields and the source location of an event on their own indented lines, following the first line
+    /// of a [`LogFormat::Pretty`] record.
+    ///
+    /// The field marking a highlighted record is omitted.
+    fn format_pretty_fields<W>(
+        &self,
+        writer: &mut W,
+        output: &LogOutput,
+        fields: &Map<String, Value>,
+        metadata: &Metadata<'_>,
+    ) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        /// Indentation of the lines following the first line of a record.
+        const INDENT: &str = "    ";
+
+        let color_enabled = matches!(*output, LogOutput::Stdout(_, Some(_)));
+
+        let highlight_field = self.highlight.map(|(field, _)| field);
+
+        for (name, value) in fields.iter().filter(|(name, _)| Some(name.as_str()) != highlight_field) {
+            let name = if color_enabled {
+                name.as_str().bold()
+            } else {
+                name.as_str().into()
+            };
+
+            match value {
+                Value::String(value) => write!(writer, "\n{}{}: {}", INDENT, name, value)?,
+                value => write!(writer, "\n{}{}: {}", INDENT, name, value)?,
+            }
+        }
+
+        if let Some(file) = metadata.file() {
+            let location = match metadata.line() {
+                Some(line) => format!("{}:{}", file, line),
+                None => file.to_string(),
+            };
+            let location = if color_enabled {
+                location.as_str().dimmed()
+            } else {
+                location.as_str().into()
+            };
 
-            writeln!(writer)?;
+            write!(writer, "\n{}at {}", INDENT, location)?;
         }
 
         Ok(())
@@ -2,14 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod flamegraph;
+#[cfg(all(feature = "journald", target_os = "linux"))]
+mod journald;
 mod log;
+#[cfg(all(feature = "winlog", windows))]
+mod winlog;
 
-use std::path::Path;
+use std::{panic, path::Path};
 
 use fern_logger::LoggerConfig;
 use tracing::Metadata;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 
-pub use self::{flamegraph::FlamegraphLayer, log::LogLayer};
+pub use self::{
+    flamegraph::FlamegraphLayer,
+    log::{build_targets, color_level, fmt_layer, FmtLayer, LogFormat, LogLayer, LogStats},
+};
 use crate::{util::Flamegrapher, Error};
 
 /// Creates a new [`FlamegraphLayer`].
@@ -59,12 +67,60 @@ pub(crate) fn flamegraph_filter(meta: &Metadata<'_>) -> bool {
 /// This function can fail in the following ways:
 ///  - An [`io::Error`](std::io::Error) was encountered when creating any log files required by the config.
 pub fn log_layer(config: LoggerConfig) -> Result<LogLayer, Error> {
-    LogLayer::new(config)
+    LogLayer::new(config, None)
 }
 
-/// Filter function for the log layer. Registers all [`Event`](tracing::Event)s with the layer.
+/// Creates a new [`LogLayer`], using the parameters provided by the given [`LoggerConfig`], whose `stdout` outputs
+/// write to the writers made by the given console instead of the standard output.
+///
+/// This allows applications managing the standard output themselves, or running without one, to redirect the console
+/// records, e.g. to an embedded terminal. A writer is made for every record, and the standard output is never used
+/// by the layer, including by outputs added later with [`LogLayer::add_output`] and by the panic logging installed
+/// with [`install_panic_logging`].
+///
+/// # Errors
+/// This function can fail in the following ways:
+///  - An [`io::Error`](std::io::Error) was encountered when creating any log files required by the config.
+pub fn log_layer_with_console(config: LoggerConfig, console: BoxMakeWriter) -> Result<LogLayer, Error> {
+    LogLayer::new(config, Some(console))
+}
+
+/// Installs a panic hook that logs panics through the given [`LogLayer`].
+///
+/// The panic message, its location and, if enabled through `RUST_BACKTRACE`, a backtrace are logged at the
+/// `ERROR` level with the `panic` target, to every output of the layer whose filters permit it. All outputs are
+/// flushed afterwards, so that the crash information is persisted in the log files.
+///
+/// The previously installed panic hook (by default, the one printing to standard error) is called afterwards.
+///
+/// # Notes
+///  - The hook only holds a handle to the outputs of the layer, so it keeps working after the layer has been moved
+///    into a subscriber.
+pub fn install_panic_logging(layer: &LogLayer) {
+    let logger = layer.panic_logger();
+    let previous_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.as_str()
+        } else {
+            "Box<dyn Any>"
+        };
+
+        logger.log(message, info.location().map(ToString::to_string));
+
+        previous_hook(info);
+    }));
+}
+
+/// Filter function for the log layer. Registers all [`Event`](tracing::Event)s with the layer, as well as
+/// [`Span`](tracing::Span)s, that describe the context of events in [`LogFormat::Json`] records.
 pub(crate) fn log_filter(meta: &tracing::Metadata<'_>) -> bool {
-    meta.is_event()
+    meta.is_event() || meta.is_span()
 }
 
 /// Creates a new [`console_subscriber::ConsoleLayer`].
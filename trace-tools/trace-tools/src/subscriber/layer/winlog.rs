@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{ffi::c_void, fmt::Write, io, iter, ptr};
+
+use serde_json::{Map, Value};
+use tracing::Level;
+
+/// Handle of an event source, as returned by `RegisterEventSourceW`.
+type Handle = *mut c_void;
+
+/// Event type of error records.
+const EVENTLOG_ERROR_TYPE: u16 = 0x0001;
+/// Event type of warning records.
+const EVENTLOG_WARNING_TYPE: u16 = 0x0002;
+/// Event type of informational records.
+const EVENTLOG_INFORMATION_TYPE: u16 = 0x0004;
+
+#[link(name = "advapi32")]
+extern "system" {
+    fn RegisterEventSourceW(server_name: *const u16, source_name: *const u16) -> Handle;
+    fn DeregisterEventSource(event_log: Handle) -> i32;
+    fn ReportEventW(
+        event_log: Handle,
+        event_type: u16,
+        category: u16,
+        event_id: u32,
+        user_sid: *mut c_void,
+        num_strings: u16,
+        data_size: u32,
+        strings: *const *const u16,
+        raw_data: *mut c_void,
+    ) -> i32;
+}
+
+/// Writes records to the Windows Event Log, from an event source of the local computer.
+///
+/// Every record is written as a single string made of the target, the message and the fields of the event, with an
+/// event type mapped from its level. The source does not need to be registered with a message file, in which case
+/// the Event Viewer shows the string along with a note that the description of the event cannot be found.
+pub(crate) struct EventLog {
+    handle: Handle,
+}
+
+// SAFETY: the handle of an event source can be used from any thread, and `ReportEventW` can be called concurrently.
+unsafe impl Send for EventLog {}
+// SAFETY: see the `Send` implementation.
+unsafe impl Sync for EventLog {}
+
+impl EventLog {
+    /// Registers the event source with the given name.
+    pub(crate) fn register(source: &str) -> io::Result<Self> {
+        let source = wide(source);
+
+        // SAFETY: `source` is a NUL-terminated wide string, and a null server name designates the local computer.
+        let handle = unsafe { RegisterEventSourceW(ptr::null(), source.as_ptr()) };
+
+        if handle.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Self { handle })
+    }
+
+    /// Writes a record to the event log.
+    pub(crate) fn send(
+        &self,
+        level: Level,
+        target: &str,
+        message: &str,
+        fields: &Map<String, Value>,
+    ) -> io::Result<()> {
+        let mut record = format!("{}: {}", target, message);
+
+        for (name, value) in fields {
+            let _ = match value {
+                Value::String(value) => write!(record, " {}={}", name, value),
+                value => write!(record, " {}={}", name, value),
+            };
+        }
+
+        let record = wide(&record);
+        let strings = [record.as_ptr()];
+
+        // SAFETY: the handle is valid until the event log is dropped, and `strings` holds a single NUL-terminated wide
+        // string, without raw data.
+        let reported = unsafe {
+            ReportEventW(
+                self.handle,
+                event_type(level),
+                0,
+                0,
+                ptr::null_mut(),
+                1,
+                0,
+                strings.as_ptr(),
+                ptr::null_mut(),
+            )
+        };
+
+        if reported == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for EventLog {
+    fn drop(&mut self) {
+        // SAFETY: the handle was returned by `RegisterEventSourceW` and is not used after this call.
+        unsafe {
+            DeregisterEventSource(self.handle);
+        }
+    }
+}
+
+/// Maps a [`Level`] to the corresponding event type.
+///
+/// The event log has no debug type, so debug and trace records are informational.
+fn event_type(level: Level) -> u16 {
+    match level {
+        Level::ERROR => EVENTLOG_ERROR_TYPE,
+        Level::WARN => EVENTLOG_WARNING_TYPE,
+        Level::INFO | Level::DEBUG | Level::TRACE => EVENTLOG_INFORMATION_TYPE,
+    }
+}
+
+/// Encodes a string as a NUL-terminated wide string.
+fn wide(value: &str) -> Vec<u16> {
+    value.encode_utf16().chain(iter::once(0)).collect()
+}
@@ -1,11 +1,19 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+/// Contains the log context, prepended to the message of every record logged by the current thread or task.
+///
+/// This complements span fields to identify records, such as with a request or correlation ID, when entering spans is
+/// not convenient.
+pub mod context;
 /// Contains layers used in this crate's subscriber for node diagnostics.
 pub mod layer;
 
-/// Contains visitors that record [`Span`](tracing::Span) field information.
-pub(crate) mod visitors;
+/// Contains visitors that record [`Span`](tracing::Span) and [`Event`](tracing::Event) field information.
+///
+/// These visitors are used by the layers of this crate, and can be reused by other layers to avoid recording the
+/// same fields several times.
+pub mod visitors;
 
 use std::path::{Path, PathBuf};
 
@@ -31,7 +39,7 @@ use crate::{util::Flamegrapher, Error};
 ///
 /// # Notes
 ///  - This should only be called once. Any subsequent calls will fail, since the global logger can only be set
-/// once in a program's lifespan.
+///    once in a program's lifespan.
 ///  - If the global logger has already been set (by the [`log`] crate, for example), this will fail.
 pub fn collect_logs() -> Result<(), log::SetLoggerError> {
     LogTracer::init()
@@ -67,6 +75,9 @@ pub struct SubscriberBuilder {
     console_enabled: bool,
 
     logger_config: Option<LoggerConfig>,
+    log_format: layer::LogFormat,
+    output_log_formats: Vec<(String, layer::LogFormat)>,
+    log_timestamp_field: Option<String>,
     flamegraph_stack_file: Option<PathBuf>,
 }
 
@@ -84,6 +95,30 @@ impl SubscriberBuilder {
         self
     }
 
+    /// Sets the format of the records written by the [`LogLayer`](layer::LogLayer), if it is enabled.
+    pub fn with_log_format(mut self, log_format: layer::LogFormat) -> Self {
+        self.log_format = log_format;
+        self
+    }
+
+    /// Sets the format of the records written by the [`LogLayer`](layer::LogLayer) to the outputs with the given
+    /// name, overriding the one set with [`with_log_format`](SubscriberBuilder::with_log_format).
+    ///
+    /// This can be used to write [`LogFormat::Pretty`](layer::LogFormat::Pretty) records to `stdout` during local
+    /// development while files keep receiving records in another format.
+    pub fn with_output_log_format(mut self, name: impl Into<String>, log_format: layer::LogFormat) -> Self {
+        self.output_log_formats.push((name.into(), log_format));
+        self
+    }
+
+    /// Stamps the records written by the [`LogLayer`](layer::LogLayer) with the value of the given field of their event
+    /// or spans instead of the current time, as described in
+    /// [`LogLayer::with_timestamp_field`](layer::LogLayer::with_timestamp_field).
+    pub fn with_log_timestamp_field(mut self, name: impl Into<String>) -> Self {
+        self.log_timestamp_field = Some(name.into());
+        self
+    }
+
     /// Enables the [`FlamegraphLayer`](layer::FlamegraphLayer) for this subscriber.
     ///
     /// The given path describes the desired output location of the folded stack file that is generated by
@@ -109,10 +144,10 @@ impl SubscriberBuilder {
     ///
     /// # Notes
     ///  - This method calls the [`collect_logs`] function. Any [`log`] records emitted will be converting
-    /// into [`tracing`] events, and therefore any external functionality that deals with [`log`] records
-    /// may no longer function as expected.
+    ///    into [`tracing`] events, and therefore any external functionality that deals with [`log`] records
+    ///    may no longer function as expected.
     ///  - This method does *not* set the global subscriber. As such, a call to `finish` can be used to
-    /// further extend the return subscriber with external [`Layer`](tracing_subscriber::Layer)s.
+    ///    further extend the return subscriber with external [`Layer`](tracing_subscriber::Layer)s.
     pub fn finish(self) -> Result<(TraceSubscriber, Option<Flamegrapher>), Error> {
         self.compose()
     }
@@ -133,10 +168,10 @@ impl SubscriberBuilder {
     ///
     /// # Notes
     ///  - This method calls the [`collect_logs`] function. Any [`log`] records emitted will be converting
-    /// into [`tracing`] events, and therefore any external functionality that deals with [`log`] records
-    /// may no longer function as expected.
+    ///    into [`tracing`] events, and therefore any external functionality that deals with [`log`] records
+    ///    may no longer function as expected.
     ///  - This method sets the global subscriber. Any further attempts to set the global subscriber
-    /// (including another call to this method) will fail.
+    ///    (including another call to this method) will fail.
     ///  - The subscriber initialised by this method cannot be extended.
     pub fn init(self) -> Result<Option<Flamegrapher>, Error> {
         let (subscriber, flamegrapher) = self.compose()?;
@@ -182,10 +217,28 @@ impl SubscriberBuilder {
             collect_logs().map_err(|err| Error::LogLayer(err.into()))?;
         }
 
+        let log_format = self.log_format;
+        let output_log_formats = std::mem::take(&mut self.output_log_formats);
+        let log_timestamp_field = self.log_timestamp_field.take();
+
         self.logger_config
             .take()
             .map(layer::log_layer)
-            .map_or(Ok(None), |res| res.map(Some))
+            .map_or(Ok(None), |res| {
+                res.map(|layer| {
+                    let mut layer = layer.with_format(log_format);
+
+                    if let Some(name) = log_timestamp_field {
+                        layer = layer.with_timestamp_field(name);
+                    }
+
+                    for (name, log_format) in &output_log_formats {
+                        layer.set_output_format(name, *log_format);
+                    }
+
+                    Some(layer)
+                })
+            })
     }
 
     fn build_flamegraph_layer(&mut self) -> Result<(Option<layer::FlamegraphLayer>, Option<Flamegrapher>), Error> {
@@ -1,9 +1,13 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt;
+use std::fmt::{self, Write};
 
-use tracing::field::{Field, Visit};
+use serde_json::{Map, Value};
+use tracing::{
+    field::{Field, Visit},
+    Event,
+};
 
 use crate::observe::{FILE_FIELD_NAME, LINE_FIELD_NAME};
 
@@ -45,18 +49,205 @@ impl Visit for LocationVisitor {
 ///
 /// The `message` field is very common in [`tracing`] spans and events, and is also used by events
 /// generated by [`tracing_log`] to describe [`log`] event messages.
-#[derive(Default)]
-pub(crate) struct MessageVisitor(pub(crate) String);
+#[derive(Clone, Debug, Default)]
+pub struct MessageVisitor(pub String);
 
 impl MessageVisitor {
     /// The field name that describes an event message.
-    const FIELD_NAME: &'static str = "message";
+    pub const FIELD_NAME: &'static str = "message";
 }
 
-impl Visit for MessageVisitor {
-    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+impl MessageVisitor {
+    /// Records the message if the field describes it.
+    fn record(&mut self, field: &Field, value: impl fmt::Display) {
         if field.name() == Self::FIELD_NAME {
-            self.0 = format!("{:?}", value);
+            self.0.clear();
+            // A failing `Display` implementation only truncates the message instead of dropping the whole line.
+            let _ = write!(self.0, "{}", value);
         }
     }
 }
+
+impl Visit for MessageVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, value);
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, value);
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.record(field, value);
+    }
+
+    fn record_u128(&mut self, field: &Field, value: u128) {
+        self.record(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field, value);
+    }
+
+    // Recorded without the quotes that the `Debug` representation of a string would add.
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value);
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.record(field, value);
+    }
+
+    // Raw bytes are recorded as text, with invalid UTF-8 sequences replaced by `U+FFFD`.
+    fn record_bytes(&mut self, field: &Field, value: &[u8]) {
+        self.record(field, String::from_utf8_lossy(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format_args!("{:?}", value));
+    }
+}
+
+/// Visits a [`Span`](tracing::Span) or an [`Event`](tracing::Event) and records all fields as JSON values.
+///
+/// Fields that are not numbers, booleans or strings are recorded using their [`Debug`](fmt::Debug) representation.
+/// Raw bytes are recorded as strings, with invalid UTF-8 sequences replaced by `U+FFFD`.
+///
+/// When the [`LogLayer`](crate::subscriber::layer::LogLayer) writes JSON records, it stores a `JsonVisitor` with the
+/// fields of every span in the extensions of the span. Other layers can read these fields instead of recording them
+/// again, or insert their own `JsonVisitor` which is then reused by the layer.
+#[derive(Clone, Debug, Default)]
+pub struct JsonVisitor(pub Map<String, Value>);
+
+impl JsonVisitor {
+    fn insert(&mut self, field: &Field, value: impl Into<Value>) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+}
+
+impl Visit for JsonVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value);
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value);
+    }
+
+    fn record_bytes(&mut self, field: &Field, value: &[u8]) {
+        self.insert(field, String::from_utf8_lossy(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let mut debug = String::new();
+        // A failing `Debug` implementation only truncates the value instead of dropping the whole line.
+        let _ = write!(debug, "{:?}", value);
+        self.insert(field, debug);
+    }
+}
+
+/// Visits an [`Event`] once and records both its message and its other fields.
+///
+/// The message is recorded as by a [`MessageVisitor`], and the other fields as by a [`JsonVisitor`], leaving out the
+/// `log.*` fields added by [`tracing_log`] which are already part of the normalized metadata of the event.
+#[derive(Clone, Debug, Default)]
+pub struct EventFields {
+    message: MessageVisitor,
+    fields: JsonVisitor,
+}
+
+impl EventFields {
+    /// Records the fields of an [`Event`].
+    pub fn from_event(event: &Event<'_>) -> Self {
+        let mut visitor = Self::default();
+        event.record(&mut visitor);
+
+        visitor.fields.0.remove(MessageVisitor::FIELD_NAME);
+        visitor.fields.0.retain(|name, _| !name.starts_with("log."));
+
+        visitor
+    }
+
+    /// Returns the message of the event, or an empty string if it has none.
+    pub fn message(&self) -> &str {
+        &self.message.0
+    }
+
+    /// Returns the fields of the event other than its message, as JSON values.
+    pub fn fields(&self) -> &Map<String, Value> {
+        &self.fields.0
+    }
+
+    /// Consumes the recorded fields and returns the message and the other fields of the event.
+    pub fn into_parts(self) -> (String, Map<String, Value>) {
+        (self.message.0, self.fields.0)
+    }
+}
+
+impl Visit for EventFields {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.message.record_f64(field, value);
+        self.fields.record_f64(field, value);
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.message.record_i64(field, value);
+        self.fields.record_i64(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.message.record_u64(field, value);
+        self.fields.record_u64(field, value);
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.message.record_i128(field, value);
+        self.fields.record_i128(field, value);
+    }
+
+    fn record_u128(&mut self, field: &Field, value: u128) {
+        self.message.record_u128(field, value);
+        self.fields.record_u128(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.message.record_bool(field, value);
+        self.fields.record_bool(field, value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.message.record_str(field, value);
+        self.fields.record_str(field, value);
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.message.record_error(field, value);
+        self.fields.record_error(field, value);
+    }
+
+    fn record_bytes(&mut self, field: &Field, value: &[u8]) {
+        self.message.record_bytes(field, value);
+        self.fields.record_bytes(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.message.record_debug(field, value);
+        self.fields.record_debug(field, value);
+    }
+}